@@ -0,0 +1,191 @@
+use std::fs::File;
+use std::io::{ self, BufWriter, Write };
+
+use deterministic_trigonometry::{ Compatibility, DTrig };
+
+mod reference;
+use reference::Real;
+
+/* This is a tool that reports the accuracy of every trig function against a reference computed with exact integer
+series. It evaluates all 6283 angle indices of the sine, cosine and tangent, all 2001 inputs of the arcsine and
+arccosine, and every thousandth input of each arctangent tier with both compatibility settings, and writes a CSV
+summary with the largest and mean absolute errors, the same errors in units of the last place, which is 1/1000 for
+every function, and the input with the largest error. Run it in release mode, as it evaluates about fourteen million
+reference values:
+
+cargo run --release --example dtrig_accuracy > accuracy.csv
+
+Adding "--entries entries.csv" also writes the error of every single input to a second CSV file. */
+fn main() -> io::Result<()> {
+    let arguments: Vec<String> = std::env::args().collect();
+
+    // This optional file gets one row for every input that is evaluated.
+    let mut entries = match arguments.iter().position(|argument| argument == "--entries") {
+        Some(index) => {
+            let path = arguments.get(index + 1).expect("The --entries option needs a file name.");
+            let mut file = BufWriter::new(File::create(path)?);
+            writeln!(file, "function,input,output,reference,absolute_error")?;
+            Some(file)
+        }
+        None => None,
+    };
+
+    // This checks the reference against known digits of pi before anything is measured with it.
+    assert_eq!(reference::pi().to_decimal(30), "3.141592653589793238462643383280");
+
+    let d_trig = DTrig::initialize();
+    let legacy_d_trig = DTrig::initialize().with_compatibility(Compatibility::Version0_1);
+
+    let mut output = io::stdout().lock();
+    writeln!(
+        output,
+        "function,domain,inputs,max_absolute_error,mean_absolute_error,max_ulp_error,mean_ulp_error,worst_input"
+    )?;
+
+    let angles: Vec<i64> = (0..6283).collect();
+    let values: Vec<i64> = (-1000..1001).collect();
+
+    let mut domains = vec![
+        Domain {
+            function: "sine",
+            description: "0 to 6282/1000".to_string(),
+            inputs: angles.clone(),
+            evaluate: |d_trig, input| d_trig.sine((input, 1000)).0,
+            exact: |input| reference::sine(input, 1000),
+            compatibility: Compatibility::Current,
+        },
+        Domain {
+            function: "cosine",
+            description: "0 to 6282/1000".to_string(),
+            inputs: angles.clone(),
+            evaluate: |d_trig, input| d_trig.cosine((input, 1000)).0,
+            exact: |input| reference::cosine(input, 1000),
+            compatibility: Compatibility::Current,
+        },
+        Domain {
+            function: "tangent",
+            description: "0 to 6282/1000".to_string(),
+            inputs: angles,
+            evaluate: |d_trig, input| d_trig.tangent((input, 1000)).0,
+            exact: |input| reference::tangent(input, 1000),
+            compatibility: Compatibility::Current,
+        },
+        Domain {
+            function: "arcsine",
+            description: "-1 to 1".to_string(),
+            inputs: values.clone(),
+            evaluate: |d_trig, input| d_trig.arcsine((input, 1000)).0,
+            exact: |input| reference::arcsine(input, 1000),
+            compatibility: Compatibility::Current,
+        },
+        Domain {
+            function: "arccosine",
+            description: "-1 to 1".to_string(),
+            inputs: values,
+            evaluate: |d_trig, input| d_trig.arccosine((input, 1000)).0,
+            exact: |input| reference::arccosine(input, 1000),
+            compatibility: Compatibility::Current,
+        }
+    ];
+
+    // Each arctangent tier is reported on its own, with both signs and the inputs on either side of each boundary, for
+    // the current results and for version 0.1, which truncated the negative inputs of the ones tier.
+    let tiers = [
+        ("thousandths tier", 0, 4000),
+        ("hundredths tier", 4001, 20000),
+        ("tenths tier", 20001, 100000),
+        ("ones tier", 100001, 1000000),
+        // Past 3374653/1000 the result stays at 1571/1000 and the error only shrinks.
+        ("past the tables", 1000001, 3400000),
+    ];
+
+    for (compatibility, version) in [(Compatibility::Current, "current"), (Compatibility::Version0_1, "version 0.1")] {
+        for (name, start, end) in tiers {
+            domains.push(Domain {
+                function: "arctangent",
+                description: format!("{}/1000 to {}/1000 and the negatives ({}; {})", start, end, name, version),
+                inputs: if start == 0 { (-end..=end).collect() } else { (-end..=-start).chain(start..=end).collect() },
+                evaluate: |d_trig, input| d_trig.arctangent((input, 1000)).0,
+                exact: |input| reference::arctangent(input, 1000),
+                compatibility,
+            });
+        }
+    }
+
+    for domain in domains {
+        let d_trig = match domain.compatibility {
+            Compatibility::Current => &d_trig,
+            Compatibility::Version0_1 => &legacy_d_trig,
+        };
+        domain.measure(d_trig, &mut output, &mut entries)?;
+    }
+
+    if let Some(mut file) = entries {
+        file.flush()?;
+    }
+
+    Ok(())
+}
+
+// This is one function over one list of inputs in thousandths, which makes one row of the summary.
+struct Domain {
+    function: &'static str,
+    description: String,
+    inputs: Vec<i64>,
+    evaluate: fn(&DTrig, i64) -> i32,
+    exact: fn(i64) -> Real,
+    compatibility: Compatibility,
+}
+
+impl Domain {
+    // This measures the error for every input and writes the summary row.
+    fn measure(
+        &self,
+        d_trig: &DTrig,
+        output: &mut impl Write,
+        entries: &mut Option<BufWriter<File>>
+    ) -> io::Result<()> {
+        let mut largest = Real::zero();
+        let mut total = Real::zero();
+        let mut worst_input = self.inputs[0];
+
+        for input in &self.inputs {
+            let result = (self.evaluate)(d_trig, *input);
+            let exact = (self.exact)(*input);
+            let error = Real::from_integer(i128::from(result)).div_small(1000).sub(&exact).abs();
+
+            if let Some(file) = entries.as_mut() {
+                writeln!(
+                    file,
+                    "{},{}/1000,{}/1000,{},{}",
+                    self.function,
+                    input,
+                    result,
+                    exact.to_decimal(12),
+                    error.to_decimal(12)
+                )?;
+            }
+
+            total = total.add(&error);
+            if error > largest {
+                largest = error;
+                worst_input = *input;
+            }
+        }
+
+        let mean = total.div_small(self.inputs.len() as u64);
+
+        writeln!(
+            output,
+            "{},{},{},{},{},{},{},{}/1000",
+            self.function,
+            self.description,
+            self.inputs.len(),
+            largest.to_decimal(9),
+            mean.to_decimal(9),
+            largest.mul_small(1000).to_decimal(6),
+            mean.mul_small(1000).to_decimal(6),
+            worst_input
+        )
+    }
+}
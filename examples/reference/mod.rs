@@ -0,0 +1,472 @@
+/* This module contains exact reference values for the accuracy report and the table regeneration tool. Every value is a
+fixed point number with 192 fractional bits held in a big integer, and every function is computed with integer series,
+so the reference does not depend on floating point arithmetic. Pi comes from Machin's formula, the sine and cosine from
+their Taylor series, the arctangent from Euler's series and the arcsine from its Taylor series, with the inputs kept as
+integer ratios so most steps only multiply or divide by small integers. The error of every value is far smaller than a
+billionth of a thousandth, so rounding a value to thousandths gives the correctly rounded result. */
+
+#![allow(dead_code)]
+
+use std::cmp::Ordering;
+use std::sync::OnceLock;
+
+// The number of bits after the binary point of every reference value.
+const FRACTION_BITS: usize = 192;
+
+// A non-negative integer of any size held as 64 bit limbs with the least significant limb first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Natural(Vec<u64>);
+
+impl Natural {
+    fn from_u128(value: u128) -> Natural {
+        Natural::trimmed(vec![value as u64, (value >> 64) as u64])
+    }
+
+    // Removes leading zero limbs so every value has one representation.
+    fn trimmed(mut limbs: Vec<u64>) -> Natural {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Natural(limbs)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn bits(&self) -> usize {
+        match self.0.last() {
+            Some(top) => self.0.len() * 64 - (top.leading_zeros() as usize),
+            None => 0,
+        }
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        self.0.get(index / 64).is_some_and(|limb| (limb >> (index % 64)) & 1 == 1)
+    }
+
+    fn to_u128(&self) -> u128 {
+        if self.0.len() > 2 {
+            panic!("Reference value too large for a u128.");
+        }
+        self.0.iter().rev().fold(0, |value, limb| (value << 64) | u128::from(*limb))
+    }
+
+    fn add(&self, other: &Natural) -> Natural {
+        let mut limbs = Vec::with_capacity(self.0.len().max(other.0.len()) + 1);
+        let mut carry = 0u128;
+
+        for index in 0..self.0.len().max(other.0.len()) {
+            let first = u128::from(*self.0.get(index).unwrap_or(&0));
+            let second = u128::from(*other.0.get(index).unwrap_or(&0));
+            let sum = first + second + carry;
+            limbs.push(sum as u64);
+            carry = sum >> 64;
+        }
+        limbs.push(carry as u64);
+
+        Natural::trimmed(limbs)
+    }
+
+    // Subtracts a value that is not larger than this one.
+    fn sub(&self, other: &Natural) -> Natural {
+        let mut limbs = Vec::with_capacity(self.0.len());
+        let mut borrow = 0u64;
+
+        for index in 0..self.0.len() {
+            let (difference, first) = self.0[index].overflowing_sub(*other.0.get(index).unwrap_or(&0));
+            let (difference, second) = difference.overflowing_sub(borrow);
+            limbs.push(difference);
+            borrow = u64::from(first || second);
+        }
+
+        if borrow != 0 || other.0.len() > self.0.len() {
+            panic!("Reference subtraction would be negative.");
+        }
+
+        Natural::trimmed(limbs)
+    }
+
+    fn mul(&self, other: &Natural) -> Natural {
+        let mut limbs = vec![0u64; self.0.len() + other.0.len()];
+
+        for (first_index, first) in self.0.iter().enumerate() {
+            let mut carry = 0u128;
+            for (second_index, second) in other.0.iter().enumerate() {
+                let product =
+                    u128::from(*first) * u128::from(*second) + u128::from(limbs[first_index + second_index]) + carry;
+                limbs[first_index + second_index] = product as u64;
+                carry = product >> 64;
+            }
+            limbs[first_index + other.0.len()] = carry as u64;
+        }
+
+        Natural::trimmed(limbs)
+    }
+
+    fn mul_small(&self, factor: u64) -> Natural {
+        let mut limbs = Vec::with_capacity(self.0.len() + 1);
+        let mut carry = 0u128;
+
+        for limb in &self.0 {
+            let product = u128::from(*limb) * u128::from(factor) + carry;
+            limbs.push(product as u64);
+            carry = product >> 64;
+        }
+        limbs.push(carry as u64);
+
+        Natural::trimmed(limbs)
+    }
+
+    // Divides by a small integer, rounding down.
+    fn div_small(&self, divisor: u64) -> Natural {
+        let mut limbs = vec![0u64; self.0.len()];
+        let mut remainder = 0u128;
+
+        for index in (0..self.0.len()).rev() {
+            let current = (remainder << 64) | u128::from(self.0[index]);
+            limbs[index] = (current / u128::from(divisor)) as u64;
+            remainder = current % u128::from(divisor);
+        }
+
+        Natural::trimmed(limbs)
+    }
+
+    fn shl(&self, bits: usize) -> Natural {
+        let mut limbs = vec![0u64; bits / 64];
+        let shift = bits % 64;
+        let mut carry = 0u64;
+
+        for limb in &self.0 {
+            limbs.push((limb << shift) | carry);
+            carry = if shift == 0 { 0 } else { limb >> (64 - shift) };
+        }
+        limbs.push(carry);
+
+        Natural::trimmed(limbs)
+    }
+
+    fn shr(&self, bits: usize) -> Natural {
+        let shift = bits % 64;
+        let source = &self.0[(bits / 64).min(self.0.len())..];
+        let mut limbs = Vec::with_capacity(source.len());
+
+        for index in 0..source.len() {
+            let high = if shift == 0 { 0 } else { source.get(index + 1).map_or(0, |limb| limb << (64 - shift)) };
+            limbs.push((source[index] >> shift) | high);
+        }
+
+        Natural::trimmed(limbs)
+    }
+
+    // Divides by another value one bit at a time, rounding down.
+    fn div(&self, divisor: &Natural) -> Natural {
+        if divisor.is_zero() {
+            panic!("Division by zero, which is undefined.");
+        }
+
+        let mut quotient = vec![0u64; self.0.len()];
+        let mut remainder = Natural(Vec::new());
+
+        for index in (0..self.bits()).rev() {
+            remainder = remainder.shl(1);
+            if self.bit(index) {
+                remainder = remainder.add(&Natural(vec![1]));
+            }
+            if remainder >= *divisor {
+                remainder = remainder.sub(divisor);
+                quotient[index / 64] |= 1 << (index % 64);
+            }
+        }
+
+        Natural::trimmed(quotient)
+    }
+
+    // Outputs the square root rounded down with Newton's method, starting above the root so it only decreases.
+    fn sqrt(&self) -> Natural {
+        if self.is_zero() {
+            return Natural(Vec::new());
+        }
+
+        let mut root = Natural(vec![1]).shl(self.bits().div_ceil(2));
+        loop {
+            let next = root.add(&self.div(&root)).shr(1);
+            if next >= root {
+                return root;
+            }
+            root = next;
+        }
+    }
+}
+
+impl Ord for Natural {
+    fn cmp(&self, other: &Natural) -> Ordering {
+        self.0.len().cmp(&other.0.len()).then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+impl PartialOrd for Natural {
+    fn partial_cmp(&self, other: &Natural) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A signed fixed point number with 192 fractional bits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Real {
+    negative: bool,
+    magnitude: Natural,
+}
+
+impl Real {
+    fn new(negative: bool, magnitude: Natural) -> Real {
+        Real { negative: negative && !magnitude.is_zero(), magnitude }
+    }
+
+    pub fn zero() -> Real {
+        Real::new(false, Natural(Vec::new()))
+    }
+
+    pub fn from_integer(value: i128) -> Real {
+        Real::new(value < 0, Natural::from_u128(value.unsigned_abs()).shl(FRACTION_BITS))
+    }
+
+    /// Outputs the ratio of two integers rounded toward zero to the last fractional bit.
+    pub fn from_ratio(numerator: i128, denominator: i128) -> Real {
+        let shifted = Natural::from_u128(numerator.unsigned_abs()).shl(FRACTION_BITS);
+        let magnitude = match u64::try_from(denominator.unsigned_abs()) {
+            Ok(divisor) => shifted.div_small(divisor),
+            Err(_) => shifted.div(&Natural::from_u128(denominator.unsigned_abs())),
+        };
+        Real::new((numerator < 0) != (denominator < 0), magnitude)
+    }
+
+    /// Outputs the square root of an integer.
+    pub fn sqrt_of_integer(value: u128) -> Real {
+        Real::new(false, Natural::from_u128(value).shl(2 * FRACTION_BITS).sqrt())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    pub fn neg(&self) -> Real {
+        Real::new(!self.negative, self.magnitude.clone())
+    }
+
+    pub fn abs(&self) -> Real {
+        Real::new(false, self.magnitude.clone())
+    }
+
+    pub fn add(&self, other: &Real) -> Real {
+        if self.negative == other.negative {
+            Real::new(self.negative, self.magnitude.add(&other.magnitude))
+        } else if self.magnitude >= other.magnitude {
+            Real::new(self.negative, self.magnitude.sub(&other.magnitude))
+        } else {
+            Real::new(other.negative, other.magnitude.sub(&self.magnitude))
+        }
+    }
+
+    pub fn sub(&self, other: &Real) -> Real {
+        self.add(&other.neg())
+    }
+
+    pub fn mul(&self, other: &Real) -> Real {
+        Real::new(self.negative != other.negative, self.magnitude.mul(&other.magnitude).shr(FRACTION_BITS))
+    }
+
+    pub fn div(&self, other: &Real) -> Real {
+        Real::new(self.negative != other.negative, self.magnitude.shl(FRACTION_BITS).div(&other.magnitude))
+    }
+
+    pub fn mul_small(&self, factor: u64) -> Real {
+        Real::new(self.negative, self.magnitude.mul_small(factor))
+    }
+
+    pub fn div_small(&self, divisor: u64) -> Real {
+        Real::new(self.negative, self.magnitude.div_small(divisor))
+    }
+
+    /// Outputs the nearest integer with halves rounded away from zero.
+    pub fn round(&self) -> i128 {
+        let half = Natural(vec![1]).shl(FRACTION_BITS - 1);
+        let magnitude = self.magnitude.add(&half).shr(FRACTION_BITS).to_u128() as i128;
+        if self.negative { -magnitude } else { magnitude }
+    }
+
+    /// Outputs the value as a decimal with a number of digits after the point, rounded to the nearest last digit.
+    pub fn to_decimal(&self, digits: u32) -> String {
+        let scaled = (0..digits).fold(self.abs(), |value, _| value.mul_small(10)).round();
+        let sign = if self.negative && scaled != 0 { "-" } else { "" };
+        let unit = 10i128.pow(digits);
+        format!("{}{}.{:0width$}", sign, scaled / unit, scaled % unit, width = digits as usize)
+    }
+}
+
+impl Ord for Real {
+    fn cmp(&self, other: &Real) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for Real {
+    fn partial_cmp(&self, other: &Real) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Converts a series factor to a u64, which every input the tools use fits in.
+fn small(value: u128) -> u64 {
+    u64::try_from(value).expect("Reference input too large for the series.")
+}
+
+/// Outputs pi from Machin's formula, pi = 16 arctan(1/5) - 4 arctan(1/239).
+pub fn pi() -> &'static Real {
+    static PI: OnceLock<Real> = OnceLock::new();
+
+    PI.get_or_init(|| arctangent_of_inverse(5).mul_small(16).sub(&arctangent_of_inverse(239).mul_small(4)))
+}
+
+/// Outputs pi over 2.
+pub fn half_pi() -> Real {
+    pi().div_small(2)
+}
+
+// Outputs the arctangent of 1/m from its Taylor series.
+fn arctangent_of_inverse(m: u64) -> Real {
+    let mut power = Real::from_ratio(1, i128::from(m));
+    let mut sum = Real::zero();
+    let mut index = 0u64;
+
+    while !power.is_zero() {
+        let term = power.div_small(2 * index + 1);
+        sum = if index.is_multiple_of(2) { sum.add(&term) } else { sum.sub(&term) };
+        power = power.div_small(m * m);
+        index += 1;
+    }
+
+    sum
+}
+
+/// Outputs the sine of numerator/denominator radians.
+pub fn sine(numerator: i64, denominator: u64) -> Real {
+    let square = small(u128::from(numerator.unsigned_abs()).pow(2));
+    let denominator_square = u128::from(denominator).pow(2);
+
+    let mut term = Real::from_ratio(i128::from(numerator), i128::from(denominator));
+    let mut sum = term.clone();
+    let mut index = 1u128;
+
+    while !term.is_zero() {
+        term = term.mul_small(square).div_small(small(denominator_square * (2 * index) * (2 * index + 1))).neg();
+        sum = sum.add(&term);
+        index += 1;
+    }
+
+    sum
+}
+
+/// Outputs the cosine of numerator/denominator radians.
+pub fn cosine(numerator: i64, denominator: u64) -> Real {
+    let square = small(u128::from(numerator.unsigned_abs()).pow(2));
+    let denominator_square = u128::from(denominator).pow(2);
+
+    let mut term = Real::from_integer(1);
+    let mut sum = term.clone();
+    let mut index = 1u128;
+
+    while !term.is_zero() {
+        term = term.mul_small(square).div_small(small(denominator_square * (2 * index - 1) * (2 * index))).neg();
+        sum = sum.add(&term);
+        index += 1;
+    }
+
+    sum
+}
+
+/// Outputs the tangent of numerator/denominator radians.
+pub fn tangent(numerator: i64, denominator: u64) -> Real {
+    sine(numerator, denominator).div(&cosine(numerator, denominator))
+}
+
+/// Outputs the arctangent of numerator/denominator.
+pub fn arctangent(numerator: i64, denominator: u64) -> Real {
+    if numerator < 0 {
+        return arctangent(-numerator, denominator).neg();
+    }
+
+    let numerator = numerator as u64;
+    if numerator > denominator {
+        // Uses arctan(x) = pi/2 - arctan(1/x) so the series always converges at least as fast as powers of 1/2.
+        return half_pi().sub(&arctangent(denominator as i64, numerator));
+    }
+
+    // Euler's series, arctan(x) = sum of (2^2k (k!)^2 / (2k + 1)!) x^(2k + 1) / (1 + x^2)^(k + 1).
+    let square = u128::from(numerator).pow(2);
+    let sum_of_squares = square + u128::from(denominator).pow(2);
+
+    let mut term = Real::from_ratio(i128::from(numerator) * i128::from(denominator), sum_of_squares as i128);
+    let mut sum = term.clone();
+    let mut index = 1u128;
+
+    while !term.is_zero() {
+        term = term.mul_small(small(2 * index * square)).div_small(small((2 * index + 1) * sum_of_squares));
+        sum = sum.add(&term);
+        index += 1;
+    }
+
+    sum
+}
+
+// Outputs the sum of the arcsine series without its leading factor, the sum of
+// ((2k)! / (4^k (k!)^2 (2k + 1))) (numerator/denominator)^k for a ratio of at most one half.
+fn arcsine_series(numerator: u128, denominator: u128) -> Real {
+    let mut term = Real::from_integer(1);
+    let mut sum = term.clone();
+    let mut index = 1u128;
+
+    while !term.is_zero() {
+        term = term
+            .mul_small(small(numerator * (2 * index - 1) * (2 * index - 1)))
+            .div_small(small(denominator * (2 * index) * (2 * index + 1)));
+        sum = sum.add(&term);
+        index += 1;
+    }
+
+    sum
+}
+
+/// Outputs the arcsine of numerator/denominator, which must be from -1 to 1.
+pub fn arcsine(numerator: i64, denominator: u64) -> Real {
+    if numerator < 0 {
+        return arcsine(-numerator, denominator).neg();
+    }
+
+    let square = u128::from(numerator as u64).pow(2);
+    let denominator_square = u128::from(denominator).pow(2);
+
+    if square > denominator_square {
+        panic!("Reference arcsine input outside of -1 to 1.");
+    }
+
+    if 2 * square <= denominator_square {
+        // arcsin(x) = x times the series in x^2.
+        arcsine_series(square, denominator_square).mul_small(numerator as u64).div_small(denominator)
+    } else {
+        // arcsin(x) = pi/2 - arcsin(sqrt(1 - x^2)), where the series only needs the rational 1 - x^2.
+        let complement = denominator_square - square;
+        let root = Real::sqrt_of_integer(complement).div_small(denominator);
+        half_pi().sub(&arcsine_series(complement, denominator_square).mul(&root))
+    }
+}
+
+/// Outputs the arccosine of numerator/denominator, which must be from -1 to 1.
+pub fn arccosine(numerator: i64, denominator: u64) -> Real {
+    half_pi().sub(&arcsine(numerator, denominator))
+}
@@ -0,0 +1,161 @@
+use std::fs;
+use std::process::ExitCode;
+
+mod reference;
+use reference::Real;
+
+/* This is a tool that checks every literal in the pre-baked tables of src/initialize.rs. It recomputes each entry
+from the exact integer reference, rounds it to the nearest thousandth, and lists every entry of the shipped tables that
+is not the correctly rounded value. It exits with an error code if any entry differs, so it can run as a check:
+
+cargo run --release --example regenerate_tables
+
+Adding "--write initialize.rs" also writes a copy of src/initialize.rs with every table entry replaced by the
+regenerated value. The rest of the file, including the layout of the tables, is copied unchanged, so the copy is
+identical to the original when every entry is already correct. */
+fn main() -> ExitCode {
+    let arguments: Vec<String> = std::env::args().collect();
+
+    let output_path = arguments
+        .iter()
+        .position(|argument| argument == "--write")
+        .map(|index| arguments.get(index + 1).expect("The --write option needs a file name.").clone());
+
+    // This checks the reference against known digits of pi before any entry is computed with it.
+    assert_eq!(reference::pi().to_decimal(30), "3.141592653589793238462643383280");
+
+    let source_path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/initialize.rs");
+    let source = fs::read_to_string(source_path).expect("Could not read src/initialize.rs.");
+
+    // Each table entry at an index is the function of (index + offset) / denominator, in thousandths.
+    let tables = [
+        Table { name: "sine_array", length: 6283, offset: 0, denominator: 1000, exact: reference::sine },
+        Table { name: "cosine_array", length: 6283, offset: 0, denominator: 1000, exact: reference::cosine },
+        Table { name: "tangent_array", length: 6283, offset: 0, denominator: 1000, exact: reference::tangent },
+        Table { name: "arcsine_array", length: 2001, offset: -1000, denominator: 1000, exact: reference::arcsine },
+        Table { name: "arccosine_array", length: 2001, offset: -1000, denominator: 1000, exact: reference::arccosine },
+        Table {
+            name: "arctangent_thousandths",
+            length: 8001,
+            offset: -4000,
+            denominator: 1000,
+            exact: reference::arctangent,
+        },
+        Table {
+            name: "arctangent_hundredths",
+            length: 4001,
+            offset: -2000,
+            denominator: 100,
+            exact: reference::arctangent,
+        },
+        Table { name: "arctangent_tenths", length: 2001, offset: -1000, denominator: 10, exact: reference::arctangent },
+        Table { name: "arctangent_ones", length: 2001, offset: -1000, denominator: 1, exact: reference::arctangent },
+    ];
+
+    let mut regenerated = source.clone();
+    let mut total_mismatches = 0;
+
+    for table in &tables {
+        let (start, end) = table.locate(&regenerated);
+        let shipped = parse_entries(&regenerated[start..end]);
+        assert_eq!(shipped.len(), table.length, "{} does not have {} entries.", table.name, table.length);
+
+        let mut correct = Vec::with_capacity(table.length);
+        let mut mismatches = 0;
+
+        for (index, shipped_entry) in shipped.iter().enumerate() {
+            let input = index as i64 + table.offset;
+            let exact = (table.exact)(input, table.denominator);
+            let entry = exact.mul_small(1000).round();
+
+            if entry != *shipped_entry {
+                println!(
+                    "{}[{}] at {}/{} is {} but should be {}, as the exact value is {}/1000",
+                    table.name,
+                    index,
+                    input,
+                    table.denominator,
+                    shipped_entry,
+                    entry,
+                    exact.mul_small(1000).to_decimal(6)
+                );
+                mismatches += 1;
+            }
+
+            correct.push(entry);
+        }
+
+        println!("{}: {} entries, {} not correctly rounded", table.name, table.length, mismatches);
+        total_mismatches += mismatches;
+
+        regenerated.replace_range(start..end, &replace_entries(&regenerated[start..end], &correct));
+    }
+
+    if let Some(path) = output_path {
+        fs::write(&path, &regenerated).expect("Could not write the regenerated file.");
+        println!("Wrote the regenerated tables to {}.", path);
+    }
+
+    if total_mismatches == 0 { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
+
+// This is one pre-baked table and the exact function its entries are rounded from.
+struct Table {
+    name: &'static str,
+    length: usize,
+    offset: i64,
+    denominator: u64,
+    exact: fn(i64, u64) -> Real,
+}
+
+impl Table {
+    // This finds the text between the brackets of the table in the source of initialize.rs.
+    fn locate(&self, source: &str) -> (usize, usize) {
+        let field = source
+            .find(&format!("{}: {{", self.name))
+            .unwrap_or_else(|| panic!("Could not find {} in src/initialize.rs.", self.name));
+        let start = field + source[field..].find('[').expect("The table has no opening bracket.") + 1;
+        let end = start + source[start..].find(']').expect("The table has no closing bracket.");
+        (start, end)
+    }
+}
+
+// This reads every integer literal in the text of a table.
+fn parse_entries(text: &str) -> Vec<i128> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| entry.parse().unwrap_or_else(|_| panic!("Could not read the table entry {}.", entry)))
+        .collect()
+}
+
+// This swaps every integer literal in the text of a table for the matching new entry and keeps everything else. A
+// literal that already has the right value is kept as written, so the "-0" entries of the shipped tables stay as-is.
+fn replace_entries(text: &str, entries: &[i128]) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut entries = entries.iter();
+
+    for (index, piece) in text.split(',').enumerate() {
+        if index > 0 {
+            output.push(',');
+        }
+
+        let trimmed = piece.trim();
+        if trimmed.is_empty() {
+            output.push_str(piece);
+        } else {
+            let leading = &piece[..piece.len() - piece.trim_start().len()];
+            let trailing = &piece[piece.trim_end().len()..];
+            let entry = entries.next().expect("The table has more literals than entries.");
+            output.push_str(leading);
+            if trimmed.parse::<i128>() == Ok(*entry) {
+                output.push_str(trimmed);
+            } else {
+                output.push_str(&entry.to_string());
+            }
+            output.push_str(trailing);
+        }
+    }
+
+    output
+}
@@ -0,0 +1,22 @@
+#![no_main]
+
+/* This fuzz target checks that the arctangent never falls and never jumps by more than 1/1000 per thousandth, with the
+inputs drawn toward the tier boundaries at 4, 20, 100 and 1000 and the end of the tables, with both compatibility
+settings. */
+
+use std::sync::OnceLock;
+
+use deterministic_trigonometry::DTrig;
+use libfuzzer_sys::fuzz_target;
+
+#[path = "../../tests/fuzz_checks/mod.rs"]
+mod fuzz_checks;
+
+// The tables are only initialized once for the whole run.
+static D_TRIGS: OnceLock<Vec<DTrig>> = OnceLock::new();
+
+fuzz_target!(|input: fuzz_checks::TierInput| {
+    for d_trig in D_TRIGS.get_or_init(fuzz_checks::d_trig_for_every_compatibility) {
+        fuzz_checks::check_arctangent_tiers(d_trig, input);
+    }
+});
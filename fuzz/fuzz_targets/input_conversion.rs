@@ -0,0 +1,20 @@
+#![no_main]
+
+/* This fuzz target checks that fractions of i64 values are rounded to thousandths correctly with every rounding mode,
+and that no input other than a documented domain error panics or overflows. */
+
+use std::sync::OnceLock;
+
+use deterministic_trigonometry::DTrig;
+use libfuzzer_sys::fuzz_target;
+
+#[path = "../../tests/fuzz_checks/mod.rs"]
+mod fuzz_checks;
+
+// The tables are only initialized once for the whole run.
+static D_TRIGS: OnceLock<Vec<DTrig>> = OnceLock::new();
+
+fuzz_target!(|input: fuzz_checks::ConversionInput| {
+    let d_trigs = D_TRIGS.get_or_init(fuzz_checks::d_trig_for_every_rounding_mode);
+    fuzz_checks::check_conversion(d_trigs, input);
+});
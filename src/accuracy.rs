@@ -0,0 +1,504 @@
+/* This module contains the guaranteed error bounds of the trig functions. The error of a result comes from three
+places: the rounding of the input to thousandths, the normalization of angles outside of 0 to 6282 thousandths, and
+the rounding of the pre-baked tables. The table errors were measured against a high precision reference and are checked
+by the tests below. The other two are worked out from the rounding mode and the input range, and the effect of an input
+error on the result is bounded with the tables themselves, so no floating point arithmetic is used. Bounds are computed
+in millionths and rounded up to thousandths so they can be added to and subtracted from the results directly. */
+
+use std::ops::RangeInclusive;
+
+use crate::reduction;
+use crate::{ Compatibility, DTrig, Fraction, IntoFraction, RoundingMode };
+
+// The largest difference in millionths between a table entry and the exact value it represents. The tables of sine,
+// cosine, tangent, arcsine and arccosine are correctly rounded to thousandths.
+const TABLE_ERROR: i128 = 500;
+
+// The largest difference in millionths between the arctangent of an input in thousandths and the exact arctangent. This
+// is larger than the table error because the tiers past 4 round the input to hundredths, tenths or ones.
+const ARCTANGENT_ERROR: i128 = 755;
+
+// An upper bound on the error in billionths of a millionth of a radian that the normalization of version 0.1 adds for
+// every turn, from the 12 digit value of 2 PI it uses.
+const NORMALIZATION_ERROR_PER_TURN: i128 = 414;
+
+/// A trig function, used to ask for its error bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Function {
+    /// The `DTrig::sine` function.
+    Sine,
+    /// The `DTrig::cosine` function.
+    Cosine,
+    /// The `DTrig::tangent` function.
+    Tangent,
+    /// The `DTrig::arcsine` function.
+    Arcsine,
+    /// The `DTrig::arccosine` function.
+    Arccosine,
+    /// The `DTrig::arctangent` function.
+    Arctangent,
+}
+
+/// The largest possible difference between a result and the exact value of the function at the exact input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ErrorBound {
+    /// The exact value is no further than this fraction out of 1000 from the result.
+    Within(Fraction),
+    /// There is no finite bound, such as for a tangent whose input could be on either side of an asymptote.
+    Unbounded,
+}
+
+/// A result together with an interval that is guaranteed to contain the exact value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundedResult {
+    /// The result, the same as the function without a bound gives.
+    pub value: Fraction,
+    /// The smallest value the exact result could have.
+    pub lower: Fraction,
+    /// The largest value the exact result could have.
+    pub upper: Fraction,
+}
+
+impl DTrig {
+    /// Outputs a bound on the error of a function for every input in a range.
+    ///
+    /// - The bound covers the rounding of the input, the normalization of large angles and the rounding of the tables.
+    /// - Depends on the rounding mode, since floor, ceiling and truncate rounding can move an input a full thousandth.
+    /// - The bound for the tangent is `Unbounded` if an input in the range could be within reach of an asymptote.
+    /// - Only inputs from -1 to 1 are covered for the arcsine and arccosine.
+    /// - An empty range has a bound of zero.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator of either end of the range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::DTrig;
+    /// use deterministic_trigonometry::accuracy::{ ErrorBound, Function };
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize();
+    ///
+    /// let bound = d_trig.max_error(Function::Sine, (0, 1000)..=(6282, 1000));
+    ///
+    /// if let ErrorBound::Within(error) = bound {
+    ///     println!("The sine is within {} of the exact value from 0 to 6282/1000 radians.", error);
+    /// }
+    ///
+    /// }
+    /// ```
+    pub fn max_error<F: IntoFraction + Copy>(&self, function: Function, input_range: RangeInclusive<F>) -> ErrorBound {
+        let start = self.to_thousandths(*input_range.start());
+        let end = self.to_thousandths(*input_range.end());
+
+        if start > end {
+            return ErrorBound::Within(Fraction::ZERO);
+        }
+
+        self.bound_thousandths(function, start, end)
+    }
+
+    /// Calculates the sine of an angle in radians along with an interval that contains the exact sine.
+    ///
+    /// - The value is the same as `sine` gives.
+    /// - See `max_error` for what the interval covers.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::DTrig;
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize();
+    ///
+    /// let sine = d_trig.sine_with_bound((785, 1000));
+    ///
+    /// println!("The sine of 785/1000 radians is {}, between {} and {}.", sine.value, sine.lower, sine.upper);
+    ///
+    /// }
+    /// ```
+    pub fn sine_with_bound<F: IntoFraction + Copy>(&self, argument_fraction: F) -> BoundedResult {
+        self.with_bound(Function::Sine, self.sine(argument_fraction), argument_fraction, (-1000, 1000))
+    }
+
+    /// Calculates the cosine of an angle in radians along with an interval that contains the exact cosine.
+    ///
+    /// - The value is the same as `cosine` gives.
+    /// - See `max_error` for what the interval covers.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator.
+    pub fn cosine_with_bound<F: IntoFraction + Copy>(&self, argument_fraction: F) -> BoundedResult {
+        self.with_bound(Function::Cosine, self.cosine(argument_fraction), argument_fraction, (-1000, 1000))
+    }
+
+    /// Calculates the tangent of an angle in radians along with an interval that contains the exact tangent.
+    ///
+    /// - The value is the same as `tangent` gives.
+    /// - Outputs None if the input could be within reach of an asymptote, where no interval is possible.
+    /// - See `max_error` for what the interval covers.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator.
+    pub fn tangent_with_bound<F: IntoFraction + Copy>(&self, argument_fraction: F) -> Option<BoundedResult> {
+        let thousandths = self.to_thousandths(argument_fraction);
+
+        match self.bound_thousandths(Function::Tangent, thousandths, thousandths) {
+            ErrorBound::Within(_) => {
+                let value = self.tangent(argument_fraction);
+                Some(self.with_bound(Function::Tangent, value, argument_fraction, (i32::MIN, i32::MAX)))
+            }
+            ErrorBound::Unbounded => None,
+        }
+    }
+
+    /// Calculates the arcsine of a value along with an interval that contains the exact arcsine.
+    ///
+    /// - The value is the same as `arcsine` gives.
+    /// - See `max_error` for what the interval covers.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator.
+    /// - An input that is less than -1 or greater than 1 after rounding to thousandths.
+    pub fn arcsine_with_bound<F: IntoFraction + Copy>(&self, argument_fraction: F) -> BoundedResult {
+        self.with_bound(Function::Arcsine, self.arcsine(argument_fraction), argument_fraction, (-1571, 1571))
+    }
+
+    /// Calculates the arccosine of a value along with an interval that contains the exact arccosine.
+    ///
+    /// - The value is the same as `arccosine` gives.
+    /// - See `max_error` for what the interval covers.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator.
+    /// - An input that is less than -1 or greater than 1 after rounding to thousandths.
+    pub fn arccosine_with_bound<F: IntoFraction + Copy>(&self, argument_fraction: F) -> BoundedResult {
+        self.with_bound(Function::Arccosine, self.arccosine(argument_fraction), argument_fraction, (0, 3142))
+    }
+
+    /// Calculates the arctangent of a value along with an interval that contains the exact arctangent.
+    ///
+    /// - The value is the same as `arctangent` gives.
+    /// - See `max_error` for what the interval covers.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator.
+    pub fn arctangent_with_bound<F: IntoFraction + Copy>(&self, argument_fraction: F) -> BoundedResult {
+        self.with_bound(Function::Arctangent, self.arctangent(argument_fraction), argument_fraction, (-1571, 1571))
+    }
+
+    // Puts the interval around a result, clamped to the values the exact function can have in thousandths.
+    fn with_bound<F: IntoFraction>(
+        &self,
+        function: Function,
+        value: Fraction,
+        argument_fraction: F,
+        limits: (i32, i32)
+    ) -> BoundedResult {
+        let thousandths = self.to_thousandths(argument_fraction);
+
+        let error = match self.bound_thousandths(function, thousandths, thousandths) {
+            ErrorBound::Within(error) => error.0,
+            ErrorBound::Unbounded => i32::MAX,
+        };
+
+        BoundedResult {
+            value,
+            lower: Fraction(value.0.saturating_sub(error).max(limits.0), 1000),
+            upper: Fraction(value.0.saturating_add(error).min(limits.1), 1000),
+        }
+    }
+
+    // Outputs the bound for every input that rounds to between two numbers of thousandths.
+    fn bound_thousandths(&self, function: Function, start: i128, end: i128) -> ErrorBound {
+        // The input error in millionths of a radian or of a unit.
+        let input_error = match self.rounding {
+            RoundingMode::HalfAwayFromZero | RoundingMode::HalfEven => 500,
+            RoundingMode::Floor | RoundingMode::Ceiling | RoundingMode::Truncate => 1000,
+        };
+
+        let millionths = match function {
+            Function::Sine | Function::Cosine => {
+                // Neither the result nor the exact value can be outside of -1 to 1.
+                (TABLE_ERROR + input_error + self.normalization_error(start, end)).min(2000000)
+            }
+            Function::Tangent => {
+                match self.tangent_spread(start, end, input_error + self.normalization_error(start, end)) {
+                    Some(spread) => 3 * TABLE_ERROR + spread * 1000,
+                    None => {
+                        return ErrorBound::Unbounded;
+                    }
+                }
+            }
+            Function::Arcsine | Function::Arccosine => {
+                let table = if function == Function::Arcsine { &self.arcsine_array } else { &self.arccosine_array };
+                3 * TABLE_ERROR + arc_spread(table, start, end) * 1000
+            }
+            Function::Arctangent => {
+                // The arctangent changes by no more than its input does.
+                ARCTANGENT_ERROR + input_error
+            }
+        };
+
+        // Rounds up to a whole number of thousandths.
+        match i32::try_from((millionths + 999) / 1000) {
+            Ok(thousandths) => ErrorBound::Within(Fraction(thousandths, 1000)),
+            Err(_) => ErrorBound::Unbounded,
+        }
+    }
+
+    // Outputs the largest change in thousandths of the tangent table within a number of millionths of any index the
+    // inputs can normalize to, or None if an asymptote could be within reach.
+    fn tangent_spread(&self, start: i128, end: i128, reach: i128) -> Option<i128> {
+        let steps = (reach + 999) / 1000;
+        if steps >= 3141 {
+            return None;
+        }
+
+        let indices: Vec<i64> = if end - start >= 6282 {
+            (0..6283).collect()
+        } else {
+            (start..=end).map(|thousandths| reduction::normalize_angle(thousandths, self.compatibility)).collect()
+        };
+
+        let entry = |index: i64| i128::from(self.tangent_array[index.rem_euclid(6283) as usize]);
+        let mut spread = 0;
+
+        for index in indices {
+            // The asymptotes are between 1570 and 1571 and between 4712 and 4713.
+            if
+                ((index - steps as i64)..(index + steps as i64)).any(|step| {
+                    let step = step.rem_euclid(6283);
+                    step == 1570 || step == 4712
+                })
+            {
+                return None;
+            }
+
+            // The tangent only increases between asymptotes, so the furthest entries are the largest changes.
+            spread = spread
+                .max((entry(index + steps as i64) - entry(index)).abs())
+                .max((entry(index) - entry(index - steps as i64)).abs());
+        }
+
+        Some(spread)
+    }
+
+    // Outputs the error in millionths that normalization can add for inputs between two numbers of thousandths.
+    fn normalization_error(&self, start: i128, end: i128) -> i128 {
+        if start >= 0 && end <= 6282 {
+            return 0;
+        }
+
+        match self.compatibility {
+            // The exact reduction is off by far less than a millionth before the reduced angle is rounded to a
+            // thousandth.
+            Compatibility::Current => 501,
+            // The reduced angle is rounded to a thousandth and each turn adds the error of the 12 digit value of 2 PI.
+            Compatibility::Version0_1 => {
+                let turns = start.abs().max(end.abs()) / 6283 + 1;
+                500 + (turns * NORMALIZATION_ERROR_PER_TURN + 999999999) / 1000000000
+            }
+        }
+    }
+}
+
+
+// Outputs the largest change in thousandths between neighboring entries of an arcsine or arccosine table for the inputs
+// from -1 to 1 between two numbers of thousandths.
+fn arc_spread(table: &[i16; 2001], start: i128, end: i128) -> i128 {
+    let start = start.max(-1000) + 1000;
+    let end = end.min(1000) + 1000;
+
+    (start..=end)
+        .map(|index| {
+            let entry = |index: i128| i128::from(table[index.clamp(0, 2000) as usize]);
+            (entry(index + 1) - entry(index)).abs().max((entry(index) - entry(index - 1)).abs())
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DRng;
+
+    const MODES: [RoundingMode; 5] = [
+        RoundingMode::HalfAwayFromZero,
+        RoundingMode::HalfEven,
+        RoundingMode::Floor,
+        RoundingMode::Ceiling,
+        RoundingMode::Truncate,
+    ];
+
+    // Outputs the exact value of a function in thousandths, which f64 gives to far better than a millionth here.
+    fn exact(function: Function, value: f64) -> f64 {
+        1000.0 *
+            (match function {
+                Function::Sine => value.sin(),
+                Function::Cosine => value.cos(),
+                Function::Tangent => value.tan(),
+                Function::Arcsine => value.asin(),
+                Function::Arccosine => value.acos(),
+                Function::Arctangent => value.atan(),
+            })
+    }
+
+    fn result(d_trig: &DTrig, function: Function, input: (i64, i64)) -> i32 {
+        (match function {
+            Function::Sine => d_trig.sine(input),
+            Function::Cosine => d_trig.cosine(input),
+            Function::Tangent => d_trig.tangent(input),
+            Function::Arcsine => d_trig.arcsine(input),
+            Function::Arccosine => d_trig.arccosine(input),
+            Function::Arctangent => d_trig.arctangent(input),
+        }).0
+    }
+
+    #[test]
+    fn test_table_errors() {
+        let d_trig = DTrig::initialize();
+
+        for index in 0..6283 {
+            for function in [Function::Sine, Function::Cosine, Function::Tangent] {
+                let value = result(&d_trig, function, (index, 1000)) as f64;
+                let error = exact(function, (index as f64) / 1000.0) - value;
+                assert!(error.abs() * 1000.0 <= TABLE_ERROR as f64, "{:?} {}", function, index);
+            }
+        }
+
+        for index in -1000..1001 {
+            for function in [Function::Arcsine, Function::Arccosine] {
+                let value = result(&d_trig, function, (index, 1000)) as f64;
+                let error = exact(function, (index as f64) / 1000.0) - value;
+                assert!(error.abs() * 1000.0 <= TABLE_ERROR as f64, "{:?} {}", function, index);
+            }
+        }
+
+        // Past 3374653 thousandths the result stays at 1571/1000 and the error only shrinks.
+        for index in -3400000..3400001 {
+            let error =
+                (exact(Function::Arctangent, (index as f64) / 1000.0) -
+                    (result(&d_trig, Function::Arctangent, (index, 1000)) as f64)).abs();
+            assert!(error * 1000.0 <= ARCTANGENT_ERROR as f64, "{}", index);
+        }
+    }
+
+    #[test]
+    fn test_bounds_hold() {
+        let functions = [
+            Function::Sine,
+            Function::Cosine,
+            Function::Tangent,
+            Function::Arcsine,
+            Function::Arccosine,
+            Function::Arctangent,
+        ];
+        let mut rng = DRng::new(41);
+
+        for mode in MODES {
+            let d_trig = DTrig::initialize().with_rounding(mode);
+
+            for _ in 0..20000 {
+                let denominator = rng.next_in_range(1, 100000) as i64;
+                let numerator = rng.next_in_range(-20 * (denominator as i32), 20 * (denominator as i32)) as i64;
+
+                for function in functions {
+                    let numerator = if function == Function::Arcsine || function == Function::Arccosine {
+                        numerator % (denominator + 1)
+                    } else {
+                        numerator
+                    };
+
+                    let input = (numerator, denominator);
+
+                    if let ErrorBound::Within(error) = d_trig.max_error(function, input..=input) {
+                        let exact = exact(function, (numerator as f64) / (denominator as f64));
+                        let value = result(&d_trig, function, input) as f64;
+                        let message = format!("{:?} {:?} {:?}", mode, function, input);
+                        assert!((exact - value).abs() <= (error.0 as f64) + 1e-6, "{}", message);
+                    } else {
+                        assert_eq!(function, Function::Tangent);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bounds_for_ranges() {
+        let d_trig = DTrig::initialize();
+        let floor = DTrig::initialize().with_rounding(RoundingMode::Floor);
+
+        assert_eq!(d_trig.max_error(Function::Sine, (0, 1000)..=(6282, 1000)), ErrorBound::Within(Fraction(1, 1000)));
+        assert_eq!(d_trig.max_error(Function::Cosine, (-1, 1)..=(1, 1)), ErrorBound::Within(Fraction(2, 1000)));
+        assert_eq!(floor.max_error(Function::Sine, (0, 1000)..=(6282, 1000)), ErrorBound::Within(Fraction(2, 1000)));
+        let everything = (i64::MIN, 1)..=(i64::MAX, 1);
+        assert_eq!(d_trig.max_error(Function::Arctangent, everything.clone()), ErrorBound::Within(Fraction(2, 1000)));
+        assert_eq!(d_trig.max_error(Function::Sine, everything.clone()), ErrorBound::Within(Fraction(2, 1000)));
+        assert_eq!(
+            DTrig::initialize().with_compatibility(Compatibility::Version0_1).max_error(Function::Sine, everything),
+            ErrorBound::Within(Fraction(2000, 1000))
+        );
+        assert_eq!(d_trig.max_error(Function::Sine, (1, 1)..=(0, 1)), ErrorBound::Within(Fraction::ZERO));
+
+        // The tangent is bounded away from the asymptotes but not across one.
+        assert_eq!(d_trig.max_error(Function::Tangent, (0, 1000)..=(785, 1000)), ErrorBound::Within(Fraction(4, 1000)));
+        assert!(matches!(d_trig.max_error(Function::Tangent, (1500, 1000)..=(1560, 1000)), ErrorBound::Within(_)));
+        assert_eq!(d_trig.max_error(Function::Tangent, (1500, 1000)..=(1600, 1000)), ErrorBound::Unbounded);
+        assert_eq!(d_trig.max_error(Function::Tangent, (0, 1)..=(7, 1)), ErrorBound::Unbounded);
+        assert_eq!(d_trig.max_error(Function::Tangent, (1571, 1000)..=(1571, 1000)), ErrorBound::Unbounded);
+
+        // The arcsine changes quickly near 1, so inputs there have a wider bound.
+        let middle = d_trig.max_error(Function::Arcsine, (-1, 2)..=(1, 2));
+        let edge = d_trig.max_error(Function::Arcsine, (999, 1000)..=(1, 1));
+        assert_eq!(middle, ErrorBound::Within(Fraction(4, 1000)));
+        assert!(matches!(edge, ErrorBound::Within(Fraction(error, 1000)) if error > 30));
+    }
+
+    #[test]
+    fn test_with_bound() {
+        let d_trig = DTrig::initialize();
+
+        let sine = d_trig.sine_with_bound((785, 1000));
+        assert_eq!(
+            sine,
+            BoundedResult { value: Fraction(707, 1000), lower: Fraction(706, 1000), upper: Fraction(708, 1000) }
+        );
+
+        let cosine = d_trig.cosine_with_bound((0, 1));
+        assert_eq!((cosine.lower, cosine.upper), (Fraction(999, 1000), Fraction(1000, 1000)));
+
+        let arccosine = d_trig.arccosine_with_bound((1, 1));
+        assert_eq!(arccosine.lower, Fraction(0, 1000));
+
+        assert_eq!(d_trig.arctangent_with_bound((1, 1)).value, d_trig.arctangent((1, 1)));
+        assert_eq!(d_trig.arcsine_with_bound((1, 2)).value, d_trig.arcsine((1, 2)));
+        assert!(d_trig.tangent_with_bound((1571, 1000)).is_none());
+        assert_eq!(d_trig.tangent_with_bound((1, 1)).unwrap().value, d_trig.tangent((1, 1)));
+
+        // The interval holds the exact value.
+        for numerator in -7000..7000 {
+            let sine = d_trig.sine_with_bound((numerator, 997));
+            let exact = exact(Function::Sine, (numerator as f64) / 997.0);
+            assert!((sine.lower.0 as f64) <= exact && exact <= (sine.upper.0 as f64));
+        }
+    }
+}
@@ -0,0 +1,604 @@
+/* This module contains the angle type and helper functions for working with headings. Angles are normalized the same
+way the trig functions of a DTrig with the default settings normalize them, so every angle lands on one of the 6283
+thousandth radian steps from 0 to 6282. Because there is an odd number of steps, every pair of angles has exactly one
+shortest way around and ties never need to be broken.
+
+The helpers always reduce angles exactly. They did not exist in version 0.1, so Compatibility::Version0_1 has no
+results of theirs to keep and does not change them, not even for lerp_angle_eased, which only uses its DTrig for the
+sine that eases the amount. */
+
+use crate::reduction;
+use crate::utility;
+use crate::{ DTrig, Fraction, IntoFraction };
+
+// The number of thousandth radian steps in a full turn after normalization.
+const STEPS_PER_TURN: i64 = 6283;
+
+// The largest number of steps in either direction that is still the shortest way around.
+const HALF_TURN_STEPS: i64 = 3141;
+
+/// An angle in radians held as a whole number of thousandths.
+///
+/// - Holds the same thousandths the trig functions round their inputs to, so no rounding is needed when it is used.
+/// - The angle is not normalized, so 7000/1000 and 717/1000 are different angles with the same sine.
+/// - Can be passed to any function that accepts a fraction.
+/// - Used by the slice functions such as `DTrig::sine_slice` to evaluate many angles at once.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::{ Angle, DTrig };
+///
+/// fn main (){
+///
+/// let d_trig = DTrig::initialize();
+///
+/// let heading = Angle::from_fraction((1, 3));
+///
+/// let sine = d_trig.sine(heading);
+///
+/// println!("The sine of {} radians is {}.", heading, sine);
+///
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Angle(i32);
+
+impl Angle {
+    /// An angle of zero radians.
+    pub const ZERO: Angle = Angle(0);
+
+    /// Creates an angle from a whole number of thousandths of a radian.
+    pub const fn from_thousandths(thousandths: i32) -> Self {
+        Angle(thousandths)
+    }
+
+    /// Creates an angle from a fraction of radians rounded to the nearest thousandth.
+    ///
+    /// - Rounds halves away from zero, the same as the trig functions do by default.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator.
+    /// - An angle whose number of thousandths does not fit in an i32.
+    pub fn from_fraction<F: IntoFraction>(angle_fraction: F) -> Self {
+        match i32::try_from(utility::wide_denominator_to_1000(angle_fraction.into_fraction())) {
+            Ok(thousandths) => Angle(thousandths),
+            Err(_) => panic!("Angle too large for an i32 number of thousandths."),
+        }
+    }
+
+    /// Outputs the angle as a whole number of thousandths of a radian.
+    pub const fn thousandths(&self) -> i32 {
+        self.0
+    }
+
+    /// Outputs the angle as a fraction of radians out of 1000.
+    pub const fn to_fraction(&self) -> Fraction {
+        Fraction(self.0, 1000)
+    }
+}
+
+impl IntoFraction for Angle {
+    fn into_fraction(self) -> (i64, i64) {
+        (i64::from(self.0), 1000)
+    }
+}
+
+impl From<Angle> for Fraction {
+    fn from(angle: Angle) -> Self {
+        angle.to_fraction()
+    }
+}
+
+impl std::ops::Add for Angle {
+    type Output = Angle;
+
+    fn add(self, other: Angle) -> Angle {
+        match self.0.checked_add(other.0) {
+            Some(thousandths) => Angle(thousandths),
+            None => panic!("Angle too large for an i32 number of thousandths."),
+        }
+    }
+}
+
+impl std::ops::Sub for Angle {
+    type Output = Angle;
+
+    fn sub(self, other: Angle) -> Angle {
+        match self.0.checked_sub(other.0) {
+            Some(thousandths) => Angle(thousandths),
+            None => panic!("Angle too large for an i32 number of thousandths."),
+        }
+    }
+}
+
+impl std::ops::Neg for Angle {
+    type Output = Angle;
+
+    fn neg(self) -> Angle {
+        match self.0.checked_neg() {
+            Some(thousandths) => Angle(thousandths),
+            None => panic!("Angle too large for an i32 number of thousandths."),
+        }
+    }
+}
+
+impl std::fmt::Display for Angle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/1000", self.0)
+    }
+}
+
+/// Normalizes an angle to between 0 and 2 PI radians.
+///
+/// - The input represents the angle in radians as a numerator and denominator.
+/// - Accepts any fraction that implements `IntoFraction`, such as an (i32, i32), (i64, i64) or (u32, u32) tuple.
+/// - The output fraction represents the angle in radians as a numerator and denominator.
+/// - The output is between 0/1000 and 6282/1000.
+/// - Rounds the input to thousandths with halves away from zero and reduces it exactly, the same as a DTrig with the
+///   default settings, so the sine of the output equals the sine of the input for `DTrig::initialize()`.
+/// - A DTrig with another rounding mode or with `Compatibility::Version0_1` can give a different sine for the input.
+///
+/// # Panics
+///
+/// - A zero as the input for the denominator.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::angle::normalize_angle_unsigned;
+///
+/// fn main (){
+///
+/// let normalized = normalize_angle_unsigned((-1i64, 2i64));
+///
+/// println!("The angle -1/2 radians is the same as {}/{} radians.", normalized.0, normalized.1);
+///
+/// }
+/// ```
+pub fn normalize_angle_unsigned<F: IntoFraction>(angle_fraction: F) -> Fraction {
+    Fraction(wide_normalize(angle_fraction) as i32, 1000)
+}
+
+/// Normalizes an angle to between -PI and PI radians.
+///
+/// - The input represents the angle in radians as a numerator and denominator.
+/// - Accepts any fraction that implements `IntoFraction`, such as an (i32, i32), (i64, i64) or (u32, u32) tuple.
+/// - The output fraction represents the angle in radians as a numerator and denominator.
+/// - The output is between -3141/1000 and 3141/1000.
+/// - Rounds the input to thousandths with halves away from zero and reduces it exactly, the same as a DTrig with the
+///   default settings, so the sine of the output equals the sine of the input for `DTrig::initialize()`.
+/// - A DTrig with another rounding mode or with `Compatibility::Version0_1` can give a different sine for the input.
+///
+/// # Panics
+///
+/// - A zero as the input for the denominator.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::angle::normalize_angle_signed;
+///
+/// fn main (){
+///
+/// let normalized = normalize_angle_signed((6000, 1000));
+///
+/// println!("The angle 6000/1000 radians is the same as {}/{} radians.", normalized.0, normalized.1);
+///
+/// }
+/// ```
+pub fn normalize_angle_signed<F: IntoFraction>(angle_fraction: F) -> Fraction {
+    Fraction(shortest_difference_thousandths(0, wide_normalize(angle_fraction)) as i32, 1000)
+}
+
+/// Calculates the signed difference from one angle to another taking the shortest way around.
+///
+/// - The inputs represent the angles in radians as numerators and denominators.
+/// - The output fraction represents the difference in radians as a numerator and denominator.
+/// - A positive result is counterclockwise and a negative result is clockwise.
+/// - The result is always between -3141/1000 and 3141/1000.
+///
+/// # Panics
+///
+/// - A zero as the input for either denominator.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::angle::shortest_angle_difference;
+///
+/// fn main (){
+///
+/// let difference = shortest_angle_difference((6200, 1000), (100, 1000));
+///
+/// println!("Turning from 6200/1000 to 100/1000 radians is a turn of {}/{} radians.", difference.0, difference.1);
+///
+/// }
+/// ```
+pub fn shortest_angle_difference<F: IntoFraction, G: IntoFraction>(from_fraction: F, to_fraction: G) -> Fraction {
+    Fraction(
+        shortest_difference_thousandths(wide_normalize(from_fraction), wide_normalize(to_fraction)) as i32,
+        1000
+    )
+}
+
+/// Rotates a heading toward a target heading by no more than a maximum step taking the shortest way around.
+///
+/// - The inputs represent the angles and the maximum step in radians as numerators and denominators.
+/// - The output fraction represents the new heading in radians as a numerator and denominator.
+/// - The output is normalized to between 0/1000 and 6282/1000.
+/// - Returns the target heading once it is within the maximum step.
+/// - The sign of the maximum step is ignored.
+///
+/// # Panics
+///
+/// - A zero as the input for any denominator.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::Fraction;
+/// use deterministic_trigonometry::angle::turn_toward;
+///
+/// fn main (){
+///
+/// let mut heading = Fraction::new(6200, 1000);
+///
+/// // Turns by at most 50/1000 radians per tick.
+/// while heading != (100, 1000) {
+///     heading = turn_toward(heading, (100, 1000), (50, 1000));
+/// }
+///
+/// println!("The heading is now {}/{} radians.", heading.0, heading.1);
+///
+/// }
+/// ```
+pub fn turn_toward<F: IntoFraction, G: IntoFraction, H: IntoFraction>(
+    current_fraction: F,
+    target_fraction: G,
+    max_step_fraction: H
+) -> Fraction {
+    let current = wide_normalize(current_fraction);
+    let target = wide_normalize(target_fraction);
+    let max_step = utility::wide_denominator_to_1000(max_step_fraction.into_fraction()).abs();
+
+    let difference = shortest_difference_thousandths(current, target);
+
+    if i128::from(difference.abs()) <= max_step {
+        Fraction(target as i32, 1000)
+    } else if difference > 0 {
+        Fraction(reduction::reduce_angle(i128::from(current) + max_step) as i32, 1000)
+    } else {
+        Fraction(reduction::reduce_angle(i128::from(current) - max_step) as i32, 1000)
+    }
+}
+
+/// Interpolates between two angles taking the shortest way around.
+///
+/// - The inputs represent the angles in radians and the interpolation amount as numerators and denominators.
+/// - An amount of 0 gives the first angle and an amount of 1 gives the second angle.
+/// - Amounts below 0 or above 1 continue past the ends along the same arc.
+/// - The output fraction represents the angle in radians as a numerator and denominator.
+/// - The output is normalized to between 0/1000 and 6282/1000.
+///
+/// # Panics
+///
+/// - A zero as the input for any denominator.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::angle::lerp_angle;
+///
+/// fn main (){
+///
+/// let halfway = lerp_angle((6200, 1000), (100, 1000), (1, 2));
+///
+/// println!("Halfway from 6200/1000 to 100/1000 radians is {}/{} radians.", halfway.0, halfway.1);
+///
+/// }
+/// ```
+pub fn lerp_angle<F: IntoFraction, G: IntoFraction, H: IntoFraction>(
+    from_fraction: F,
+    to_fraction: G,
+    amount_fraction: H
+) -> Fraction {
+    let amount_fraction = amount_fraction.into_fraction();
+
+    if amount_fraction.1 == 0 {
+        panic!("Input denominator of zero, which is undefined.");
+    }
+
+    let from = wide_normalize(from_fraction);
+    let to = wide_normalize(to_fraction);
+
+    // Scales the exact amount instead of rounding it to a thousandth first.
+    let step = utility::divide_and_round(
+        i128::from(shortest_difference_thousandths(from, to)) * i128::from(amount_fraction.0),
+        i128::from(amount_fraction.1)
+    );
+
+    Fraction(reduction::reduce_angle(i128::from(from) + step) as i32, 1000)
+}
+
+/// Interpolates between two angles taking the shortest way around and easing in and out.
+///
+/// - Works like `lerp_angle` but starts and ends slowly.
+/// - The amount is eased with (1 + sine(PI * amount - PI / 2)) / 2 using the sine table.
+/// - The amount is rounded to the nearest thousandth.
+/// - Only the easing sine uses the settings of the DTrig. The angles are normalized the same way as by `lerp_angle`,
+///   whatever its rounding and compatibility.
+///
+/// # Panics
+///
+/// - A zero as the input for any denominator.
+/// - An amount less than 0 or greater than 1.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::DTrig;
+/// use deterministic_trigonometry::angle::lerp_angle_eased;
+///
+/// fn main (){
+///
+/// let d_trig = DTrig::initialize();
+///
+/// let early = lerp_angle_eased(&d_trig, (6200, 1000), (100, 1000), (1, 10));
+///
+/// println!("One tenth of the way with easing is {}/{} radians.", early.0, early.1);
+///
+/// }
+/// ```
+pub fn lerp_angle_eased<F: IntoFraction, G: IntoFraction, H: IntoFraction>(
+    d_trig: &DTrig,
+    from_fraction: F,
+    to_fraction: G,
+    amount_fraction: H
+) -> Fraction {
+    let amount = utility::wide_denominator_to_1000(amount_fraction.into_fraction());
+
+    if amount < 0 {
+        panic!("Eased interpolation amount less than 0.");
+    } else if amount > 1000 {
+        panic!("Eased interpolation amount greater than 1.");
+    }
+
+    // Converts the amount to an angle from -PI / 2 to PI / 2 in thousandths of a radian.
+    let angle = utility::divide_and_round(amount * 3141592654, 1000000000) - 1571;
+
+    let eased = utility::divide_and_round(
+        1000 + i128::from(d_trig.sine((angle as i32, 1000)).0),
+        2
+    );
+
+    lerp_angle(from_fraction, to_fraction, (eased as i32, 1000))
+}
+
+// Normalizes a fraction of any integer type that fits in an i64 to 0 to 6282 thousandth radians.
+fn wide_normalize<F: IntoFraction>(angle_fraction: F) -> i64 {
+    reduction::reduce_angle(utility::wide_denominator_to_1000(angle_fraction.into_fraction()))
+}
+
+// Outputs the shortest signed number of steps between two normalized thousandth angles.
+fn shortest_difference_thousandths(from: i64, to: i64) -> i64 {
+    let difference = (to - from).rem_euclid(STEPS_PER_TURN);
+
+    if difference > HALF_TURN_STEPS {
+        difference - STEPS_PER_TURN
+    } else {
+        difference
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Angles within 300 thousandths on either side of the seam between 2 PI and 0.
+    fn seam_angles() -> Vec<i32> {
+        (5983..6283).chain(0..300).collect()
+    }
+
+    #[test]
+    fn test_angle_type() {
+        let d_trig = DTrig::initialize();
+
+        assert_eq!(Angle::from_fraction((1, 3)).thousandths(), 333);
+        assert_eq!(Angle::from_fraction((-1, 2000)).thousandths(), -1);
+        assert_eq!(Angle::from_fraction((7000i64, 1000i64)), Angle::from_thousandths(7000));
+        assert_eq!(Angle::from_thousandths(-785).to_fraction(), (-785, 1000));
+        assert_eq!(Fraction::from(Angle::from_thousandths(5)), (5, 1000));
+        assert_eq!(Angle::from_thousandths(1571).to_string(), "1571/1000");
+        assert_eq!(Angle::default(), Angle::ZERO);
+        assert!(Angle::from_thousandths(-1) < Angle::ZERO);
+        assert_eq!(Angle::from_thousandths(700) + Angle::from_thousandths(85), Angle::from_thousandths(785));
+        assert_eq!(Angle::from_thousandths(700) - Angle::from_thousandths(785), Angle::from_thousandths(-85));
+        assert_eq!(-Angle::from_thousandths(i32::MAX), Angle::from_thousandths(i32::MIN + 1));
+
+        for numerator in -7000..7001 {
+            let angle = Angle::from_fraction((numerator, 999));
+            assert_eq!(d_trig.sine(angle), d_trig.sine((numerator, 999)));
+            assert_eq!(normalize_angle_unsigned(angle), normalize_angle_unsigned((numerator, 999)));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_angle_too_large_panics() {
+        Angle::from_fraction((i64::from(i32::MAX), 1));
+    }
+
+    #[test]
+    fn test_normalize_angle() {
+        let d_trig = DTrig::initialize();
+
+        assert_eq!(normalize_angle_unsigned((-1, 2)), (5783, 1000));
+        assert_eq!(normalize_angle_unsigned((6283, 1000)), (0, 1000));
+        assert_eq!(normalize_angle_unsigned((7000u32, 1000u32)), (717, 1000));
+        assert_eq!(normalize_angle_unsigned((7000i16, 1000i16)), (717, 1000));
+        assert_eq!(normalize_angle_signed((3141, 1000)), (3141, 1000));
+        assert_eq!(normalize_angle_signed((3142, 1000)), (-3141, 1000));
+        assert_eq!(normalize_angle_signed((-1, 2)), (-500, 1000));
+
+        // Matches the normalization inside the trig functions for every i32 input.
+        for numerator in (-100000..100001).step_by(37) {
+            for denominator in [1, -3, 7, 250, 1000, -1024, 99991] {
+                let unsigned = normalize_angle_unsigned((numerator, denominator));
+                let signed = normalize_angle_signed((numerator, denominator));
+
+                assert!(unsigned.0 >= 0 && unsigned.0 <= 6282);
+                assert!(signed.0 >= -3141 && signed.0 <= 3141);
+                assert_eq!(d_trig.sine(unsigned), d_trig.sine((numerator, denominator)));
+                assert_eq!(d_trig.cosine(signed), d_trig.cosine((numerator, denominator)));
+            }
+        }
+
+        // Wide numerators and denominators do not overflow.
+        for fraction in [(i64::MAX, 1), (i64::MIN, 1), (i64::MIN, -1), (i64::MAX, i64::MIN), (1, i64::MAX)] {
+            let unsigned = normalize_angle_unsigned(fraction);
+            assert!(unsigned.0 >= 0 && unsigned.0 <= 6282);
+        }
+        assert_eq!(normalize_angle_unsigned((u32::MAX, 1)), normalize_angle_unsigned((4294967295i64, 1)));
+
+        // Compares i64 inputs past the i32 range against floating point.
+        for whole_radians in (0..1000000i64).step_by(997) {
+            let numerator = whole_radians * 1000000000 + 123456789;
+            let expected = ((numerator as f64) / 1e9).rem_euclid(2.0 * std::f64::consts::PI) * 1000.0;
+            let unsigned = normalize_angle_unsigned((numerator, 1000000000)).0;
+
+            assert!((expected - (unsigned as f64)).abs() <= 1.0 || (expected - 6283.185).abs() <= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_shortest_angle_difference() {
+        assert_eq!(shortest_angle_difference((6200, 1000), (100, 1000)), (183, 1000));
+        assert_eq!(shortest_angle_difference((100, 1000), (6200, 1000)), (-183, 1000));
+        assert_eq!(shortest_angle_difference((0, 1000), (3141, 1000)), (3141, 1000));
+        assert_eq!(shortest_angle_difference((0, 1000), (3142, 1000)), (-3141, 1000));
+        assert_eq!(shortest_angle_difference((-100, 1000), (100, 1000)), (200, 1000));
+        assert_eq!(shortest_angle_difference((1, 2), (3, 2)), (1000, 1000));
+
+        for from in seam_angles() {
+            for to in seam_angles() {
+                let difference = shortest_angle_difference((from, 1000), (to, 1000)).0;
+
+                // Stepping by the difference lands on the target.
+                assert_eq!(i64::from(from + difference).rem_euclid(6283), i64::from(to));
+
+                // The difference is the same size both ways around.
+                assert_eq!(shortest_angle_difference((to, 1000), (from, 1000)).0, -difference);
+
+                assert!(difference.abs() <= 3141);
+            }
+        }
+    }
+
+    #[test]
+    fn test_turn_toward() {
+        assert_eq!(turn_toward((6200, 1000), (100, 1000), (50, 1000)), (6250, 1000));
+        assert_eq!(turn_toward((6250, 1000), (100, 1000), (50, 1000)), (17, 1000));
+        assert_eq!(turn_toward((17, 1000), (100, 1000), (50, 1000)), (67, 1000));
+        assert_eq!(turn_toward((67, 1000), (100, 1000), (50, 1000)), (100, 1000));
+        assert_eq!(turn_toward((100, 1000), (6200, 1000), (-50, 1000)), (50, 1000));
+        assert_eq!(turn_toward((100, 1000), (6200, 1000), (0, 1000)), (100, 1000));
+
+        for current in seam_angles() {
+            for target in seam_angles() {
+                for max_step in [0, 1, 7, 150, 599, 3141] {
+                    let difference = shortest_angle_difference((current, 1000), (target, 1000)).0;
+                    let turned = turn_toward((current, 1000), (target, 1000), (max_step, 1000));
+
+                    let expected = if difference.abs() <= max_step {
+                        target
+                    } else {
+                        (current + difference.signum() * max_step).rem_euclid(6283)
+                    };
+
+                    assert_eq!(turned, (expected, 1000));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lerp_angle() {
+        assert_eq!(lerp_angle((6200, 1000), (100, 1000), (0, 1)), (6200, 1000));
+        assert_eq!(lerp_angle((6200, 1000), (100, 1000), (1, 1)), (100, 1000));
+        assert_eq!(lerp_angle((6200, 1000), (100, 1000), (1, 2)), (9, 1000));
+        assert_eq!(lerp_angle((100, 1000), (6200, 1000), (1, 2)), (8, 1000));
+        assert_eq!(lerp_angle((0, 1000), (1000, 1000), (3, 2)), (1500, 1000));
+        assert_eq!(lerp_angle((0, 1000), (1000, 1000), (-1, 2)), (5783, 1000));
+
+        for from in seam_angles() {
+            for to in seam_angles() {
+                let difference = shortest_angle_difference((from, 1000), (to, 1000)).0;
+                let mut previous = 0;
+
+                // Every step along the way stays on the short arc and moves toward the target.
+                for amount in 0..101 {
+                    let angle = lerp_angle((from, 1000), (to, 1000), (amount, 100));
+                    let travelled = shortest_angle_difference((from, 1000), angle).0;
+
+                    assert_eq!(angle.1, 1000);
+                    assert!(angle.0 >= 0 && angle.0 <= 6282);
+                    assert!(travelled.abs() <= difference.abs());
+                    assert!(travelled == 0 || travelled.signum() == difference.signum());
+                    assert!(travelled.abs() >= previous);
+                    previous = travelled.abs();
+                }
+
+                assert_eq!(lerp_angle((from, 1000), (to, 1000), (100, 100)), (to, 1000));
+            }
+        }
+    }
+
+    #[test]
+    fn test_lerp_angle_eased() {
+        let d_trig = DTrig::initialize();
+
+        assert_eq!(lerp_angle_eased(&d_trig, (6200, 1000), (100, 1000), (0, 1)), (6200, 1000));
+        assert_eq!(lerp_angle_eased(&d_trig, (6200, 1000), (100, 1000), (1, 1)), (100, 1000));
+        assert_eq!(lerp_angle_eased(&d_trig, (0, 1000), (1000, 1000), (1, 2)), (500, 1000));
+
+        // Eased steps are smaller than plain steps near the ends and never move backward.
+        let mut previous = 0;
+        for amount in 0..1001 {
+            let eased = lerp_angle_eased(&d_trig, (0, 1000), (3000, 1000), (amount, 1000)).0;
+            assert!(eased >= previous);
+            if amount < 200 {
+                assert!(eased <= lerp_angle((0, 1000), (3000, 1000), (amount, 1000)).0);
+            }
+            previous = eased;
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lerp_angle_eased_panics_past_one() {
+        lerp_angle_eased(&DTrig::initialize(), (0, 1000), (1000, 1000), (1001, 1000));
+    }
+
+    #[test]
+    fn test_turn_toward_arrives_without_overshooting() {
+        for current in seam_angles() {
+            for target in [6283 - 150, 6282, 0, 1, 150] {
+                let mut heading = Fraction::new(current, 1000);
+                let mut remaining =
+                    shortest_angle_difference(heading, (target, 1000)).0.abs();
+
+                while heading != (target, 1000) {
+                    heading = turn_toward(heading, (target, 1000), (13, 1000));
+                    let now_remaining = shortest_angle_difference(heading, (target, 1000)).0.abs();
+
+                    assert!(now_remaining < remaining);
+                    remaining = now_remaining;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,207 @@
+/* This module contains the table lookup of the arctangent. The four arctangent tables cover the inputs up to 4, 20, 100
+and 1000 at steps of a thousandth, a hundredth, a tenth and one. Rather than walking through a range check and a
+rounding branch for each table, the lookup counts the boundaries the input is past to get the number of its tier and
+reads the step, rounding and table of that tier from a list. Every input takes the same few comparisons, one division
+and one table read, whichever tier it falls in.
+
+The tiers are looked up with the size of the input and the sign is put back afterward, which relies on each table
+holding the arctangent of its negative inputs as the negatives of its positive entries. The tables are correctly
+rounded, which makes this exact. Inputs are rounded to the nearest step with halves away from zero, so the arctangent of
+a negative input is always the negative of the arctangent of its size. Version 0.1 truncated the negative inputs of the
+ones tier toward zero instead, which is kept for its compatibility setting.
+
+Past 1000 the arctangent comes from atan(x) = PI/2 - atan(1/x). The arctangent table can not be used for atan(1/x) here,
+as 1/x is less than a thousandth, so the first two terms of its series are used, which are exact to far less than a
+billionth of a thousandth. The results are correctly rounded, and are the same as the 1570/1000 and 1571/1000 that
+version 0.1 gave on either side of 3374653/1000. */
+
+use crate::{ Compatibility, DTrig };
+
+// The largest input in thousandths of each tier.
+const TIER_LIMITS: [u128; 4] = [4000, 20000, 100000, 1000000];
+
+// PI/2 in trillionths.
+const HALF_PI_TRILLIONTHS: u128 = 1570796326795;
+
+// How the input is rounded and looked up in one tier.
+struct Tier {
+    // The thousandths between two entries of the table.
+    step: u128,
+    // The thousandths added to the size of the input before dividing by the step, so it rounds to the nearest entry.
+    half: u128,
+    // The same for a negative input with the results of version 0.1.
+    version_0_1_negative_half: u128,
+    // The index of the entry for an input of zero.
+    center: usize,
+}
+
+const TIERS: [Tier; 4] = [
+    Tier { step: 1, half: 0, version_0_1_negative_half: 0, center: 4000 },
+    Tier { step: 10, half: 5, version_0_1_negative_half: 5, center: 2000 },
+    Tier { step: 100, half: 50, version_0_1_negative_half: 50, center: 1000 },
+    Tier { step: 1000, half: 500, version_0_1_negative_half: 0, center: 1000 },
+];
+
+impl DTrig {
+    // Looks up the arctangent of an input in thousandths and outputs it in thousandths.
+    pub (in crate) fn arctangent_of_thousandths(&self, thousandths: i128) -> i32 {
+        let size = thousandths.unsigned_abs();
+        let is_negative = thousandths < 0;
+
+        // Counts the tier boundaries that the input is past.
+        let tier_number = TIER_LIMITS.iter().map(|limit| usize::from(size > *limit)).sum::<usize>();
+
+        let entry = match TIERS.get(tier_number) {
+            Some(tier) => {
+                let tables: [&[i16]; 4] = [
+                    &self.arctangent_thousandths,
+                    &self.arctangent_hundredths,
+                    &self.arctangent_tenths,
+                    &self.arctangent_ones,
+                ];
+
+                let half = if is_negative && self.compatibility == Compatibility::Version0_1 {
+                    tier.version_0_1_negative_half
+                } else {
+                    tier.half
+                };
+                let steps = (size + half) / tier.step;
+                i32::from(tables[tier_number][tier.center + steps as usize])
+            }
+            None => reciprocal_arctangent(size),
+        };
+
+        if is_negative { -entry } else { entry }
+    }
+}
+
+// Outputs the arctangent in thousandths of an input past 1000 from atan(x) = PI/2 - atan(1/x), with the size of the
+// input in thousandths.
+fn reciprocal_arctangent(size: u128) -> i32 {
+    // 1/x in trillionths, which is at most a billion.
+    let reciprocal = 1000000000000000 / size;
+
+    // The second term of atan(1/x) = 1/x - 1/(3x^3) + ..., also in trillionths.
+    let second_term = reciprocal * reciprocal * reciprocal / 3000000000000000000000000;
+
+    let arctangent = HALF_PI_TRILLIONTHS - reciprocal + second_term;
+
+    // Rounds the trillionths to thousandths.
+    ((arctangent + 500000000) / 1000000000) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ Compatibility, DTrig };
+
+    // The arctangent as it was written in version 0.1, with a range check and a rounding branch for each table.
+    fn arctangent_with_branches(d_trig: &DTrig, numerator_out_of_1000: i128) -> i32 {
+        if (-4000..=4000).contains(&numerator_out_of_1000) {
+            i32::from(d_trig.arctangent_thousandths[(numerator_out_of_1000 + 4000) as usize])
+        } else if (-20000..=20000).contains(&numerator_out_of_1000) {
+            let index = if (numerator_out_of_1000 % 10).abs() < 5 {
+                numerator_out_of_1000 / 10
+            } else if numerator_out_of_1000 > 0 {
+                numerator_out_of_1000 / 10 + 1
+            } else {
+                numerator_out_of_1000 / 10 - 1
+            };
+            i32::from(d_trig.arctangent_hundredths[(index + 2000) as usize])
+        } else if (-100000..=100000).contains(&numerator_out_of_1000) {
+            let index = if (numerator_out_of_1000 % 100).abs() < 50 {
+                numerator_out_of_1000 / 100
+            } else if numerator_out_of_1000 > 0 {
+                numerator_out_of_1000 / 100 + 1
+            } else {
+                numerator_out_of_1000 / 100 - 1
+            };
+            i32::from(d_trig.arctangent_tenths[(index + 1000) as usize])
+        } else if (-1000000..=1000000).contains(&numerator_out_of_1000) {
+            let index = if numerator_out_of_1000 % 1000 < 500 {
+                numerator_out_of_1000 / 1000
+            } else if numerator_out_of_1000 > 0 {
+                numerator_out_of_1000 / 1000 + 1
+            } else {
+                numerator_out_of_1000 / 1000 - 1
+            };
+            i32::from(d_trig.arctangent_ones[(index + 1000) as usize])
+        } else if (-3374652..-1000000).contains(&numerator_out_of_1000) {
+            -1570
+        } else if (1000001..3374653).contains(&numerator_out_of_1000) {
+            1570
+        } else if numerator_out_of_1000 <= -3374653 {
+            -1571
+        } else {
+            1571
+        }
+    }
+
+    #[test]
+    fn test_version_0_1_matches_branches_for_every_input() {
+        let d_trig = DTrig::initialize().with_compatibility(Compatibility::Version0_1);
+
+        for thousandths in -3500000..=3500000 {
+            assert_eq!(
+                d_trig.arctangent_of_thousandths(thousandths),
+                arctangent_with_branches(&d_trig, thousandths),
+                "{}/1000",
+                thousandths
+            );
+        }
+
+        for thousandths in [i128::from(i64::MIN) * 1000, i128::from(i64::MAX) * 1000, -1 << 100, 1 << 100] {
+            assert_eq!(
+                d_trig.arctangent_of_thousandths(thousandths),
+                arctangent_with_branches(&d_trig, thousandths)
+            );
+        }
+    }
+
+    #[test]
+    fn test_reciprocal_past_the_tables() {
+        let d_trig = DTrig::initialize();
+
+        // These are the correctly rounded results on either side of where the arctangent crosses 1570.5/1000.
+        assert_eq!(d_trig.arctangent_of_thousandths(1000001), 1570);
+        assert_eq!(d_trig.arctangent_of_thousandths(3374652), 1570);
+        assert_eq!(d_trig.arctangent_of_thousandths(3374653), 1571);
+        assert_eq!(d_trig.arctangent_of_thousandths(-3374653), -1571);
+        assert_eq!(d_trig.arctangent_of_thousandths(i128::MAX), 1571);
+        assert_eq!(d_trig.arctangent_of_thousandths(-i128::MAX), -1571);
+    }
+
+    #[test]
+    fn test_odd_symmetry_for_every_input() {
+        let d_trig = DTrig::initialize();
+
+        // Every thousandth of every tier and past the tables up to where the result stays at 1571/1000.
+        for thousandths in 0..=3500000i64 {
+            let arctangent = d_trig.arctangent((thousandths, 1000));
+            assert_eq!(d_trig.arctangent((-thousandths, 1000)), -arctangent, "{}/1000", thousandths);
+        }
+
+        for numerator in [i64::MAX, i64::from(i32::MAX)] {
+            assert_eq!(d_trig.arctangent((-numerator, 1)), -d_trig.arctangent((numerator, 1)));
+        }
+    }
+
+    #[test]
+    fn test_only_negative_ones_tier_differs_from_version_0_1() {
+        let d_trig = DTrig::initialize();
+        let legacy_d_trig = DTrig::initialize().with_compatibility(Compatibility::Version0_1);
+
+        for thousandths in -3500000..=3500000 {
+            let is_negative_ones_tier = (-1000000..=-100001).contains(&thousandths);
+            if !is_negative_ones_tier {
+                assert_eq!(
+                    d_trig.arctangent_of_thousandths(thousandths),
+                    legacy_d_trig.arctangent_of_thousandths(thousandths)
+                );
+            }
+        }
+
+        // Version 0.1 truncated -137999/1000 to -137 rather than rounding it to -138.
+        assert_eq!(d_trig.arctangent_of_thousandths(-137999), -d_trig.arctangent_of_thousandths(137999));
+        assert_eq!(legacy_d_trig.arctangent_of_thousandths(-137999), -d_trig.arctangent_of_thousandths(137000));
+    }
+}
@@ -0,0 +1,255 @@
+/* This module contains the functions that evaluate whole slices of inputs at once. The angles are already held in
+thousandths, so no fractions need to be rounded, and angles within one turn of the table skip the full normalization.
+The sine and cosine lookups use vector instructions where the processor has them. Every result is the same as calling
+the matching single value function on each input. */
+
+use crate::reduction;
+use crate::simd;
+use crate::{ Angle, Compatibility };
+
+impl crate::DTrig {
+    /// Calculates the sine of every angle in a slice.
+    ///
+    /// - Each output is the numerator of the sine out of 1000.
+    /// - Gives the same results as calling `sine` on each angle.
+    /// - Uses AVX2, SSE2 or NEON vector instructions when they are available.
+    ///
+    /// # Panics
+    ///
+    /// - An output slice that is not the same length as the input slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::{ Angle, DTrig };
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize();
+    ///
+    /// let headings = [Angle::from_thousandths(0), Angle::from_thousandths(785), Angle::from_thousandths(1571)];
+    /// let mut sines = [0; 3];
+    ///
+    /// d_trig.sine_slice(&headings, &mut sines);
+    ///
+    /// println!("The sines are {:?} out of 1000.", sines);
+    ///
+    /// }
+    /// ```
+    pub fn sine_slice(&self, angles: &[Angle], output: &mut [i32]) {
+        check_lengths(angles.len(), output.len());
+
+        simd::lookup(&self.sine_array, angles, output, self.compatibility);
+    }
+
+    /// Calculates the cosine of every angle in a slice.
+    ///
+    /// - Each output is the numerator of the cosine out of 1000.
+    /// - Gives the same results as calling `cosine` on each angle.
+    ///
+    /// # Panics
+    ///
+    /// - An output slice that is not the same length as the input slice.
+    pub fn cosine_slice(&self, angles: &[Angle], output: &mut [i32]) {
+        check_lengths(angles.len(), output.len());
+
+        simd::lookup(&self.cosine_array, angles, output, self.compatibility);
+    }
+
+    /// Calculates the sine and cosine of every angle in a slice.
+    ///
+    /// - Each output is the numerator of the sine or cosine out of 1000.
+    /// - Gives the same results as calling `sine` and `cosine` on each angle.
+    ///
+    /// # Panics
+    ///
+    /// - An output slice that is not the same length as the input slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::{ Angle, DTrig };
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize();
+    ///
+    /// let headings = [Angle::from_thousandths(-500), Angle::from_thousandths(7000)];
+    /// let mut velocities_x = [0; 2];
+    /// let mut velocities_y = [0; 2];
+    ///
+    /// d_trig.sine_cosine_slice(&headings, &mut velocities_y, &mut velocities_x);
+    ///
+    /// println!("The x velocities are {:?} and the y velocities are {:?}.", velocities_x, velocities_y);
+    ///
+    /// }
+    /// ```
+    pub fn sine_cosine_slice(&self, angles: &[Angle], sines: &mut [i32], cosines: &mut [i32]) {
+        check_lengths(angles.len(), sines.len());
+        check_lengths(angles.len(), cosines.len());
+
+        simd::lookup(&self.sine_array, angles, sines, self.compatibility);
+        simd::lookup(&self.cosine_array, angles, cosines, self.compatibility);
+    }
+
+    /// Calculates the angle from the positive x axis to every point given by a slice of y and a slice of x coordinates.
+    ///
+    /// - Each output is the numerator of the angle in radians out of 1000.
+    /// - Gives the same results as calling `arctangent2` on each point.
+    ///
+    /// # Panics
+    ///
+    /// - Input or output slices that are not all the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::DTrig;
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize();
+    ///
+    /// let velocities_y = [0, 1000, -5];
+    /// let velocities_x = [1000, 0, -5];
+    /// let mut headings = [0; 3];
+    ///
+    /// d_trig.arctangent2_slice(&velocities_y, &velocities_x, &mut headings);
+    ///
+    /// println!("The headings are {:?} thousandths of a radian.", headings);
+    ///
+    /// }
+    /// ```
+    pub fn arctangent2_slice(&self, y: &[i32], x: &[i32], output: &mut [i32]) {
+        check_lengths(y.len(), x.len());
+        check_lengths(y.len(), output.len());
+
+        for ((y, x), angle) in y.iter().zip(x.iter()).zip(output.iter_mut()) {
+            *angle = self.arctangent2(*y, *x).0;
+        }
+    }
+}
+
+// Panics if two slices that are processed together have different lengths.
+fn check_lengths(first: usize, second: usize) {
+    if first != second {
+        panic!("Input slice lengths do not match.");
+    }
+}
+
+// Outputs the table index for an angle in thousandths, skipping the full normalization within one turn of the table.
+pub (in crate) fn table_index(thousandths: i32, compatibility: Compatibility) -> usize {
+    if (0..6283).contains(&thousandths) {
+        thousandths as usize
+    } else if (-6283..0).contains(&thousandths) {
+        (thousandths + 6283) as usize
+    } else if (6283..12566).contains(&thousandths) {
+        (thousandths - 6283) as usize
+    } else {
+        reduction::normalize_angle(i128::from(thousandths), compatibility) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ DRng, DTrig };
+
+    // Angles covering the fast paths, their edges and the full normalization.
+    fn test_angles() -> Vec<Angle> {
+        let mut rng = DRng::new(36);
+        (-20000..20001)
+            .chain([i32::MIN, i32::MIN + 1, i32::MAX, i32::MAX - 1, 12565, 12566, 12567, -6283, -6284])
+            .chain((0..20000).map(|_| rng.next_in_range(i32::MIN, i32::MAX)))
+            .map(Angle::from_thousandths)
+            .collect()
+    }
+
+    #[test]
+    fn test_table_index_matches_normalization() {
+        for compatibility in [Compatibility::Current, Compatibility::Version0_1] {
+            for thousandths in -100000..100001 {
+                assert_eq!(
+                    table_index(thousandths, compatibility) as i64,
+                    reduction::normalize_angle(i128::from(thousandths), compatibility)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_slices_match_single_values() {
+        let angles = test_angles();
+
+        for compatibility in [Compatibility::Current, Compatibility::Version0_1] {
+            let d_trig = DTrig::initialize().with_compatibility(compatibility);
+
+            let mut sines = vec![0; angles.len()];
+            let mut cosines = vec![0; angles.len()];
+            let mut paired_sines = vec![0; angles.len()];
+            let mut paired_cosines = vec![0; angles.len()];
+
+            d_trig.sine_slice(&angles, &mut sines);
+            d_trig.cosine_slice(&angles, &mut cosines);
+            d_trig.sine_cosine_slice(&angles, &mut paired_sines, &mut paired_cosines);
+
+            for (index, angle) in angles.iter().enumerate() {
+                assert_eq!(d_trig.sine(*angle), (sines[index], 1000));
+                assert_eq!(d_trig.cosine(*angle), (cosines[index], 1000));
+                assert_eq!(paired_sines[index], sines[index]);
+                assert_eq!(paired_cosines[index], cosines[index]);
+            }
+        }
+
+        // Empty slices are allowed.
+        DTrig::initialize().sine_slice(&[], &mut []);
+    }
+
+    #[test]
+    fn test_arctangent2_slice_matches_single_values() {
+        let d_trig = DTrig::initialize();
+        let mut rng = DRng::new(2);
+
+        let mut y: Vec<i32> = (-50..51).flat_map(|y| [y; 101]).collect();
+        let mut x: Vec<i32> = (0..101).flat_map(|_| -50..51).collect();
+        for _ in 0..10000 {
+            y.push(rng.next_in_range(i32::MIN, i32::MAX));
+            x.push(rng.next_in_range(i32::MIN, i32::MAX));
+        }
+
+        let mut angles = vec![0; y.len()];
+        d_trig.arctangent2_slice(&y, &x, &mut angles);
+
+        for index in 0..y.len() {
+            assert_eq!(d_trig.arctangent2(y[index], x[index]), (angles[index], 1000));
+        }
+    }
+
+    #[test]
+    fn test_arctangent2() {
+        let d_trig = DTrig::initialize();
+
+        assert_eq!(d_trig.arctangent2(0, 0), (0, 1000));
+        assert_eq!(d_trig.arctangent2(0, 5), (0, 1000));
+        assert_eq!(d_trig.arctangent2(5, 0), (1571, 1000));
+        assert_eq!(d_trig.arctangent2(0, -5), (3142, 1000));
+        assert_eq!(d_trig.arctangent2(-5, 0), (-1571, 1000));
+        assert_eq!(d_trig.arctangent2(-4000, -3000), (-2215, 1000));
+
+        // Agrees with the angle from the polar conversion to within the rounding of each.
+        for y in -30..31 {
+            for x in -30..31 {
+                let angle = d_trig.arctangent2(y, x).0;
+                let polar_angle = d_trig.cartesian_to_polar(x, y).1.0;
+                assert!(angle.abs() <= 3142);
+                assert!([0, 1, 6282].contains(&(angle - polar_angle).rem_euclid(6283)));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mismatched_lengths_panic() {
+        DTrig::initialize().sine_cosine_slice(&[Angle::ZERO; 3], &mut [0; 3], &mut [0; 2]);
+    }
+}
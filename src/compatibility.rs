@@ -0,0 +1,43 @@
+/* This module contains the compatibility setting, which chooses between the results of this version of the library and
+the results of earlier versions. Programs running in lockstep or replaying recorded inputs need every peer to compute
+the same results, so a change that fixes or improves a result is only made for the current version, and the results of
+earlier versions can still be asked for. */
+
+/// The version of the library whose results the trig functions give.
+///
+/// - The default is `Current`.
+/// - The setting for the trig functions is set with `DTrig::with_compatibility`.
+/// - `Version0_1` gives the same results as version 0.1 for every input, for replays and lockstep sessions that were
+///   recorded with it.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::{ Compatibility, DTrig };
+///
+/// fn main (){
+///
+/// let d_trig = DTrig::initialize();
+/// let legacy_d_trig = DTrig::initialize().with_compatibility(Compatibility::Version0_1);
+///
+/// // Version 0.1 truncated the negative inputs of the ones tier of the arctangent toward zero.
+/// assert_eq!(d_trig.arctangent((-137999, 1000)), (-1564, 1000));
+/// assert_eq!(legacy_d_trig.arctangent((-137999, 1000)), (-1563, 1000));
+///
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Compatibility {
+    /// The results of this version of the library.
+    #[default]
+    Current,
+    /// The results of version 0.1 of the library.
+    ///
+    /// - The negative inputs of the ones tier of the arctangent, from -100 to -1000, are truncated toward zero rather
+    ///   than rounded, so the arctangent of a negative input there can differ from the negative of the arctangent of
+    ///   its size.
+    /// - Angles are reduced to one turn with a 12 digit value of 2 PI, whose error grows with every turn, so an angle
+    ///   of many turns can land on the neighboring thousandth.
+    Version0_1,
+}
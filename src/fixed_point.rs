@@ -0,0 +1,277 @@
+/* This module contains the support for the fixed point types of the fixed crate. A fixed point number is its raw bits
+over a power of two, so it is converted to an input exactly as an integer fraction and then rounded to thousandths with
+the same rounding as every other input. Results are converted back by rounding the fraction out of 1000 to the nearest
+number of bits with the same rounding mode, so no floating point arithmetic is used in either direction. */
+
+use fixed::types::{ I16F16, I32F32 };
+
+use crate::{ DeterministicTrig, DTrig, Fraction, IntoFraction, RoundingMode };
+
+/// A fixed point type that the trig functions can take and return directly.
+///
+/// - Implemented for `I16F16` and `I32F32` from the fixed crate.
+/// - Inputs are converted exactly to a fraction over a power of two and rounded to thousandths like any other input.
+/// - Outputs are rounded from thousandths to the fixed point type with the rounding mode of the `DTrig` struct.
+/// - Outputs that are too large for the type are saturated to its largest or smallest value.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::{ DTrig, FixedPoint };
+/// use fixed::types::I16F16;
+///
+/// fn main (){
+///
+/// let d_trig = DTrig::initialize();
+///
+/// let sine = I16F16::from_fraction(d_trig.sine((785, 1000)));
+///
+/// println!("The sine of 785/1000 radians is {}.", sine);
+///
+/// }
+/// ```
+pub trait FixedPoint: IntoFraction + Copy {
+    /// Converts a fraction to this type with half away from zero rounding, saturating if it does not fit.
+    fn from_fraction(fraction: Fraction) -> Self {
+        Self::from_fraction_with_rounding(fraction, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Converts a fraction to this type with the given rounding, saturating if it does not fit.
+    ///
+    /// # Panics
+    ///
+    /// - A fraction with a zero denominator.
+    fn from_fraction_with_rounding(fraction: Fraction, rounding: RoundingMode) -> Self;
+}
+
+// Implements the conversion and trig traits for a fixed point type with the given raw integer type and number of
+// fractional bits.
+macro_rules! impl_fixed_point {
+    ($fixed:ty, $bits:ty, $fractional_bits:expr) => {
+        impl IntoFraction for $fixed {
+            fn into_fraction(self) -> (i64, i64) {
+                (i64::from(self.to_bits()), 1i64 << $fractional_bits)
+            }
+        }
+
+        impl FixedPoint for $fixed {
+            fn from_fraction_with_rounding(fraction: Fraction, rounding: RoundingMode) -> Self {
+                if fraction.1 == 0 {
+                    panic!("Input denominator of zero, which is undefined.");
+                }
+
+                let bits = rounding.divide(i128::from(fraction.0) << $fractional_bits, i128::from(fraction.1));
+
+                <$fixed>::from_bits(bits.clamp(i128::from(<$bits>::MIN), i128::from(<$bits>::MAX)) as $bits)
+            }
+        }
+
+        impl DeterministicTrig<$fixed> for DTrig {
+            fn sine(&self, argument: $fixed) -> $fixed {
+                self.sin_fixed(argument)
+            }
+
+            fn cosine(&self, argument: $fixed) -> $fixed {
+                self.cos_fixed(argument)
+            }
+
+            fn tangent(&self, argument: $fixed) -> $fixed {
+                self.tan_fixed(argument)
+            }
+
+            fn arcsine(&self, argument: $fixed) -> $fixed {
+                self.asin_fixed(argument)
+            }
+
+            fn arccosine(&self, argument: $fixed) -> $fixed {
+                self.acos_fixed(argument)
+            }
+
+            fn arctangent(&self, argument: $fixed) -> $fixed {
+                self.atan_fixed(argument)
+            }
+        }
+    };
+}
+
+impl_fixed_point!(I16F16, i32, 16);
+impl_fixed_point!(I32F32, i64, 32);
+
+impl DTrig {
+    /// Calculates the sine of an angle in radians given as a fixed point number.
+    ///
+    /// - Gives the sine out of 1000 rounded to the fixed point type.
+    /// - Works with `I16F16` and `I32F32` inputs and outputs.
+    /// - See README for limitations on accuracy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::DTrig;
+    /// use fixed::types::I16F16;
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize();
+    ///
+    /// let sine = d_trig.sin_fixed(I16F16::from_bits(51446));
+    ///
+    /// println!("The sine of {} radians is {}.", I16F16::from_bits(51446), sine);
+    ///
+    /// }
+    /// ```
+    pub fn sin_fixed<T: FixedPoint>(&self, argument: T) -> T {
+        T::from_fraction_with_rounding(self.sine(argument), self.rounding)
+    }
+
+    /// Calculates the cosine of an angle in radians given as a fixed point number.
+    ///
+    /// - Gives the cosine out of 1000 rounded to the fixed point type.
+    /// - Works with `I16F16` and `I32F32` inputs and outputs.
+    /// - See README for limitations on accuracy.
+    pub fn cos_fixed<T: FixedPoint>(&self, argument: T) -> T {
+        T::from_fraction_with_rounding(self.cosine(argument), self.rounding)
+    }
+
+    /// Calculates the tangent of an angle in radians given as a fixed point number.
+    ///
+    /// - Gives the tangent out of 1000 rounded to the fixed point type.
+    /// - Results too large for the fixed point type are saturated.
+    /// - Works with `I16F16` and `I32F32` inputs and outputs.
+    /// - See README for limitations on accuracy.
+    pub fn tan_fixed<T: FixedPoint>(&self, argument: T) -> T {
+        T::from_fraction_with_rounding(self.tangent(argument), self.rounding)
+    }
+
+    /// Calculates the arcsine of a fixed point number and gives the angle in radians.
+    ///
+    /// - Gives the angle out of 1000 rounded to the fixed point type.
+    /// - Works with `I16F16` and `I32F32` inputs and outputs.
+    /// - See README for limitations on accuracy.
+    ///
+    /// # Panics
+    ///
+    /// - An input that is less than -1 or greater than 1 after rounding to thousandths.
+    pub fn asin_fixed<T: FixedPoint>(&self, argument: T) -> T {
+        T::from_fraction_with_rounding(self.arcsine(argument), self.rounding)
+    }
+
+    /// Calculates the arccosine of a fixed point number and gives the angle in radians.
+    ///
+    /// - Gives the angle out of 1000 rounded to the fixed point type.
+    /// - Works with `I16F16` and `I32F32` inputs and outputs.
+    /// - See README for limitations on accuracy.
+    ///
+    /// # Panics
+    ///
+    /// - An input that is less than -1 or greater than 1 after rounding to thousandths.
+    pub fn acos_fixed<T: FixedPoint>(&self, argument: T) -> T {
+        T::from_fraction_with_rounding(self.arccosine(argument), self.rounding)
+    }
+
+    /// Calculates the arctangent of a fixed point number and gives the angle in radians.
+    ///
+    /// - Gives the angle out of 1000 rounded to the fixed point type.
+    /// - Works with `I16F16` and `I32F32` inputs and outputs.
+    /// - See README for limitations on accuracy.
+    pub fn atan_fixed<T: FixedPoint>(&self, argument: T) -> T {
+        T::from_fraction_with_rounding(self.arctangent(argument), self.rounding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utility;
+
+    #[test]
+    fn test_inputs_match_integer_fractions() {
+        let d_trig = DTrig::initialize();
+
+        for bits in (-500000..500000).step_by(7) {
+            let small = I16F16::from_bits(bits);
+            let large = I32F32::from_bits(i64::from(bits) << 16);
+
+            assert_eq!(d_trig.sine(small), d_trig.sine((bits, 65536)));
+            assert_eq!(d_trig.sine(large), d_trig.sine((bits, 65536)));
+            assert_eq!(d_trig.cosine(small), d_trig.cosine((bits, 65536)));
+            assert_eq!(d_trig.arctangent(large), d_trig.arctangent((bits, 65536)));
+        }
+
+        // The conversion to thousandths is the same as for any other fraction.
+        for bits in [i64::MIN, -1, 0, 1, 2147483, 2147484, i64::MAX] {
+            assert_eq!(
+                d_trig.to_thousandths(I32F32::from_bits(bits)),
+                utility::wide_denominator_to_1000((bits, 1 << 32))
+            );
+        }
+    }
+
+    #[test]
+    fn test_outputs() {
+        let d_trig = DTrig::initialize();
+
+        // 707/1000 is 46333.952 out of 65536.
+        assert_eq!(d_trig.sin_fixed(I16F16::from_bits(51446)), I16F16::from_bits(46334));
+        assert_eq!(d_trig.cos_fixed(I16F16::ZERO), I16F16::ONE);
+        assert_eq!(d_trig.cos_fixed(I32F32::ZERO), I32F32::ONE);
+        assert_eq!(d_trig.asin_fixed(I16F16::ONE), I16F16::from_fraction(Fraction(1571, 1000)));
+        assert_eq!(d_trig.acos_fixed(I32F32::ONE), I32F32::ZERO);
+        assert_eq!(d_trig.atan_fixed(I16F16::ONE), I16F16::from_fraction(Fraction(785, 1000)));
+
+        // The output rounding follows the rounding mode.
+        let floor = DTrig::initialize().with_rounding(RoundingMode::Floor);
+        assert_eq!(floor.sin_fixed(I16F16::from_bits(51446)), I16F16::from_bits(46333));
+
+        for thousandths in -3000..3000 {
+            let angle = I32F32::from_fraction(Fraction(thousandths, 1000));
+            assert_eq!(
+                d_trig.tan_fixed(angle),
+                I32F32::from_fraction(d_trig.tangent((thousandths, 1000)))
+            );
+        }
+    }
+
+    #[test]
+    fn test_generic_trait() {
+        let d_trig = DTrig::initialize();
+
+        for bits in (-300000..300000).step_by(11) {
+            let argument = I16F16::from_bits(bits);
+            assert_eq!(DeterministicTrig::sine(&d_trig, argument), d_trig.sin_fixed(argument));
+            assert_eq!(DeterministicTrig::tangent(&d_trig, argument), d_trig.tan_fixed(argument));
+
+            let argument = I32F32::from_bits(i64::from(bits) << 16);
+            assert_eq!(DeterministicTrig::cosine(&d_trig, argument), d_trig.cos_fixed(argument));
+            assert_eq!(DeterministicTrig::arctangent(&d_trig, argument), d_trig.atan_fixed(argument));
+        }
+
+        let argument = I16F16::from_bits(-30000);
+        assert_eq!(DeterministicTrig::arcsine(&d_trig, argument), d_trig.asin_fixed(argument));
+        assert_eq!(DeterministicTrig::arccosine(&d_trig, argument), d_trig.acos_fixed(argument));
+    }
+
+    #[test]
+    fn test_from_fraction() {
+        assert_eq!(I16F16::from_fraction(Fraction(-1, 2)), I16F16::from_bits(-32768));
+        assert_eq!(I16F16::from_fraction(Fraction(1, -2)), I16F16::from_bits(-32768));
+        assert_eq!(I16F16::from_fraction(Fraction(1, 3)), I16F16::from_bits(21845));
+        assert_eq!(I16F16::from_fraction(Fraction(2, 3)), I16F16::from_bits(43691));
+        assert_eq!(
+            I16F16::from_fraction_with_rounding(Fraction(-2, 3), RoundingMode::Truncate),
+            I16F16::from_bits(-43690)
+        );
+
+        // Values outside the range of the type are saturated.
+        assert_eq!(I16F16::from_fraction(Fraction(40000, 1)), I16F16::MAX);
+        assert_eq!(I16F16::from_fraction(Fraction(-40000, 1)), I16F16::MIN);
+        assert_eq!(I32F32::from_fraction(Fraction(i32::MAX, 1)), I32F32::from_num(i32::MAX));
+        assert_eq!(I32F32::from_fraction(Fraction(i32::MIN, 1)), I32F32::MIN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_denominator_panics() {
+        I16F16::from_fraction(Fraction(1, 0));
+    }
+}
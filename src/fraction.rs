@@ -0,0 +1,861 @@
+/* This module contains the fraction type that the trig functions take and return, and the trait that lets them accept
+fractions made of different integer types. Every input is widened to a pair of i64 values before it is rounded to
+thousandths, and the rounding itself is done with i128 values, so no input is ever truncated or overflows. Fraction
+arithmetic is done exactly in i128 values and the result is reduced before it is narrowed back to i32 values. */
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{ Hash, Hasher };
+use std::num::ParseIntError;
+use std::ops::{ Add, Div, Mul, Neg, Rem, Sub };
+
+use crate::utility;
+use crate::RoundingMode;
+
+/// A fraction with an i32 numerator and denominator.
+///
+/// - The first field is the numerator and the second field is the denominator.
+/// - Every `DTrig` function returns its result as a fraction out of 1000.
+/// - Fractions are compared by value, so 1/2 is equal to 500/1000.
+/// - Arithmetic is exact. The operators panic if the reduced result does not fit in i32 values.
+/// - Converts from and to (i32, i32) tuples and can be compared directly with them.
+///
+/// # Panics
+///
+/// - Any arithmetic or trig function on a fraction with a zero denominator. These can only be made by setting the
+///   fields directly. Comparison and hashing never panic: fractions with a zero denominator are compared by their
+///   numerators and sort after every other fraction.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::{ DTrig, Fraction };
+///
+/// fn main (){
+///
+/// let d_trig = DTrig::initialize();
+///
+/// let sine = d_trig.sine((785, 1000));
+/// let cosine = d_trig.cosine((785, 1000));
+///
+/// let sum = sine + cosine;
+///
+/// println!("The sine plus the cosine of 785/1000 radians is {}.", sum);
+///
+/// assert_eq!(sum, Fraction::new(707, 500));
+///
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Fraction(pub i32, pub i32);
+
+impl Fraction {
+    /// The fraction 0/1.
+    pub const ZERO: Fraction = Fraction(0, 1);
+
+    /// The fraction 1/1.
+    pub const ONE: Fraction = Fraction(1, 1);
+
+    /// Creates a fraction from a numerator and denominator.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator.
+    pub const fn new(numerator: i32, denominator: i32) -> Self {
+        if denominator == 0 {
+            panic!("Input denominator of zero, which is undefined.");
+        }
+        Fraction(numerator, denominator)
+    }
+
+    /// Divides the numerator and denominator by their greatest common divisor.
+    ///
+    /// - The denominator of the result is positive unless the only way to hold the value is with two negative numbers,
+    ///   which only happens for i32::MIN/-1.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the denominator.
+    pub fn reduce(&self) -> Self {
+        let (numerator, denominator) = self.wide();
+        fit(numerator, denominator).unwrap_or(*self)
+    }
+
+    /// Converts the fraction into the nearest fraction with the given denominator.
+    ///
+    /// - Rounds to the nearest integer with halves rounded away from zero, which is the default for the trig functions.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the denominator of the fraction or the input.
+    /// - A rescaled numerator too large for an i32.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::Fraction;
+    ///
+    /// fn main (){
+    ///
+    /// let two_thirds = Fraction::new(2, 3).rescale(1000);
+    ///
+    /// println!("Two thirds out of 1000 is {}.", two_thirds);
+    ///
+    /// }
+    /// ```
+    pub fn rescale(&self, denominator: i32) -> Self {
+        self.rescale_with_rounding(denominator, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Converts the fraction into a fraction with the given denominator using a rounding mode.
+    ///
+    /// - The mode rounds the value of the result, so a negative denominator gives the same value as a positive one.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the denominator of the fraction or the input.
+    /// - A rescaled numerator too large for an i32.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::{ Fraction, RoundingMode };
+    ///
+    /// fn main (){
+    ///
+    /// let two_thirds = Fraction::new(2, 3).rescale_with_rounding(1000, RoundingMode::Floor);
+    ///
+    /// println!("Two thirds out of 1000 rounded down is {}.", two_thirds);
+    ///
+    /// }
+    /// ```
+    pub fn rescale_with_rounding(&self, denominator: i32, rounding: RoundingMode) -> Self {
+        let (numerator, own_denominator) = self.wide();
+        if denominator == 0 {
+            panic!("Input denominator of zero, which is undefined.");
+        }
+
+        // Rounds the value rather than the numerator so a negative denominator rounds in the same direction.
+        let magnitude = rounding.divide(numerator * i128::from(denominator).abs(), own_denominator);
+        let rescaled = magnitude * i128::from(denominator.signum());
+
+        match i32::try_from(rescaled) {
+            Ok(rescaled) => Fraction(rescaled, denominator),
+            Err(_) => panic!("Rescaled numerator too large for an i32."),
+        }
+    }
+
+    /// Adds two fractions, returning None if the reduced result does not fit in i32 values.
+    pub fn checked_add(self, other: Fraction) -> Option<Fraction> {
+        let (numerator, denominator) = self.exact_add(other.wide());
+        fit(numerator, denominator)
+    }
+
+    /// Subtracts a fraction, returning None if the reduced result does not fit in i32 values.
+    pub fn checked_sub(self, other: Fraction) -> Option<Fraction> {
+        let (numerator, denominator) = self.exact_add(negate(other.wide()));
+        fit(numerator, denominator)
+    }
+
+    /// Multiplies two fractions, returning None if the reduced result does not fit in i32 values.
+    pub fn checked_mul(self, other: Fraction) -> Option<Fraction> {
+        let (numerator, denominator) = self.exact_mul(other);
+        fit(numerator, denominator)
+    }
+
+    /// Divides by a fraction, returning None if the divisor is zero or the reduced result does not fit in i32 values.
+    pub fn checked_div(self, other: Fraction) -> Option<Fraction> {
+        let (numerator, denominator) = self.exact_div(other)?;
+        fit(numerator, denominator)
+    }
+
+    /// Outputs the remainder after dividing by a fraction a whole number of times, returning None if the divisor is zero
+    /// or the reduced result does not fit in i32 values.
+    ///
+    /// - The quotient is rounded toward zero, so the remainder has the same sign as the dividend like the integer `%`.
+    pub fn checked_rem(self, other: Fraction) -> Option<Fraction> {
+        let (numerator, denominator) = self.exact_rem(other)?;
+        fit(numerator, denominator)
+    }
+
+    /// Adds two fractions, approximating results that do not fit in i32 values.
+    ///
+    /// - Values past the i32 range are clamped to i32::MAX/1 or i32::MIN/1.
+    /// - Other values whose reduced form does not fit have the numerator and denominator scaled down together.
+    pub fn saturating_add(self, other: Fraction) -> Fraction {
+        let (numerator, denominator) = self.exact_add(other.wide());
+        saturate(numerator, denominator)
+    }
+
+    /// Subtracts a fraction, approximating results that do not fit in i32 values the same way as `saturating_add`.
+    pub fn saturating_sub(self, other: Fraction) -> Fraction {
+        let (numerator, denominator) = self.exact_add(negate(other.wide()));
+        saturate(numerator, denominator)
+    }
+
+    /// Multiplies two fractions, approximating results that do not fit in i32 values the same way as `saturating_add`.
+    pub fn saturating_mul(self, other: Fraction) -> Fraction {
+        let (numerator, denominator) = self.exact_mul(other);
+        saturate(numerator, denominator)
+    }
+
+    /// Divides by a fraction, approximating results that do not fit in i32 values the same way as `saturating_add`.
+    ///
+    /// # Panics
+    ///
+    /// - A divisor of zero.
+    pub fn saturating_div(self, other: Fraction) -> Fraction {
+        match self.exact_div(other) {
+            Some((numerator, denominator)) => saturate(numerator, denominator),
+            None => panic!("Division by zero, which is undefined."),
+        }
+    }
+
+    // Outputs the numerator and denominator as i128 values with a positive denominator.
+    fn wide(&self) -> (i128, i128) {
+        if self.1 == 0 {
+            panic!("Input denominator of zero, which is undefined.");
+        }
+
+        if self.1 < 0 {
+            (-i128::from(self.0), -i128::from(self.1))
+        } else {
+            (i128::from(self.0), i128::from(self.1))
+        }
+    }
+
+    // Outputs the exact sum with a fraction held in i128 values.
+    fn exact_add(self, other: (i128, i128)) -> (i128, i128) {
+        let (numerator, denominator) = self.wide();
+        let (other_numerator, other_denominator) = other;
+        (numerator * other_denominator + other_numerator * denominator, denominator * other_denominator)
+    }
+
+    // Outputs the exact product as i128 values.
+    fn exact_mul(self, other: Fraction) -> (i128, i128) {
+        let (numerator, denominator) = self.wide();
+        let (other_numerator, other_denominator) = other.wide();
+        (numerator * other_numerator, denominator * other_denominator)
+    }
+
+    // Outputs the exact quotient as i128 values, or None if the divisor is zero.
+    fn exact_div(self, other: Fraction) -> Option<(i128, i128)> {
+        let (numerator, denominator) = self.wide();
+        let (other_numerator, other_denominator) = other.wide();
+        if other_numerator == 0 {
+            return None;
+        }
+        Some((numerator * other_denominator, denominator * other_numerator))
+    }
+
+    // Outputs the exact remainder after a quotient rounded toward zero, or None if the divisor is zero.
+    fn exact_rem(self, other: Fraction) -> Option<(i128, i128)> {
+        let (quotient_numerator, quotient_denominator) = self.exact_div(other)?;
+        let quotient = quotient_numerator / quotient_denominator;
+
+        let (numerator, denominator) = self.wide();
+        let (other_numerator, other_denominator) = other.wide();
+        Some((
+            numerator * other_denominator - quotient * other_numerator * denominator,
+            denominator * other_denominator,
+        ))
+    }
+}
+
+// Negates a fraction held in i128 values, which cannot overflow for fractions made from i32 values.
+fn negate(fraction: (i128, i128)) -> (i128, i128) {
+    (-fraction.0, fraction.1)
+}
+
+// Reduces an exact fraction and makes the denominator positive.
+fn reduce_wide(numerator: i128, denominator: i128) -> (i128, i128) {
+    let divisor = utility::greatest_common_divisor(numerator.unsigned_abs(), denominator.unsigned_abs()) as i128;
+    let sign = if denominator < 0 { -1 } else { 1 };
+    ((sign * numerator) / divisor, (sign * denominator) / divisor)
+}
+
+// Reduces an exact fraction and narrows it to i32 values with a positive denominator if it fits.
+fn fit(numerator: i128, denominator: i128) -> Option<Fraction> {
+    let (numerator, denominator) = reduce_wide(numerator, denominator);
+
+    match (i32::try_from(numerator), i32::try_from(denominator)) {
+        (Ok(numerator), Ok(denominator)) => Some(Fraction(numerator, denominator)),
+        _ => None,
+    }
+}
+
+// Narrows an exact fraction to i32 values, clamping values past the i32 range and scaling down the rest.
+fn saturate(numerator: i128, denominator: i128) -> Fraction {
+    if let Some(fraction) = fit(numerator, denominator) {
+        return fraction;
+    }
+
+    let (numerator, denominator) = reduce_wide(numerator, denominator);
+    let max = i128::from(i32::MAX);
+
+    if numerator > max * denominator {
+        return Fraction(i32::MAX, 1);
+    } else if numerator < i128::from(i32::MIN) * denominator {
+        return Fraction(i32::MIN, 1);
+    }
+
+    // The smallest factor that brings both the numerator and the denominator into range.
+    let factor = ((numerator.abs() + max - 1) / max).max((denominator + max - 1) / max);
+
+    Fraction(
+        utility::divide_and_round(numerator, factor) as i32,
+        utility::divide_and_round(denominator, factor) as i32
+    )
+}
+
+impl Default for Fraction {
+    fn default() -> Self {
+        Fraction::ZERO
+    }
+}
+
+impl PartialEq for Fraction {
+    fn eq(&self, other: &Fraction) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Fraction {}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Fraction) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fraction {
+    fn cmp(&self, other: &Fraction) -> Ordering {
+        // Fractions with a zero denominator have no value, so they are compared structurally after every other fraction.
+        match (self.1 == 0, other.1 == 0) {
+            (true, true) => return self.0.cmp(&other.0),
+            (true, false) => return Ordering::Greater,
+            (false, true) => return Ordering::Less,
+            (false, false) => {}
+        }
+
+        let (numerator, denominator) = self.wide();
+        let (other_numerator, other_denominator) = other.wide();
+        (numerator * other_denominator).cmp(&(other_numerator * denominator))
+    }
+}
+
+impl Hash for Fraction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hashes the reduced form so equal values hash the same. A zero denominator is only equal to itself.
+        if self.1 == 0 {
+            (i128::from(self.0), 0i128).hash(state);
+        } else {
+            let (numerator, denominator) = self.wide();
+            reduce_wide(numerator, denominator).hash(state);
+        }
+    }
+}
+
+impl PartialEq<(i32, i32)> for Fraction {
+    fn eq(&self, other: &(i32, i32)) -> bool {
+        *self == Fraction::from(*other)
+    }
+}
+
+impl PartialEq<Fraction> for (i32, i32) {
+    fn eq(&self, other: &Fraction) -> bool {
+        Fraction::from(*self) == *other
+    }
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.0, self.1)
+    }
+}
+
+impl From<(i32, i32)> for Fraction {
+    fn from(tuple: (i32, i32)) -> Self {
+        Fraction(tuple.0, tuple.1)
+    }
+}
+
+impl From<Fraction> for (i32, i32) {
+    fn from(fraction: Fraction) -> Self {
+        (fraction.0, fraction.1)
+    }
+}
+
+// Fractions are encoded as a two element sequence of the numerator and denominator, the same as an (i32, i32) tuple.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fraction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.0, self.1).serialize(serializer)
+    }
+}
+
+// Decoding rejects a zero denominator so that every decoded fraction is valid.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fraction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (numerator, denominator) = <(i32, i32)>::deserialize(deserializer)?;
+
+        if denominator == 0 {
+            return Err(serde::de::Error::custom("Input denominator of zero, which is undefined."));
+        }
+
+        Ok(Fraction(numerator, denominator))
+    }
+}
+
+impl IntoFraction for Fraction {
+    fn into_fraction(self) -> (i64, i64) {
+        (i64::from(self.0), i64::from(self.1))
+    }
+}
+
+impl Add for Fraction {
+    type Output = Fraction;
+
+    fn add(self, other: Fraction) -> Fraction {
+        match self.checked_add(other) {
+            Some(sum) => sum,
+            None => panic!("Fraction result too large for an i32."),
+        }
+    }
+}
+
+impl Sub for Fraction {
+    type Output = Fraction;
+
+    fn sub(self, other: Fraction) -> Fraction {
+        match self.checked_sub(other) {
+            Some(difference) => difference,
+            None => panic!("Fraction result too large for an i32."),
+        }
+    }
+}
+
+impl Mul for Fraction {
+    type Output = Fraction;
+
+    fn mul(self, other: Fraction) -> Fraction {
+        match self.checked_mul(other) {
+            Some(product) => product,
+            None => panic!("Fraction result too large for an i32."),
+        }
+    }
+}
+
+impl Div for Fraction {
+    type Output = Fraction;
+
+    fn div(self, other: Fraction) -> Fraction {
+        match self.exact_div(other) {
+            Some((numerator, denominator)) =>
+                match fit(numerator, denominator) {
+                    Some(quotient) => quotient,
+                    None => panic!("Fraction result too large for an i32."),
+                }
+            None => panic!("Division by zero, which is undefined."),
+        }
+    }
+}
+
+impl Rem for Fraction {
+    type Output = Fraction;
+
+    fn rem(self, other: Fraction) -> Fraction {
+        match self.exact_rem(other) {
+            Some((numerator, denominator)) =>
+                match fit(numerator, denominator) {
+                    Some(remainder) => remainder,
+                    None => panic!("Fraction result too large for an i32."),
+                }
+            None => panic!("Division by zero, which is undefined."),
+        }
+    }
+}
+
+impl Neg for Fraction {
+    type Output = Fraction;
+
+    fn neg(self) -> Fraction {
+        // Moves the sign to the denominator when the numerator is i32::MIN. When both are i32::MIN the value is one, so
+        // the reduced negative is -1/1.
+        match (self.0.checked_neg(), self.1.checked_neg()) {
+            (Some(numerator), _) => Fraction(numerator, self.1),
+            (None, Some(denominator)) => Fraction(self.0, denominator),
+            (None, None) => Fraction(-1, 1),
+        }
+    }
+}
+
+/// Converts a value into a fraction with an i64 numerator and denominator.
+///
+/// - Implemented for tuples of two i8, i16, i32, i64, u8, u16 or u32 values.
+/// - The first value of a tuple is the numerator and the second value is the denominator.
+/// - Every `DTrig` function accepts any type that implements this trait.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::DTrig;
+///
+/// fn main (){
+///
+/// let d_trig = DTrig::initialize();
+///
+/// let world_x: i64 = 5_000_000_000;
+/// let world_scale: i64 = 4_000_000_000;
+///
+/// let sine = d_trig.sine((world_x, world_scale));
+///
+/// println!("The sine of {}/{} radians is {}/{}.", world_x, world_scale, sine.0, sine.1);
+///
+/// }
+/// ```
+pub trait IntoFraction {
+    /// Converts the value into a numerator and denominator.
+    fn into_fraction(self) -> (i64, i64);
+}
+
+// Implements the trait for tuples of integer types that convert into an i64 without loss.
+macro_rules! impl_into_fraction_for_tuple {
+    ($($integer:ty),*) => {
+        $(
+            impl IntoFraction for ($integer, $integer) {
+                fn into_fraction(self) -> (i64, i64) {
+                    (i64::from(self.0), i64::from(self.1))
+                }
+            }
+        )*
+    };
+}
+
+impl_into_fraction_for_tuple!(i8, i16, i32, i64, u8, u16, u32);
+
+/// The error from parsing a fraction written as text.
+///
+/// - Returned by the num-traits `Num::from_str_radix` implementation for `Fraction`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseFractionError {
+    /// The numerator or the denominator is not a valid i32 in the given radix.
+    Int(ParseIntError),
+    /// The denominator is zero, which is undefined.
+    ZeroDenominator,
+}
+
+impl fmt::Display for ParseFractionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseFractionError::Int(error) => write!(f, "Invalid fraction: {}", error),
+            ParseFractionError::ZeroDenominator => write!(f, "Input denominator of zero, which is undefined."),
+        }
+    }
+}
+
+impl std::error::Error for ParseFractionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseFractionError::Int(error) => Some(error),
+            ParseFractionError::ZeroDenominator => None,
+        }
+    }
+}
+
+impl From<ParseIntError> for ParseFractionError {
+    fn from(error: ParseIntError) -> Self {
+        ParseFractionError::Int(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DTrig;
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash_of(fraction: Fraction) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        fraction.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_into_fraction() {
+        assert_eq!((3i8, -4i8).into_fraction(), (3, -4));
+        assert_eq!((i16::MIN, i16::MAX).into_fraction(), (-32768, 32767));
+        assert_eq!((u32::MAX, 1u32).into_fraction(), (4294967295, 1));
+        assert_eq!((i64::MIN, i64::MAX).into_fraction(), (i64::MIN, i64::MAX));
+    }
+
+    #[test]
+    fn test_every_type_gives_the_same_result() {
+        let d_trig = DTrig::initialize();
+
+        for numerator in -120i16..121 {
+            for denominator in [1i16, 7, -13, 100] {
+                let narrow = (i32::from(numerator), i32::from(denominator));
+                let wide = (i64::from(numerator), i64::from(denominator));
+
+                assert_eq!(d_trig.sine((numerator, denominator)), d_trig.sine(narrow));
+                assert_eq!(d_trig.cosine(wide), d_trig.cosine(narrow));
+                assert_eq!(d_trig.tangent(wide), d_trig.tangent(narrow));
+                assert_eq!(d_trig.arctangent((numerator, denominator)), d_trig.arctangent(narrow));
+
+                if numerator.abs() <= denominator.abs() {
+                    assert_eq!(d_trig.arcsine(wide), d_trig.arcsine(narrow));
+                    assert_eq!(d_trig.arccosine(wide), d_trig.arccosine(narrow));
+                }
+            }
+        }
+
+        for numerator in 0u32..1000 {
+            assert_eq!(d_trig.sine((numerator, 1000u32)), d_trig.sine((numerator as i32, 1000)));
+        }
+    }
+
+    #[test]
+    fn test_wide_inputs_are_not_truncated() {
+        let d_trig = DTrig::initialize();
+
+        // These would become different fractions or a zero denominator if cast to i32.
+        let just_over_one = ((1i64 << 32) + 1, 1i64 << 32);
+        assert_eq!(d_trig.sine(just_over_one), d_trig.sine((1, 1)));
+        assert_eq!(d_trig.arctangent(just_over_one), d_trig.arctangent((1, 1)));
+        assert_eq!(d_trig.arcsine((1i64 << 40, 1i64 << 41)), d_trig.arcsine((1, 2)));
+        assert_eq!(d_trig.arccosine((-(1i64 << 40), 1i64 << 41)), d_trig.arccosine((-1, 2)));
+        assert_eq!(d_trig.tangent((u32::MAX, u32::MAX)), d_trig.tangent((1, 1)));
+
+        // The extremes do not overflow.
+        for fraction in [(i64::MAX, 1), (i64::MIN, 1), (i64::MIN, -1), (1, i64::MIN)] {
+            d_trig.sine(fraction);
+            d_trig.cosine(fraction);
+            d_trig.tangent(fraction);
+            d_trig.arctangent(fraction);
+        }
+        assert_eq!(d_trig.arctangent((i64::MAX, 1)), (1571, 1000));
+        assert_eq!(d_trig.arctangent((i64::MIN, 1)), (-1571, 1000));
+    }
+
+    #[test]
+    fn test_reduce_and_rescale() {
+        assert_eq!(Fraction(500, 1000).reduce(), Fraction(1, 2));
+        assert_eq!(Fraction(500, 1000).reduce().1, 2);
+        assert_eq!(Fraction(6, -4).reduce().0, -3);
+        assert_eq!(Fraction(6, -4).reduce().1, 2);
+        assert_eq!(Fraction(0, -7).reduce().1, 1);
+        assert_eq!(Fraction(i32::MIN, i32::MIN).reduce().0, 1);
+
+        // The only value that cannot have a positive denominator keeps its signs.
+        assert_eq!(Fraction(i32::MIN, -1).reduce().0, i32::MIN);
+
+        assert_eq!(Fraction::new(2, 3).rescale(1000).0, 667);
+        assert_eq!(Fraction::new(-2, 3).rescale(1000).0, -667);
+        assert_eq!(Fraction::new(1, 2000).rescale(1000).0, 1);
+        assert_eq!(Fraction::new(-1, 2000).rescale(1000).0, -1);
+        assert_eq!(Fraction::new(1, -2000).rescale(1000).0, -1);
+        assert_eq!(Fraction::new(3, 7).rescale(-1000), Fraction(-429, -1000));
+
+        // Matches the rounding of the trig function inputs.
+        for numerator in -3000..3001 {
+            for denominator in [3, -7, 16, 999, -2000] {
+                let rescaled = Fraction::new(numerator, denominator).rescale(1000).0;
+                assert_eq!(i128::from(rescaled), utility::wide_denominator_to_1000((numerator.into(), denominator.into())));
+            }
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(Fraction::new(1, 2) + Fraction::new(1, 3), Fraction::new(5, 6));
+        assert_eq!(Fraction::new(1, 2) - Fraction::new(1, 3), Fraction::new(1, 6));
+        assert_eq!(Fraction::new(2, 3) * Fraction::new(3, 4), Fraction::new(1, 2));
+        assert_eq!(Fraction::new(2, 3) / Fraction::new(-4, 3), Fraction::new(-1, 2));
+        assert_eq!(-Fraction::new(2, 3), Fraction::new(-2, 3));
+        assert_eq!(-Fraction(i32::MIN, 3), Fraction(i32::MIN, -3));
+        assert_eq!(-Fraction(i32::MIN, i32::MIN), Fraction(-1, 1));
+        assert_ne!(-Fraction(i32::MIN, i32::MIN), Fraction(i32::MIN, i32::MIN));
+
+        // Results are reduced before they are narrowed.
+        let big = Fraction::new(i32::MAX, 1000);
+        assert_eq!((big * Fraction::new(1000, i32::MAX)).1, 1);
+        assert_eq!(big.checked_add(big), Some(Fraction(i32::MAX, 500)));
+        assert_eq!(Fraction(i32::MAX, 1).checked_add(Fraction::ONE), None);
+        assert_eq!(Fraction(i32::MAX, 2).checked_sub(Fraction(i32::MIN, 2)), None);
+        assert_eq!(Fraction(i32::MIN, 1).checked_sub(Fraction(1, 1)), None);
+        assert_eq!(Fraction::ONE.checked_div(Fraction::ZERO), None);
+        assert_eq!(Fraction(i32::MIN, 1).checked_mul(Fraction(-1, 1)), None);
+        assert_eq!(Fraction(i32::MIN, 1).checked_div(Fraction(-1, 1)), None);
+        assert_eq!(Fraction::ONE.checked_rem(Fraction::ZERO), None);
+
+        // The remainder has the sign of the dividend like the integer remainder.
+        assert_eq!(Fraction::new(7, 2) % Fraction::new(1, 1), Fraction::new(1, 2));
+        assert_eq!(Fraction::new(-7, 2) % Fraction::new(1, 1), Fraction::new(-1, 2));
+        assert_eq!(Fraction::new(7, 2) % Fraction::new(-4, 3), Fraction::new(5, 6));
+        assert_eq!(Fraction::new(1, 3) % Fraction::new(1, 2), Fraction::new(1, 3));
+        assert_eq!(Fraction(i32::MIN, 1) % Fraction(-1, 1), Fraction::ZERO);
+
+        // Checked arithmetic matches floating point where the result fits.
+        let values = [-7, -3, -1, 0, 1, 2, 5, 1000];
+        for a in values {
+            for b in values.iter().copied().filter(|b| *b != 0) {
+                for c in values {
+                    for d in [1, 3, -4, 1000] {
+                        let first = Fraction::new(a, b);
+                        let second = Fraction::new(c, d);
+                        let (x, y) = ((a as f64) / (b as f64), (c as f64) / (d as f64));
+
+                        let sum = first + second;
+                        assert!(((sum.0 as f64) / (sum.1 as f64) - (x + y)).abs() < 1e-9);
+                        assert_eq!(first - second, first + -second);
+                        let product = first * second;
+                        assert!(((product.0 as f64) / (product.1 as f64) - x * y).abs() < 1e-9);
+                        if c != 0 {
+                            assert_eq!(first / second * second, first);
+                            let quotient = ((first / second).0 / (first / second).1) as f64;
+                            let remainder = first % second;
+                            assert!(((remainder.0 as f64) / (remainder.1 as f64) - (x - quotient * y)).abs() < 1e-9);
+                        }
+                        assert_eq!(first.cmp(&second), x.partial_cmp(&y).unwrap());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_saturating_arithmetic() {
+        let big = Fraction::new(i32::MAX, 1);
+        assert_eq!(big.saturating_add(big), Fraction(i32::MAX, 1));
+        assert_eq!((-big).saturating_sub(big), Fraction(i32::MIN, 1));
+        assert_eq!(big.saturating_mul(Fraction::new(-3, 2)), Fraction(i32::MIN, 1));
+        assert_eq!(big.saturating_div(Fraction::new(1, 2)), Fraction(i32::MAX, 1));
+        assert_eq!(Fraction::new(5, 6).saturating_add(Fraction::new(1, 6)), Fraction::ONE);
+
+        // Values in range with denominators that do not fit are approximated.
+        let tiny = Fraction::new(1, i32::MAX);
+        let approximate = tiny.saturating_add(Fraction::new(1, i32::MAX - 1));
+        let exact = 1.0 / (i32::MAX as f64) + 1.0 / ((i32::MAX - 1) as f64);
+        assert!(((approximate.0 as f64) / (approximate.1 as f64) / exact - 1.0).abs() < 1e-8);
+
+        let third = Fraction::new(i32::MAX - 1, 3);
+        let approximate = third.saturating_mul(Fraction::new(2, i32::MAX));
+        let exact = ((i32::MAX - 1) as f64) / 3.0 * 2.0 / (i32::MAX as f64);
+        assert!(((approximate.0 as f64) / (approximate.1 as f64) / exact - 1.0).abs() < 1e-8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_operator_overflow_panics() {
+        let _ = Fraction::new(i32::MAX, 1) + Fraction::ONE;
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_denominator_panics() {
+        Fraction::new(1, 0);
+    }
+
+    #[test]
+    fn test_comparison_hashing_and_conversion() {
+        assert_eq!(Fraction(1, 2), Fraction(500, 1000));
+        assert_eq!(Fraction(1, 2), Fraction(-1, -2));
+        assert_eq!(hash_of(Fraction(1, 2)), hash_of(Fraction(-500, -1000)));
+        assert_ne!(hash_of(Fraction(1, 2)), hash_of(Fraction(1, 3)));
+        assert!(Fraction(-1, 2) < Fraction(1, -3));
+        assert!(Fraction(i32::MAX, 1) > Fraction(i32::MAX - 1, 1));
+        assert!(Fraction(1, i32::MAX) > Fraction(1, i32::MIN));
+
+        assert_eq!(Fraction(707, 1000), (707, 1000));
+        assert_eq!((707, 1000), Fraction(707, 1000));
+        assert_eq!(Fraction::from((3, 4)), Fraction(3, 4));
+        assert_eq!(<(i32, i32)>::from(Fraction(3, 4)), (3, 4));
+
+        // Fractions with a zero denominator compare and hash without panicking.
+        assert_eq!(Fraction(1, 0), Fraction(1, 0));
+        assert_ne!(Fraction(1, 0), Fraction(2, 0));
+        assert_ne!(Fraction(0, 0), Fraction::ZERO);
+        assert!(Fraction(-1, 0) < Fraction(1, 0));
+        assert!(Fraction(i32::MAX, 1) < Fraction(i32::MIN, 0));
+        assert_eq!(hash_of(Fraction(1, 0)), hash_of(Fraction(1, 0)));
+        assert_ne!(hash_of(Fraction(1, 0)), hash_of(Fraction(2, 0)));
+        assert_eq!(Fraction(-3, 4).to_string(), "-3/4");
+        assert_eq!(Fraction(500, 1000).to_string(), "500/1000");
+        assert_eq!(Fraction::default(), Fraction::ZERO);
+
+        // The outputs of the trig functions can be passed straight back in.
+        let d_trig = DTrig::initialize();
+        let angle = d_trig.arctangent((1, 1));
+        assert_eq!(angle, (785, 1000));
+        assert_eq!(d_trig.sine(angle), d_trig.sine((785, 1000)));
+        assert_eq!(d_trig.sine(angle).1, 1000);
+    }
+
+    #[test]
+    fn test_rescale_with_rounding() {
+        let modes = [
+            (RoundingMode::HalfAwayFromZero, [2, -2, 1, -1]),
+            (RoundingMode::HalfEven, [2, -2, 0, 0]),
+            (RoundingMode::Floor, [1, -2, 0, -1]),
+            (RoundingMode::Ceiling, [2, -1, 1, 0]),
+            (RoundingMode::Truncate, [1, -1, 0, 0]),
+        ];
+
+        for (mode, results) in modes {
+            // 1.5, -1.5, 0.5 and -0.5 thousandths, with the signs on either part.
+            assert_eq!(Fraction(3, 2000).rescale_with_rounding(1000, mode).0, results[0]);
+            assert_eq!(Fraction(-3, -2000).rescale_with_rounding(1000, mode).0, results[0]);
+            assert_eq!(Fraction(-3, 2000).rescale_with_rounding(1000, mode).0, results[1]);
+            assert_eq!(Fraction(3, -2000).rescale_with_rounding(1000, mode).0, results[1]);
+            assert_eq!(Fraction(1, 2000).rescale_with_rounding(1000, mode).0, results[2]);
+            assert_eq!(Fraction(-1, -2000).rescale_with_rounding(1000, mode).0, results[2]);
+            assert_eq!(Fraction(-1, 2000).rescale_with_rounding(1000, mode).0, results[3]);
+            assert_eq!(Fraction(1, -2000).rescale_with_rounding(1000, mode).0, results[3]);
+
+            // A negative target denominator gives the same value.
+            assert_eq!(
+                Fraction(-3, 2000).rescale_with_rounding(-1000, mode),
+                Fraction(-3, 2000).rescale_with_rounding(1000, mode)
+            );
+        }
+    }
+
+    #[test]
+    fn test_trig_functions_use_the_rounding_mode() {
+        let d_trig = DTrig::initialize();
+
+        for (mode, thousandths) in [
+            (RoundingMode::HalfAwayFromZero, [2, -2, 1, -1]),
+            (RoundingMode::HalfEven, [2, -2, 0, 0]),
+            (RoundingMode::Floor, [1, -2, 0, -1]),
+            (RoundingMode::Ceiling, [2, -1, 1, 0]),
+            (RoundingMode::Truncate, [1, -1, 0, 0]),
+        ] {
+            let rounded = DTrig::initialize().with_rounding(mode);
+            assert_eq!(rounded.rounding(), mode);
+
+            for (fraction, expected) in [(3, 2000), (-3, 2000), (1, 2000), (1, -2000)].into_iter().zip(thousandths) {
+                assert_eq!(rounded.sine(fraction), d_trig.sine((expected, 1000)));
+                assert_eq!(rounded.cosine((-fraction.0, -fraction.1)), d_trig.cosine((expected, 1000)));
+                assert_eq!(rounded.tangent(fraction), d_trig.tangent((expected, 1000)));
+                assert_eq!(rounded.arcsine(fraction), d_trig.arcsine((expected, 1000)));
+                assert_eq!(rounded.arccosine(fraction), d_trig.arccosine((expected, 1000)));
+                assert_eq!(rounded.arctangent(fraction), d_trig.arctangent((expected, 1000)));
+                assert_eq!(rounded.polar_to_cartesian(1000, fraction), d_trig.polar_to_cartesian(1000, (expected, 1000)));
+            }
+        }
+
+        // Rounding toward zero keeps an input just past one inside the domain of arcsine.
+        let truncating = DTrig::initialize().with_rounding(RoundingMode::Truncate);
+        assert_eq!(truncating.arcsine((10005, 10000)), d_trig.arcsine((1, 1)));
+        assert_eq!(truncating.arccosine((-10005, 10000)), d_trig.arccosine((-1, 1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_default_rounding_rounds_past_one() {
+        DTrig::initialize().arcsine((10005, 10000));
+    }
+}
@@ -0,0 +1,119 @@
+/* This module contains the trait that lets code be written once for every value type the trig functions support. The
+trait is implemented by `DTrig` for each value type, and every implementation calls the same table lookups as the
+methods of `DTrig`, so the results are the same whichever way a function is called. */
+
+use crate::{ DTrig, Fraction };
+
+/// The trig functions for one value type, for code that is generic over the type it works with.
+///
+/// - Implemented by `DTrig` for `Fraction`, and for `I16F16` and `I32F32` with the `fixed` feature.
+/// - Each function takes and returns the same type.
+/// - Gives the same results as the methods of `DTrig` with the same names.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::{ DeterministicTrig, DTrig, Fraction };
+///
+/// // Works with any value type the trig functions support.
+/// fn rotate<T: Copy, D: DeterministicTrig<T>>(d_trig: &D, heading: T) -> (T, T) {
+///     (d_trig.cosine(heading), d_trig.sine(heading))
+/// }
+///
+/// fn main (){
+///
+/// let d_trig = DTrig::initialize();
+///
+/// let (x, y) = rotate(&d_trig, Fraction(785, 1000));
+///
+/// println!("The direction of 785/1000 radians is ({}, {}).", x, y);
+///
+/// }
+/// ```
+pub trait DeterministicTrig<T> {
+    /// Calculates the sine of an angle in radians.
+    fn sine(&self, argument: T) -> T;
+
+    /// Calculates the cosine of an angle in radians.
+    fn cosine(&self, argument: T) -> T;
+
+    /// Calculates the tangent of an angle in radians.
+    fn tangent(&self, argument: T) -> T;
+
+    /// Calculates the arcsine of a value and gives the angle in radians.
+    ///
+    /// # Panics
+    ///
+    /// - An input that is less than -1 or greater than 1 after rounding to thousandths.
+    fn arcsine(&self, argument: T) -> T;
+
+    /// Calculates the arccosine of a value and gives the angle in radians.
+    ///
+    /// # Panics
+    ///
+    /// - An input that is less than -1 or greater than 1 after rounding to thousandths.
+    fn arccosine(&self, argument: T) -> T;
+
+    /// Calculates the arctangent of a value and gives the angle in radians.
+    fn arctangent(&self, argument: T) -> T;
+}
+
+impl DeterministicTrig<Fraction> for DTrig {
+    fn sine(&self, argument: Fraction) -> Fraction {
+        DTrig::sine(self, argument)
+    }
+
+    fn cosine(&self, argument: Fraction) -> Fraction {
+        DTrig::cosine(self, argument)
+    }
+
+    fn tangent(&self, argument: Fraction) -> Fraction {
+        DTrig::tangent(self, argument)
+    }
+
+    fn arcsine(&self, argument: Fraction) -> Fraction {
+        DTrig::arcsine(self, argument)
+    }
+
+    fn arccosine(&self, argument: Fraction) -> Fraction {
+        DTrig::arccosine(self, argument)
+    }
+
+    fn arctangent(&self, argument: Fraction) -> Fraction {
+        DTrig::arctangent(self, argument)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Calls the functions only through the trait.
+    fn through_trait<T: Copy, D: DeterministicTrig<T>>(d_trig: &D, argument: T) -> [T; 4] {
+        [d_trig.sine(argument), d_trig.cosine(argument), d_trig.tangent(argument), d_trig.arctangent(argument)]
+    }
+
+    #[test]
+    fn test_trait_matches_methods() {
+        let d_trig = DTrig::initialize();
+
+        for numerator in -10000..10000 {
+            let argument = Fraction(numerator, 997);
+            assert_eq!(
+                through_trait(&d_trig, argument),
+                [
+                    d_trig.sine(argument),
+                    d_trig.cosine(argument),
+                    d_trig.tangent(argument),
+                    d_trig.arctangent(argument),
+                ]
+            );
+        }
+
+        for numerator in -1000..1001 {
+            let argument = Fraction(numerator, 1000);
+            assert_eq!(DeterministicTrig::arcsine(&d_trig, argument), d_trig.arcsine(argument));
+            assert_eq!(DeterministicTrig::arccosine(&d_trig, argument), d_trig.arccosine(argument));
+        }
+    }
+}
@@ -0,0 +1,716 @@
+/* This module contains intersection tests between rays, segments and circles. Every test is solved with exact i128
+integer arithmetic and only rounded at the end, so hit results are identical on every machine. Coordinates and radii
+must stay between -2^29 and 2^29, which keeps every intermediate product inside the i128 range. */
+
+use crate::utility;
+use crate::{ DTrig, IntoFraction };
+use crate::DVec2;
+
+// The largest coordinate or radius magnitude that the intersection math can handle without overflow.
+const MAX_COORDINATE: i32 = 1 << 29;
+
+/// A ray that starts at an origin and continues forever in one direction.
+///
+/// - Points on the ray are the origin plus the direction times a parameter of zero or more.
+/// - Rays built with `from_angle` have a direction about 1000 long, so a parameter in thousandths is about a distance.
+/// - Deserializing a ray with a zero direction is an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "RayFields"))]
+pub struct Ray {
+    /// The point the ray starts from.
+    pub origin: DVec2,
+    /// The direction the ray travels in, which does not need to be any particular length.
+    pub direction: DVec2,
+}
+
+impl Ray {
+    /// Creates a ray from an origin and a direction.
+    pub const fn new(origin: DVec2, direction: DVec2) -> Self {
+        Ray { origin, direction }
+    }
+
+    /// Creates a ray that travels from an origin at an angle in radians.
+    ///
+    /// - The input tuple represents the angle as a numerator and denominator.
+    /// - The direction is the cosine and sine of the angle as numerators out of 1000.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::DTrig;
+    /// use deterministic_trigonometry::DVec2;
+    /// use deterministic_trigonometry::geometry::{ Ray, Segment, ray_segment_intersection };
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize();
+    ///
+    /// let shot = Ray::from_angle(&d_trig, DVec2::new(0, 0), (785, 1000));
+    /// let wall = Segment::new(DVec2::new(100, -500), DVec2::new(100, 500));
+    ///
+    /// if let Some(hit) = ray_segment_intersection(&shot, &wall) {
+    ///     println!("The shot hit the wall at ({}, {}).", hit.point.x, hit.point.y);
+    /// }
+    ///
+    /// }
+    /// ```
+    pub fn from_angle<F: IntoFraction>(d_trig: &DTrig, origin: DVec2, angle_fraction: F) -> Self {
+        let angle_fraction = angle_fraction.into_fraction();
+
+        Ray {
+            origin,
+            direction: DVec2::new(d_trig.cosine(angle_fraction).0, d_trig.sine(angle_fraction).0),
+        }
+    }
+}
+
+/// A line segment between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment {
+    /// The point the segment starts from.
+    pub start: DVec2,
+    /// The point the segment ends at.
+    pub end: DVec2,
+}
+
+impl Segment {
+    /// Creates a segment from its two end points.
+    pub const fn new(start: DVec2, end: DVec2) -> Self {
+        Segment { start, end }
+    }
+}
+
+/// A circle with an integer center and radius.
+///
+/// - Deserializing a circle with a negative radius is an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "CircleFields"))]
+pub struct Circle {
+    /// The center of the circle.
+    pub center: DVec2,
+    /// The radius of the circle.
+    pub radius: i32,
+}
+
+impl Circle {
+    /// Creates a circle from its center and radius.
+    pub const fn new(center: DVec2, radius: i32) -> Self {
+        Circle { center, radius }
+    }
+}
+
+// The fields of a ray as they are decoded, before the direction is checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RayFields {
+    origin: DVec2,
+    direction: DVec2,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RayFields> for Ray {
+    type Error = &'static str;
+
+    fn try_from(fields: RayFields) -> Result<Self, Self::Error> {
+        if fields.direction == DVec2::ZERO {
+            return Err("Ray with a zero direction, which has no heading.");
+        }
+
+        Ok(Ray::new(fields.origin, fields.direction))
+    }
+}
+
+// The fields of a circle as they are decoded, before the radius is checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct CircleFields {
+    center: DVec2,
+    radius: i32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<CircleFields> for Circle {
+    type Error = &'static str;
+
+    fn try_from(fields: CircleFields) -> Result<Self, Self::Error> {
+        if fields.radius < 0 {
+            return Err("Circle with a negative radius.");
+        }
+
+        Ok(Circle::new(fields.center, fields.radius))
+    }
+}
+
+/// The first point where a ray or segment meets another shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Intersection {
+    /// How far along the ray direction or first segment the hit is as a numerator out of 1000.
+    ///
+    /// - A segment runs from 0/1000 at its start to 1000/1000 at its end.
+    pub parameter: i64,
+    /// The hit point rounded to the nearest integer with halves rounded away from zero.
+    pub point: DVec2,
+}
+
+/// Finds the first point where a ray meets a segment.
+///
+/// - Returns `None` if they do not meet.
+/// - If the ray runs along the segment, the hit is the first point of the segment on the ray.
+/// - The parameter and point are rounded from the exact rational result.
+///
+/// # Panics
+///
+/// - A ray with a zero direction.
+/// - A coordinate outside of -2^29 to 2^29.
+pub fn ray_segment_intersection(ray: &Ray, segment: &Segment) -> Option<Intersection> {
+    check_ray(ray);
+    check_point(segment.start);
+    check_point(segment.end);
+
+    let edge = segment.end - segment.start;
+    let offset = segment.start - ray.origin;
+    let mut denominator = ray.direction.cross(edge);
+    let mut ray_numerator = offset.cross(edge);
+    let mut segment_numerator = offset.cross(ray.direction);
+
+    if denominator == 0 {
+        if segment_numerator != 0 {
+            // Parallel lines never meet.
+            return None;
+        }
+
+        // The segment lies on the line of the ray, so finds the first part of it at or past the origin.
+        let length_squared = ray.direction.length_squared();
+        let start_along = offset.dot(ray.direction);
+        let end_along = (segment.end - ray.origin).dot(ray.direction);
+
+        return if start_along.min(end_along) <= 0 && start_along.max(end_along) >= 0 {
+            Some(Intersection { parameter: 0, point: ray.origin })
+        } else if start_along < 0 {
+            None
+        } else if start_along <= end_along {
+            Some(Intersection {
+                parameter: thousandths(start_along, length_squared),
+                point: segment.start,
+            })
+        } else {
+            Some(Intersection {
+                parameter: thousandths(end_along, length_squared),
+                point: segment.end,
+            })
+        };
+    }
+
+    // Makes the denominator positive so the bounds can be checked without dividing.
+    if denominator < 0 {
+        denominator = -denominator;
+        ray_numerator = -ray_numerator;
+        segment_numerator = -segment_numerator;
+    }
+
+    if ray_numerator < 0 || segment_numerator < 0 || segment_numerator > denominator {
+        return None;
+    }
+
+    Some(Intersection {
+        parameter: thousandths(ray_numerator, denominator),
+        point: point_along(ray.origin, ray.direction, ray_numerator, denominator),
+    })
+}
+
+/// Finds the point where two segments meet that is closest to the start of the first segment.
+///
+/// - Returns `None` if they do not meet.
+/// - The parameter is measured along the first segment.
+///
+/// # Panics
+///
+/// - A coordinate outside of -2^29 to 2^29.
+pub fn segment_segment_intersection(first: &Segment, second: &Segment) -> Option<Intersection> {
+    check_point(first.start);
+    check_point(first.end);
+    check_point(second.start);
+    check_point(second.end);
+
+    let first_edge = first.end - first.start;
+    let second_edge = second.end - second.start;
+    let offset = second.start - first.start;
+    let mut denominator = first_edge.cross(second_edge);
+    let mut first_numerator = offset.cross(second_edge);
+    let mut second_numerator = offset.cross(first_edge);
+
+    if denominator == 0 {
+        if second_numerator != 0 || first_numerator != 0 {
+            // Parallel lines never meet.
+            return None;
+        }
+
+        let length_squared = first_edge.length_squared();
+
+        if length_squared == 0 {
+            // The first segment is a single point.
+            return if on_segment(first.start, second) {
+                Some(Intersection { parameter: 0, point: first.start })
+            } else {
+                None
+            };
+        }
+
+        // Both segments lie on one line, so finds where the overlap begins.
+        let start_along = offset.dot(first_edge);
+        let end_along = (second.end - first.start).dot(first_edge);
+        let (nearest_along, nearest_point) = if start_along <= end_along {
+            (start_along, second.start)
+        } else {
+            (end_along, second.end)
+        };
+
+        return if start_along.max(end_along) < 0 || nearest_along > length_squared {
+            None
+        } else if nearest_along <= 0 {
+            Some(Intersection { parameter: 0, point: first.start })
+        } else {
+            Some(Intersection {
+                parameter: thousandths(nearest_along, length_squared),
+                point: nearest_point,
+            })
+        };
+    }
+
+    // Makes the denominator positive so the bounds can be checked without dividing.
+    if denominator < 0 {
+        denominator = -denominator;
+        first_numerator = -first_numerator;
+        second_numerator = -second_numerator;
+    }
+
+    if
+        first_numerator < 0 ||
+        first_numerator > denominator ||
+        second_numerator < 0 ||
+        second_numerator > denominator
+    {
+        return None;
+    }
+
+    Some(Intersection {
+        parameter: thousandths(first_numerator, denominator),
+        point: point_along(first.start, first_edge, first_numerator, denominator),
+    })
+}
+
+/// Finds the first point where a ray meets the edge of a circle.
+///
+/// - Returns `None` if they do not meet.
+/// - A ray that starts inside the circle hits the edge on the way out.
+/// - The hit point is accurate to within one unit.
+///
+/// # Panics
+///
+/// - A ray with a zero direction.
+/// - A negative radius.
+/// - A coordinate or radius outside of -2^29 to 2^29.
+pub fn ray_circle_intersection(ray: &Ray, circle: &Circle) -> Option<Intersection> {
+    check_ray(ray);
+    check_circle(circle);
+
+    // Solves |offset + t * direction|^2 = radius^2 for t using half of the usual b coefficient.
+    let offset = ray.origin - circle.center;
+    let a = ray.direction.length_squared();
+    let half_b = offset.dot(ray.direction);
+    let c = offset.length_squared() - i128::from(circle.radius) * i128::from(circle.radius);
+    let discriminant = half_b * half_b - a * c;
+
+    if discriminant < 0 {
+        return None;
+    }
+
+    let root = utility::rounded_square_root(discriminant as u128) as i128;
+
+    let numerator = if -half_b - root >= 0 {
+        -half_b - root
+    } else if -half_b + root >= 0 {
+        -half_b + root
+    } else {
+        return None;
+    };
+
+    Some(Intersection {
+        parameter: thousandths(numerator, a),
+        point: point_along(ray.origin, ray.direction, numerator, a),
+    })
+}
+
+/// Finds the points where the edges of two circles cross.
+///
+/// - Returns `None` if the edges do not meet or the circles are the same.
+/// - Circles that touch at one point return that point twice.
+/// - The first point is on the left of the line from the first center to the second center.
+///
+/// # Panics
+///
+/// - A negative radius.
+/// - A coordinate or radius outside of -2^29 to 2^29.
+pub fn circle_circle_intersection(first: &Circle, second: &Circle) -> Option<(DVec2, DVec2)> {
+    check_circle(first);
+    check_circle(second);
+
+    let between = second.center - first.center;
+    let distance_squared = between.length_squared();
+    let first_radius_squared = i128::from(first.radius) * i128::from(first.radius);
+    let second_radius_squared = i128::from(second.radius) * i128::from(second.radius);
+    let radius_sum = i128::from(first.radius) + i128::from(second.radius);
+    let radius_difference = i128::from(first.radius) - i128::from(second.radius);
+
+    if
+        distance_squared == 0 ||
+        distance_squared > radius_sum * radius_sum ||
+        distance_squared < radius_difference * radius_difference
+    {
+        return None;
+    }
+
+    // The chord is along_numerator / (2 * distance_squared) of the way between the centers.
+    let along_numerator = first_radius_squared - second_radius_squared + distance_squared;
+    let height_squared = 4 * distance_squared * first_radius_squared - along_numerator * along_numerator;
+    let height = utility::rounded_square_root(height_squared as u128) as i128;
+    let perpendicular = between.perpendicular();
+    let denominator = 2 * distance_squared;
+
+    let point = |side: i128| {
+        DVec2::new(
+            narrow(
+                i128::from(first.center.x) +
+                    utility::divide_and_round(
+                        i128::from(between.x) * along_numerator +
+                            side * i128::from(perpendicular.x) * height,
+                        denominator
+                    )
+            ),
+            narrow(
+                i128::from(first.center.y) +
+                    utility::divide_and_round(
+                        i128::from(between.y) * along_numerator +
+                            side * i128::from(perpendicular.y) * height,
+                        denominator
+                    )
+            )
+        )
+    };
+
+    Some((point(1), point(-1)))
+}
+
+/// Finds the points on a circle where lines from an outside point just touch the circle.
+///
+/// - Returns `None` if the point is inside the circle.
+/// - A point on the edge of the circle returns itself twice.
+/// - The first point is on the left when looking from the outside point toward the center.
+///
+/// # Panics
+///
+/// - A negative radius.
+/// - A coordinate or radius outside of -2^29 to 2^29.
+pub fn tangent_points(point: DVec2, circle: &Circle) -> Option<(DVec2, DVec2)> {
+    check_point(point);
+    check_circle(circle);
+
+    let outward = point - circle.center;
+    let distance_squared = outward.length_squared();
+    let radius_squared = i128::from(circle.radius) * i128::from(circle.radius);
+
+    if distance_squared < radius_squared || distance_squared == 0 {
+        return if distance_squared == 0 && radius_squared == 0 { Some((point, point)) } else { None };
+    }
+
+    // Each tangent point is center + (outward * r^2 +/- perpendicular * r * sqrt(d^2 - r^2)) / d^2.
+    let side_length =
+        i128::from(circle.radius) *
+        (utility::rounded_square_root((distance_squared - radius_squared) as u128) as i128);
+    let perpendicular = outward.perpendicular();
+
+    let tangent = |side: i128| {
+        DVec2::new(
+            narrow(
+                i128::from(circle.center.x) +
+                    utility::divide_and_round(
+                        i128::from(outward.x) * radius_squared +
+                            side * i128::from(perpendicular.x) * side_length,
+                        distance_squared
+                    )
+            ),
+            narrow(
+                i128::from(circle.center.y) +
+                    utility::divide_and_round(
+                        i128::from(outward.y) * radius_squared +
+                            side * i128::from(perpendicular.y) * side_length,
+                        distance_squared
+                    )
+            )
+        )
+    };
+
+    Some((tangent(-1), tangent(1)))
+}
+
+// Outputs the numerator out of 1000 nearest to a rational parameter.
+fn thousandths(numerator: i128, denominator: i128) -> i64 {
+    utility::divide_and_round(numerator * 1000, denominator) as i64
+}
+
+// Outputs the point a rational fraction of the way along a direction from a start point.
+fn point_along(start: DVec2, direction: DVec2, numerator: i128, denominator: i128) -> DVec2 {
+    DVec2::new(
+        narrow(
+            i128::from(start.x) +
+                utility::divide_and_round(i128::from(direction.x) * numerator, denominator)
+        ),
+        narrow(
+            i128::from(start.y) +
+                utility::divide_and_round(i128::from(direction.y) * numerator, denominator)
+        )
+    )
+}
+
+// Checks whether a point lies on a segment.
+fn on_segment(point: DVec2, segment: &Segment) -> bool {
+    let edge = segment.end - segment.start;
+    let offset = point - segment.start;
+    let along = offset.dot(edge);
+
+    edge.cross(offset) == 0 && along >= 0 && along <= edge.length_squared()
+}
+
+fn check_point(point: DVec2) {
+    // The unsigned size of i32::MIN does not overflow the way its absolute value would.
+    if point.x.unsigned_abs() > MAX_COORDINATE as u32 || point.y.unsigned_abs() > MAX_COORDINATE as u32 {
+        panic!("Geometry coordinate outside of -2^29 to 2^29.");
+    }
+}
+
+fn check_ray(ray: &Ray) {
+    check_point(ray.origin);
+    check_point(ray.direction);
+
+    if ray.direction == DVec2::ZERO {
+        panic!("Ray with a zero direction, which has no heading.");
+    }
+}
+
+fn check_circle(circle: &Circle) {
+    check_point(circle.center);
+
+    if circle.radius < 0 {
+        panic!("Circle with a negative radius.");
+    } else if circle.radius > MAX_COORDINATE {
+        panic!("Circle radius greater than 2^29.");
+    }
+}
+
+// Converts a rounded coordinate back to an i32.
+fn narrow(value: i128) -> i32 {
+    match i32::try_from(value) {
+        Ok(narrowed) => narrowed,
+        Err(_) => panic!("Geometry result too large for an i32."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ray_segment_intersection() {
+        let d_trig = DTrig::initialize();
+        let wall = Segment::new(DVec2::new(100, -500), DVec2::new(100, 500));
+
+        let straight = Ray::new(DVec2::new(0, 7), DVec2::new(1000, 0));
+        assert_eq!(
+            ray_segment_intersection(&straight, &wall),
+            Some(Intersection { parameter: 100, point: DVec2::new(100, 7) })
+        );
+
+        let diagonal = Ray::from_angle(&d_trig, DVec2::new(0, 0), (785, 1000));
+        assert_eq!(
+            ray_segment_intersection(&diagonal, &wall),
+            Some(Intersection { parameter: 141, point: DVec2::new(100, 100) })
+        );
+
+        // Misses behind the origin, past the ends and parallel to the wall.
+        assert_eq!(ray_segment_intersection(&Ray::new(DVec2::new(200, 0), DVec2::new(1, 0)), &wall), None);
+        assert_eq!(ray_segment_intersection(&Ray::new(DVec2::new(0, 0), DVec2::new(1, 6)), &wall), None);
+        assert_eq!(ray_segment_intersection(&Ray::new(DVec2::new(0, 0), DVec2::new(0, 1)), &wall), None);
+
+        // Runs along the wall from below and from inside it.
+        assert_eq!(
+            ray_segment_intersection(&Ray::new(DVec2::new(100, -900), DVec2::new(0, 2)), &wall),
+            Some(Intersection { parameter: 200000, point: DVec2::new(100, -500) })
+        );
+        assert_eq!(
+            ray_segment_intersection(&Ray::new(DVec2::new(100, 0), DVec2::new(0, -1)), &wall),
+            Some(Intersection { parameter: 0, point: DVec2::new(100, 0) })
+        );
+
+        // Touching an end point counts as a hit.
+        assert_eq!(
+            ray_segment_intersection(&Ray::new(DVec2::new(0, 500), DVec2::new(5, 0)), &wall),
+            Some(Intersection { parameter: 20000, point: DVec2::new(100, 500) })
+        );
+    }
+
+    #[test]
+    fn test_segment_segment_intersection() {
+        let first = Segment::new(DVec2::new(0, 0), DVec2::new(1000, 1000));
+        let second = Segment::new(DVec2::new(0, 1000), DVec2::new(1000, 0));
+        assert_eq!(
+            segment_segment_intersection(&first, &second),
+            Some(Intersection { parameter: 500, point: DVec2::new(500, 500) })
+        );
+
+        // Rounds the rational result.
+        let third = Segment::new(DVec2::new(0, 1), DVec2::new(3, 1));
+        let fourth = Segment::new(DVec2::new(1, 0), DVec2::new(2, 3));
+        assert_eq!(
+            segment_segment_intersection(&third, &fourth),
+            Some(Intersection { parameter: 444, point: DVec2::new(1, 1) })
+        );
+
+        // Overlapping and separate segments on one line.
+        let line = Segment::new(DVec2::new(0, 0), DVec2::new(10, 0));
+        let overlap = Segment::new(DVec2::new(14, 0), DVec2::new(5, 0));
+        let apart = Segment::new(DVec2::new(11, 0), DVec2::new(20, 0));
+        assert_eq!(
+            segment_segment_intersection(&line, &overlap),
+            Some(Intersection { parameter: 500, point: DVec2::new(5, 0) })
+        );
+        assert_eq!(segment_segment_intersection(&line, &apart), None);
+        assert_eq!(
+            segment_segment_intersection(&overlap, &line),
+            Some(Intersection { parameter: 444, point: DVec2::new(10, 0) })
+        );
+
+        // A segment that stops short.
+        let short = Segment::new(DVec2::new(0, 1000), DVec2::new(400, 600));
+        assert_eq!(segment_segment_intersection(&first, &short), None);
+
+        // A single point segment.
+        let dot = Segment::new(DVec2::new(3, 0), DVec2::new(3, 0));
+        assert_eq!(
+            segment_segment_intersection(&dot, &line),
+            Some(Intersection { parameter: 0, point: DVec2::new(3, 0) })
+        );
+    }
+
+    #[test]
+    fn test_ray_circle_intersection() {
+        let d_trig = DTrig::initialize();
+        let circle = Circle::new(DVec2::new(500, 0), 100);
+
+        let straight = Ray::new(DVec2::new(0, 0), DVec2::new(1000, 0));
+        assert_eq!(
+            ray_circle_intersection(&straight, &circle),
+            Some(Intersection { parameter: 400, point: DVec2::new(400, 0) })
+        );
+
+        // Starting inside hits the edge on the way out.
+        let inside = Ray::new(DVec2::new(500, 0), DVec2::new(0, 3));
+        assert_eq!(
+            ray_circle_intersection(&inside, &circle),
+            Some(Intersection { parameter: 33333, point: DVec2::new(500, 100) })
+        );
+
+        // Misses to the side and behind.
+        assert_eq!(ray_circle_intersection(&Ray::new(DVec2::new(0, 101), DVec2::new(1, 0)), &circle), None);
+        assert_eq!(ray_circle_intersection(&Ray::new(DVec2::new(0, 0), DVec2::new(-1, 0)), &circle), None);
+
+        // Every hit from a ray cast at an angle lands on the edge of the circle.
+        for angle in (-200..201).step_by(3) {
+            let ray = Ray::from_angle(&d_trig, DVec2::new(0, 0), (angle, 1000));
+            if let Some(hit) = ray_circle_intersection(&ray, &circle) {
+                let distance = (hit.point - circle.center).length();
+                assert!((distance - 100).abs() <= 1);
+                assert!((hit.parameter - (hit.point.length())).abs() <= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_circle_circle_intersection() {
+        let first = Circle::new(DVec2::new(0, 0), 500);
+        let second = Circle::new(DVec2::new(600, 0), 500);
+        assert_eq!(
+            circle_circle_intersection(&first, &second),
+            Some((DVec2::new(300, 400), DVec2::new(300, -400)))
+        );
+
+        // Touching circles return the point where they touch twice.
+        let touching = Circle::new(DVec2::new(1000, 0), 500);
+        assert_eq!(
+            circle_circle_intersection(&first, &touching),
+            Some((DVec2::new(500, 0), DVec2::new(500, 0)))
+        );
+
+        // Too far apart, one inside the other and the same circle.
+        assert_eq!(circle_circle_intersection(&first, &Circle::new(DVec2::new(1001, 0), 500)), None);
+        assert_eq!(circle_circle_intersection(&first, &Circle::new(DVec2::new(10, 0), 20)), None);
+        assert_eq!(circle_circle_intersection(&first, &first), None);
+
+        // The largest allowed values do not overflow.
+        let huge = Circle::new(DVec2::new(-(1 << 29), -(1 << 29)), 1 << 29);
+        let other = Circle::new(DVec2::new(1 << 29, -(1 << 29)), 1 << 29);
+        assert_eq!(
+            circle_circle_intersection(&huge, &other),
+            Some((DVec2::new(0, -(1 << 29)), DVec2::new(0, -(1 << 29))))
+        );
+    }
+
+    #[test]
+    fn test_tangent_points() {
+        let circle = Circle::new(DVec2::new(0, 0), 300);
+
+        assert_eq!(
+            tangent_points(DVec2::new(500, 0), &circle),
+            Some((DVec2::new(180, -240), DVec2::new(180, 240)))
+        );
+        assert_eq!(
+            tangent_points(DVec2::new(0, 300), &circle),
+            Some((DVec2::new(0, 300), DVec2::new(0, 300)))
+        );
+        assert_eq!(tangent_points(DVec2::new(100, 100), &circle), None);
+
+        // Each tangent line is perpendicular to the radius at the tangent point.
+        for x in (-2000..2001).step_by(97) {
+            for y in (-2000..2001).step_by(89) {
+                let point = DVec2::new(x, y);
+                if let Some((left, right)) = tangent_points(point, &circle) {
+                    for tangent in [left, right] {
+                        let along = (point - tangent).dot(tangent) as f64;
+                        let lengths =
+                            ((point - tangent).length() as f64) * (tangent.length() as f64);
+                        assert!(lengths == 0.0 || (along / lengths).abs() < 0.01);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_coordinate_out_of_range_panics() {
+        let far = Segment::new(DVec2::new(i32::MAX, 0), DVec2::new(0, 0));
+        segment_segment_intersection(&far, &far);
+    }
+
+    #[test]
+    #[should_panic(expected = "Geometry coordinate outside of -2^29 to 2^29.")]
+    fn test_minimum_coordinate_panics() {
+        let far = Segment::new(DVec2::new(0, i32::MIN), DVec2::new(0, 0));
+        segment_segment_intersection(&far, &far);
+    }
+}
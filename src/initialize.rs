@@ -2830,6 +2830,8 @@ impl crate::DTrig {
                     1570, 1570, 1570, 1570, 1570, 1570, 1570, 1570, 1570, 1570, 1570,
                 ]
             },
+            rounding: crate::RoundingMode::HalfAwayFromZero,
+            compatibility: crate::Compatibility::Current,
         };
     }
 }
@@ -29,9 +29,12 @@
 /// Once this struct is initialized, it holds arrays with pre-baked trig functions.
 /// Trig functions are called as methods with the input as (i32 , i32) tuples with
 /// the first i32 representing the numerator an the second i32 representing the denominator.
+/// Tuples of other integer types such as (i64 , i64) or (u32 , u32) are also accepted through the
+/// `IntoFraction` trait.
 ///
-/// The output is also a (i32 , i32) tuple with the first i32 representing the numerator
-/// and the second i32 representing the denominator. The output denominator will always be 1000.
+/// The output is a `Fraction` with the first i32 field representing the numerator
+/// and the second i32 field representing the denominator. The output denominator will always be 1000.
+/// A `Fraction` can also be used as an input and converts to and from an (i32 , i32) tuple.
 ///
 /// # Example
 ///
@@ -61,20 +64,90 @@ pub struct DTrig {
     arctangent_hundredths: [i16; 4001],
     arctangent_tenths: [i16; 2001],
     arctangent_ones: [i16; 2001],
+
+    // The rounding used to convert input fractions to thousandths.
+    rounding: RoundingMode,
+
+    // The version of the library whose results the functions give.
+    compatibility: Compatibility,
 }
 
+/// This module contains the fraction type and the trait for converting integer fractions into trig function inputs.
+pub mod fraction;
+
+/// This module contains the rounding modes for converting fractions.
+pub mod rounding;
+
+/// This module contains the setting for keeping the results of earlier versions of the library.
+pub mod compatibility;
+
 /// This module contains the code that sets the values for the arrays from the pre-baked tables.
 pub mod initialize;
 
 /// This module contains utility functions.
 pub (self) mod utility;
 
+/// This module contains the two dimensional integer vector type.
+pub mod vector;
+
+/// This module contains the 2D rotation matrix and affine transform types.
+pub mod transform;
+
+/// This module contains the angle type and helper functions for normalizing, turning and interpolating headings.
+pub mod angle;
+
+/// This module contains the conversions between polar and cartesian coordinates.
+pub mod polar;
+
+/// This module contains the seeded random number generator.
+pub mod random;
+
+/// This module contains the intersection tests between rays, segments and circles.
+pub mod geometry;
+
+/// This module contains the functions that evaluate whole slices of inputs at once.
+pub mod batch;
+
+/// This module contains the SSE2, AVX2 and NEON versions of the table lookup used by the slice functions.
+mod simd;
+
+/// This module contains the lookup of the arctangent in its tiers of tables.
+mod arctangent;
+
+/// This module contains the exact reduction of angles to one turn.
+mod reduction;
+
+/// This module contains the guaranteed error bounds of the trig functions.
+pub mod accuracy;
+
+/// This module contains the trait for code that is generic over the value type of the trig functions.
+pub mod generic;
+
+/// This module contains the support for the fixed point types of the fixed crate.
+#[cfg(feature = "fixed")]
+pub mod fixed_point;
+
+/// This module contains the num-traits implementations for the fraction and angle types.
+#[cfg(feature = "num-traits")]
+mod num;
+
+pub use angle::Angle;
+pub use compatibility::Compatibility;
+#[cfg(feature = "fixed")]
+pub use fixed_point::FixedPoint;
+pub use fraction::{ Fraction, IntoFraction, ParseFractionError };
+pub use generic::DeterministicTrig;
+pub use random::DRng;
+pub use rounding::RoundingMode;
+pub use transform::{ DAffine2, DMat2 };
+pub use vector::DVec2;
+
 // These functions pull the appropriate results out of the arrays.
 impl DTrig {
     /// Calculates the sine of an angle in radians.
     ///
-    /// - The input tuple represents the angle as a numerator and denominator.
-    /// - The output tuple represents the sine result as a numerator and denominator.
+    /// - The input represents the angle as a numerator and denominator.
+    /// - The output fraction represents the sine result as a numerator and denominator.
     /// - Most accurate between 0 and 2 PI with a factor of 1000 as denominator.
     /// - See README for limitations on accuracy.
     ///
@@ -98,13 +171,14 @@ impl DTrig {
     /// }
     /// ```
 
-    pub fn sine(&self, argument_fraction: (i32, i32)) -> (i32, i32) {
-        return (
+    pub fn sine<F: IntoFraction>(&self, argument_fraction: F) -> Fraction {
+        return Fraction(
             i32::from(
                 self.sine_array
                     [
-                        utility::normalize_angle(
-                            utility::denominator_to_1000(argument_fraction)
+                        reduction::normalize_angle(
+                            self.to_thousandths(argument_fraction),
+                            self.compatibility
                         ) as usize
                     ]
             ),
@@ -113,8 +187,8 @@ impl DTrig {
     }
     /// Calculates the cosine of an angle in radians.
     ///
-    /// - The input tuple represents the input angle as a numerator and denominator.
-    /// - The output tuple represents the cosine result as a numerator and denominator.
+    /// - The input represents the input angle as a numerator and denominator.
+    /// - The output fraction represents the cosine result as a numerator and denominator.
     /// - Most accurate between 0 and 2 PI with a factor of 1000 as denominator.
     /// - See README for limitations on accuracy.
     /// 
@@ -139,13 +213,14 @@ impl DTrig {
     ///
     /// ```
 
-    pub fn cosine(&self, argument_fraction: (i32, i32)) -> (i32, i32) {
-        return (
+    pub fn cosine<F: IntoFraction>(&self, argument_fraction: F) -> Fraction {
+        return Fraction(
             i32::from(
                 self.cosine_array
                     [
-                        utility::normalize_angle(
-                            utility::denominator_to_1000(argument_fraction)
+                        reduction::normalize_angle(
+                            self.to_thousandths(argument_fraction),
+                            self.compatibility
                         ) as usize
                     ]
             ),
@@ -155,8 +230,8 @@ impl DTrig {
 
     /// Calculates the tangent of an angle in radians.
     ///
-    /// - The input tuple represents the input angle as a numerator and denominator.
-    /// - The output tuple represents the tangent result as a numerator and denominator.
+    /// - The input represents the input angle as a numerator and denominator.
+    /// - The output fraction represents the tangent result as a numerator and denominator.
     /// - Most accurate between 0 and 2 PI with a factor of 1000 as denominator.
     /// - Can have large errors around asymptote lines for the tangent function.
     /// - See README for limitations on accuracy.
@@ -181,13 +256,14 @@ impl DTrig {
     /// }
     /// ```
 
-    pub fn tangent(&self, argument_fraction: (i32, i32)) -> (i32, i32) {
-        return (
+    pub fn tangent<F: IntoFraction>(&self, argument_fraction: F) -> Fraction {
+        return Fraction(
             self.tangent_array
                 [
-                    utility::normalize_angle(
-                        utility::denominator_to_1000(argument_fraction)
-                    ) as usize
+                    reduction::normalize_angle(
+                            self.to_thousandths(argument_fraction),
+                            self.compatibility
+                        ) as usize
                 ],
             1000,
         );
@@ -195,8 +271,8 @@ impl DTrig {
 
     /// Performs arcsine on a value to produce the measure of the corresponding angle in radians.
     ///
-    /// - The input tuple represents the input value as a numerator and denominator.
-    /// - The output tuple represents the angle result in radians as a numerator and denominator.
+    /// - The input represents the input value as a numerator and denominator.
+    /// - The output fraction represents the angle result in radians as a numerator and denominator.
     /// - Most accurate with a factor of 1000 as denominator.
     /// - See README for detailed limitations on accuracy.
     /// 
@@ -222,16 +298,19 @@ impl DTrig {
     /// }
     /// ```
 
-    pub fn arcsine(&self, argument_fraction: (i32, i32)) -> (i32, i32) {
-        if utility::denominator_to_1000(argument_fraction) < -1000 {
+    pub fn arcsine<F: IntoFraction>(&self, argument_fraction: F) -> Fraction {
+        // Converts the numerator to what it would be out of 1000.
+        let numerator_out_of_1000 = self.to_thousandths(argument_fraction);
+
+        if numerator_out_of_1000 < -1000 {
             panic!("Arcsine input less than 1.");
-        } else if utility::denominator_to_1000(argument_fraction) > 1000 {
+        } else if numerator_out_of_1000 > 1000 {
             panic!("Arcsine input greater than 1.");
         } else {
-            return (
+            return Fraction(
                 i32::from(
                     self.arcsine_array
-                        [(utility::denominator_to_1000(argument_fraction) + 1000) as usize]
+                        [(numerator_out_of_1000 + 1000) as usize]
                 ),
                 1000,
             );
@@ -240,8 +319,8 @@ impl DTrig {
 
     /// Performs arccosine on a value to produce the measure of the corresponding angle in radians
     ///
-    /// - The input tuple represents the input value as a numerator and denominator.
-    /// - The output tuple represents the angle result in radians as a numerator and denominator.
+    /// - The input represents the input value as a numerator and denominator.
+    /// - The output fraction represents the angle result in radians as a numerator and denominator.
     /// - Most accurate with a factor of 1000 as denominator.
     /// - See README for detailed limitations on accuracy.
     ///
@@ -267,16 +346,19 @@ impl DTrig {
     /// }
     /// ```
 
-    pub fn arccosine(&self, argument_fraction: (i32, i32)) -> (i32, i32) {
-        if utility::denominator_to_1000(argument_fraction) < -1000 {
+    pub fn arccosine<F: IntoFraction>(&self, argument_fraction: F) -> Fraction {
+        // Converts the numerator to what it would be out of 1000.
+        let numerator_out_of_1000 = self.to_thousandths(argument_fraction);
+
+        if numerator_out_of_1000 < -1000 {
             panic!("Arccosine input less than 1, which is undefined.");
-        } else if utility::denominator_to_1000(argument_fraction) > 1000 {
+        } else if numerator_out_of_1000 > 1000 {
             panic!("Arccosine input greater than 1, which is undefined.");
         } else {
-            return (
+            return Fraction(
                 i32::from(
                     self.arccosine_array
-                        [(utility::denominator_to_1000(argument_fraction) + 1000) as usize]
+                        [(numerator_out_of_1000 + 1000) as usize]
                 ),
                 1000,
             );
@@ -285,8 +367,8 @@ impl DTrig {
 
     /// Performs arctangent on a value to produce the measure of the corresponding angle in radians
     ///
-    /// - The input tuple represents the input value as a numerator and denominator.
-    /// - The output tuple represents the angle result in radians as a numerator and denominator.
+    /// - The input represents the input value as a numerator and denominator.
+    /// - The output fraction represents the angle result in radians as a numerator and denominator.
     /// - Most accurate with a factor of 1000 as denominator.
     /// - See README for detailed limitations on accuracy.
     ///
@@ -311,108 +393,78 @@ impl DTrig {
     ///
     /// ```
 
-    pub fn arctangent(&self, argument_fraction: (i32, i32)) -> (i32, i32) {
+    pub fn arctangent<F: IntoFraction>(&self, argument_fraction: F) -> Fraction {
         // Converts the numerator to what it would be out of 1000.
-        let numerator_out_of_1000 = utility::denominator_to_1000(argument_fraction);
+        let numerator_out_of_1000 = self.to_thousandths(argument_fraction);
 
-        if numerator_out_of_1000 >= -4000 && numerator_out_of_1000 <= 4000 {
-            // Handles from -4 to 4.
-            return (
-                i32::from(self.arctangent_thousandths[(numerator_out_of_1000 + 4000) as usize]),
-                1000,
-            );
-        } else if numerator_out_of_1000 >= -20000 && numerator_out_of_1000 <= 20000 {
-            // Handles from -20 to 20.
-            if (numerator_out_of_1000 % 10).abs() < 5 {
-                return (
-                    i32::from(
-                        self.arctangent_hundredths[(numerator_out_of_1000 / 10 + 2000) as usize]
-                    ),
-                    1000,
-                );
-            } else {
-                if numerator_out_of_1000 > 0 {
-                    return (
-                        i32::from(
-                            self.arctangent_hundredths
-                                [(numerator_out_of_1000 / 10 + 1 + 2000) as usize]
-                        ),
-                        1000,
-                    );
-                } else {
-                    return (
-                        i32::from(
-                            self.arctangent_hundredths
-                                [(numerator_out_of_1000 / 10 - 1 + 2000) as usize]
-                        ),
-                        1000,
-                    );
-                }
-            }
-        } else if numerator_out_of_1000 >= -100000 && numerator_out_of_1000 <= 100000 {
-            // Handles from -100 to 100
-            if (numerator_out_of_1000 % 100).abs() < 50 {
-                return (
-                    i32::from(
-                        self.arctangent_tenths[(numerator_out_of_1000 / 100 + 1000) as usize]
-                    ),
-                    1000,
-                );
-            } else {
-                if numerator_out_of_1000 > 0 {
-                    return (
-                        i32::from(
-                            self.arctangent_tenths
-                                [(numerator_out_of_1000 / 100 + 1 + 1000) as usize]
-                        ),
-                        1000,
-                    );
-                } else {
-                    return (
-                        i32::from(
-                            self.arctangent_tenths
-                                [(numerator_out_of_1000 / 100 - 1 + 1000) as usize]
-                        ),
-                        1000,
-                    );
-                }
-            }
-        } else if numerator_out_of_1000 >= -1000000 && numerator_out_of_1000 <= 1000000 {
-            // Handles from -1000 to 1000.
-            if numerator_out_of_1000 % 1000 < 500 {
-                return (
-                    i32::from(self.arctangent_ones[(numerator_out_of_1000 / 1000 + 1000) as usize]),
-                    1000,
-                );
-            } else {
-                if numerator_out_of_1000 > 0 {
-                    return (
-                        i32::from(
-                            self.arctangent_ones[(numerator_out_of_1000 / 1000 + 1 + 1000) as usize]
-                        ),
-                        1000,
-                    );
-                } else {
-                    return (
-                        i32::from(
-                            self.arctangent_ones[(numerator_out_of_1000 / 1000 - 1 + 1000) as usize]
-                        ),
-                        1000,
-                    );
-                }
-            }
-        } else {
-            // Handles lower than -1000 and higher than 1000.
-            if numerator_out_of_1000 < -1000000 && numerator_out_of_1000 > -3374653 {
-                return (-1570, 1000);
-            } else if numerator_out_of_1000 > 1000000 && numerator_out_of_1000 < 3374653 {
-                return (1570, 1000);
-            } else if numerator_out_of_1000 <= -3374653 {
-                return (-1571, 1000);
-            } else {
-                return (1571, 1000);
-            }
-        }
+        // Looks up the result in the tier of the arctangent tables that covers the input.
+        Fraction(self.arctangent_of_thousandths(numerator_out_of_1000), 1000)
+    }
+
+    /// Sets the rounding used to convert input fractions to thousandths.
+    ///
+    /// - The default is `RoundingMode::HalfAwayFromZero`.
+    /// - Applies to every function of this struct that takes a fraction as input.
+    /// - Inputs with a denominator of 1000 are not rounded, so the mode has no effect on them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::{ DTrig, RoundingMode };
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize().with_rounding(RoundingMode::Truncate);
+    ///
+    /// let arcsine = d_trig.arcsine((19999, 20000));
+    ///
+    /// println!("The arcsine of 19999/20000 with truncation is {}/{}.", arcsine.0, arcsine.1);
+    ///
+    /// }
+    /// ```
+    pub fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Outputs the rounding used to convert input fractions to thousandths.
+    pub fn rounding(&self) -> RoundingMode {
+        self.rounding
+    }
+
+    /// Sets the version of the library whose results the functions give.
+    ///
+    /// - The default is `Compatibility::Current`.
+    /// - `Compatibility::Version0_1` keeps the results of version 0.1 for replays and lockstep sessions recorded with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::{ Compatibility, DTrig };
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize().with_compatibility(Compatibility::Version0_1);
+    ///
+    /// let arctangent = d_trig.arctangent((-137999, 1000));
+    ///
+    /// println!("The arctangent of -137999/1000 in version 0.1 is {}/{}.", arctangent.0, arctangent.1);
+    ///
+    /// }
+    /// ```
+    pub fn with_compatibility(mut self, compatibility: Compatibility) -> Self {
+        self.compatibility = compatibility;
+        self
+    }
+
+    /// Outputs the version of the library whose results the functions give.
+    pub fn compatibility(&self) -> Compatibility {
+        self.compatibility
+    }
+
+    // Converts an input fraction to thousandths with the rounding mode of this struct.
+    pub (in crate) fn to_thousandths<F: IntoFraction>(&self, argument_fraction: F) -> i128 {
+        utility::wide_denominator_to_1000_with_rounding(argument_fraction.into_fraction(), self.rounding)
     }
 }
 
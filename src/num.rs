@@ -0,0 +1,271 @@
+/* This module contains the implementations of the num-traits traits for the fraction and angle types. Only the integer
+style traits are implemented. The float traits and the primitive conversions are left out on purpose so generic code
+bounded on these traits never reaches floating point arithmetic. The fixed point types get their implementations from
+the fixed crate, which this feature turns on when the fixed feature is also enabled. */
+
+use num_traits::{
+    Bounded,
+    CheckedAdd,
+    CheckedDiv,
+    CheckedMul,
+    CheckedNeg,
+    CheckedRem,
+    CheckedSub,
+    Inv,
+    Num,
+    One,
+    SaturatingAdd,
+    SaturatingMul,
+    SaturatingSub,
+    Signed,
+    Zero,
+};
+
+use crate::{ Angle, Fraction, ParseFractionError };
+
+impl Zero for Fraction {
+    fn zero() -> Self {
+        Fraction::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Fraction {
+    fn one() -> Self {
+        Fraction::ONE
+    }
+
+    fn is_one(&self) -> bool {
+        *self == Fraction::ONE
+    }
+}
+
+// Parses a fraction written as a numerator and denominator such as "-3/4", or as a whole number such as "5".
+// A zero denominator is an error rather than a panic.
+impl Num for Fraction {
+    type FromStrRadixErr = ParseFractionError;
+
+    fn from_str_radix(string: &str, radix: u32) -> Result<Self, ParseFractionError> {
+        match string.split_once('/') {
+            Some((numerator, denominator)) => {
+                let numerator = i32::from_str_radix(numerator, radix)?;
+                let denominator = i32::from_str_radix(denominator, radix)?;
+                if denominator == 0 {
+                    return Err(ParseFractionError::ZeroDenominator);
+                }
+                Ok(Fraction(numerator, denominator))
+            }
+            None => Ok(Fraction(i32::from_str_radix(string, radix)?, 1)),
+        }
+    }
+}
+
+impl Signed for Fraction {
+    fn abs(&self) -> Self {
+        if self.is_negative() { -*self } else { *self }
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other { Fraction::ZERO } else { *self - *other }
+    }
+
+    fn signum(&self) -> Self {
+        if self.is_positive() {
+            Fraction::ONE
+        } else if self.is_negative() {
+            Fraction(-1, 1)
+        } else {
+            Fraction::ZERO
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        self.0 != 0 && (self.0 < 0) == (self.1 < 0)
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0 != 0 && (self.0 < 0) != (self.1 < 0)
+    }
+}
+
+impl Bounded for Fraction {
+    fn min_value() -> Self {
+        Fraction(i32::MIN, 1)
+    }
+
+    fn max_value() -> Self {
+        Fraction(i32::MAX, 1)
+    }
+}
+
+impl CheckedAdd for Fraction {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Fraction::checked_add(*self, *other)
+    }
+}
+
+impl CheckedSub for Fraction {
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Fraction::checked_sub(*self, *other)
+    }
+}
+
+impl CheckedMul for Fraction {
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Fraction::checked_mul(*self, *other)
+    }
+}
+
+impl CheckedDiv for Fraction {
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        Fraction::checked_div(*self, *other)
+    }
+}
+
+impl CheckedRem for Fraction {
+    fn checked_rem(&self, other: &Self) -> Option<Self> {
+        Fraction::checked_rem(*self, *other)
+    }
+}
+
+impl SaturatingAdd for Fraction {
+    fn saturating_add(&self, other: &Self) -> Self {
+        Fraction::saturating_add(*self, *other)
+    }
+}
+
+impl SaturatingSub for Fraction {
+    fn saturating_sub(&self, other: &Self) -> Self {
+        Fraction::saturating_sub(*self, *other)
+    }
+}
+
+impl SaturatingMul for Fraction {
+    fn saturating_mul(&self, other: &Self) -> Self {
+        Fraction::saturating_mul(*self, *other)
+    }
+}
+
+impl Inv for Fraction {
+    type Output = Fraction;
+
+    fn inv(self) -> Fraction {
+        Fraction::ONE / self
+    }
+}
+
+impl Zero for Angle {
+    fn zero() -> Self {
+        Angle::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.thousandths() == 0
+    }
+}
+
+impl Bounded for Angle {
+    fn min_value() -> Self {
+        Angle::from_thousandths(i32::MIN)
+    }
+
+    fn max_value() -> Self {
+        Angle::from_thousandths(i32::MAX)
+    }
+}
+
+impl CheckedAdd for Angle {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        self.thousandths().checked_add(other.thousandths()).map(Angle::from_thousandths)
+    }
+}
+
+impl CheckedSub for Angle {
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.thousandths().checked_sub(other.thousandths()).map(Angle::from_thousandths)
+    }
+}
+
+impl CheckedNeg for Angle {
+    fn checked_neg(&self) -> Option<Self> {
+        self.thousandths().checked_neg().map(Angle::from_thousandths)
+    }
+}
+
+impl SaturatingAdd for Angle {
+    fn saturating_add(&self, other: &Self) -> Self {
+        Angle::from_thousandths(self.thousandths().saturating_add(other.thousandths()))
+    }
+}
+
+impl SaturatingSub for Angle {
+    fn saturating_sub(&self, other: &Self) -> Self {
+        Angle::from_thousandths(self.thousandths().saturating_sub(other.thousandths()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Sums values using only the num-traits bounds, the way generic code would.
+    fn generic_sum<T: Zero + CheckedAdd + Copy>(values: &[T]) -> Option<T> {
+        values.iter().try_fold(T::zero(), |sum, value| sum.checked_add(value))
+    }
+
+    #[test]
+    fn test_fraction_traits() {
+        assert_eq!(Fraction::from_str_radix("-3/4", 10), Ok(Fraction(-3, 4)));
+        assert_eq!(Fraction::from_str_radix("5", 10), Ok(Fraction(5, 1)));
+        assert_eq!(Fraction::from_str_radix("ff/10", 16), Ok(Fraction(255, 16)));
+        assert!(Fraction::from_str_radix("1/2/3", 10).is_err());
+        assert!(Fraction::from_str_radix("", 10).is_err());
+        assert_eq!(Fraction::from_str_radix("3/0", 10), Err(ParseFractionError::ZeroDenominator));
+        assert!(matches!(Fraction::from_str_radix("3/x", 10), Err(ParseFractionError::Int(_))));
+
+        assert!(Fraction(0, -5).is_zero());
+        assert!(Fraction(-7, -7).is_one());
+        assert_eq!(Fraction(3, -4).abs(), Fraction(3, 4));
+        assert_eq!(Fraction(-3, -4).signum(), Fraction::ONE);
+        assert_eq!(Fraction(3, -4).signum(), Fraction(-1, 1));
+        assert_eq!(Fraction(0, -4).signum(), Fraction::ZERO);
+        assert!(!Fraction(0, -4).is_negative() && !Fraction(0, -4).is_positive());
+        assert_eq!(Fraction(1, 2).abs_sub(&Fraction(1, 3)), Fraction(1, 6));
+        assert_eq!(Fraction(1, 3).abs_sub(&Fraction(1, 2)), Fraction::ZERO);
+        assert_eq!(Fraction(-2, 3).inv(), Fraction(-3, 2));
+        assert_eq!(<Fraction as Bounded>::max_value(), Fraction(i32::MAX, 1));
+
+        assert_eq!(generic_sum(&[Fraction(1, 2), Fraction(1, 3), Fraction(1, 6)]), Some(Fraction::ONE));
+        assert_eq!(generic_sum(&[Fraction(i32::MAX, 1), Fraction::ONE]), None);
+        assert_eq!(CheckedRem::checked_rem(&Fraction(7, 2), &Fraction::ZERO), None);
+        assert_eq!(SaturatingMul::saturating_mul(&Fraction(i32::MAX, 1), &Fraction(2, 1)), Fraction(i32::MAX, 1));
+    }
+
+    #[test]
+    fn test_angle_traits() {
+        let angles = [Angle::from_thousandths(700), Angle::from_thousandths(85)];
+        assert_eq!(generic_sum(&angles), Some(Angle::from_thousandths(785)));
+        assert_eq!(generic_sum(&[Angle::max_value(), Angle::from_thousandths(1)]), None);
+
+        assert!(Angle::zero().is_zero());
+        assert_eq!(Angle::min_value().checked_neg(), None);
+        assert_eq!(
+            SaturatingSub::saturating_sub(&Angle::min_value(), &Angle::from_thousandths(1)),
+            Angle::min_value()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fixed")]
+    fn test_fixed_point_traits() {
+        use fixed::types::I16F16;
+
+        // The fixed crate implements the traits itself when both features are enabled.
+        let values = [I16F16::from_bits(1 << 15), I16F16::from_bits(1 << 15)];
+        assert_eq!(generic_sum(&values), Some(I16F16::ONE));
+        assert_eq!(Signed::abs(&I16F16::from_bits(-5)), I16F16::from_bits(5));
+    }
+}
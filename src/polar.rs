@@ -0,0 +1,251 @@
+/* This module contains the conversions between polar and cartesian coordinates. Radii are handled as i64 values and
+every intermediate product is computed in i128 so that any radius that comes from i32 coordinates converts without
+overflow. */
+
+use crate::reduction;
+use crate::utility;
+use crate::{ Fraction, IntoFraction };
+
+// PI, PI / 2 and 2 PI in billionths of a radian.
+const PI_BILLIONTHS: i64 = 3141592654;
+const HALF_PI_BILLIONTHS: i64 = 1570796327;
+const TWO_PI_BILLIONTHS: i64 = 6283185307;
+
+impl crate::DTrig {
+    /// Converts a radius and an angle in radians into an integer point.
+    ///
+    /// - The input represents the angle as a numerator and denominator.
+    /// - The output tuple holds the x and y coordinates in the same units as the radius.
+    /// - Each coordinate is the radius times the table value rounded to an integer with the rounding mode of this DTrig.
+    /// - Negative radii point in the opposite direction of the angle.
+    /// - Coordinates that do not fit in an i32 are clamped to the nearest i32 value.
+    /// - The coordinates are accurate to within 3/2000 of the radius plus one unit with the default rounding. `Floor`,
+    ///   `Ceiling` and `Truncate` can be off by another 1/2000 of the radius from the input.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::DTrig;
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize();
+    ///
+    /// let point = d_trig.polar_to_cartesian(5000, (785, 1000));
+    ///
+    /// println!("A radius of 5000 at 785/1000 radians is the point ({}, {}).", point.0, point.1);
+    ///
+    /// }
+    /// ```
+    pub fn polar_to_cartesian<F: IntoFraction>(&self, radius: i64, angle_fraction: F) -> (i32, i32) {
+        let index = reduction::normalize_angle(
+            self.to_thousandths(angle_fraction),
+            self.compatibility
+        ) as usize;
+
+        (
+            clamp_to_i32(self.rounding.divide(i128::from(radius) * i128::from(self.cosine_array[index]), 1000)),
+            clamp_to_i32(self.rounding.divide(i128::from(radius) * i128::from(self.sine_array[index]), 1000)),
+        )
+    }
+
+    /// Converts an integer point into a radius and an angle in radians.
+    ///
+    /// - The output radius is the distance from the origin rounded to the nearest integer.
+    /// - The output tuple holds the radius and a fraction representing the angle as a numerator and denominator.
+    /// - The angle is normalized to between 0/1000 and 6282/1000.
+    /// - The angle is accurate to within 2/1000 of a radian.
+    /// - The origin has a radius of 0 and an angle of 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::DTrig;
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize();
+    ///
+    /// let (radius, angle) = d_trig.cartesian_to_polar(-3000, 4000);
+    ///
+    /// println!("The point (-3000, 4000) has a radius of {} at {}/{} radians.", radius, angle.0, angle.1);
+    ///
+    /// }
+    /// ```
+    pub fn cartesian_to_polar(&self, x: i32, y: i32) -> (i64, Fraction) {
+        let radius = utility::rounded_square_root(
+            (i128::from(x) * i128::from(x) + i128::from(y) * i128::from(y)) as u128
+        ) as i64;
+
+        let mut angle = self.arctangent2_thousandths(y, x, true);
+
+        // Handles the case in which the angle rounds up to 2 PI radians.
+        if angle == 6283 {
+            angle = 0;
+        }
+
+        (radius, Fraction(angle, 1000))
+    }
+
+    /// Calculates the angle in radians from the positive x axis to a point.
+    ///
+    /// - The inputs are the y and x coordinates of the point, in that order.
+    /// - The output fraction represents the angle as a numerator and denominator.
+    /// - The angle is between -3142/1000 and 3142/1000, with points on the negative x axis at 3142/1000.
+    /// - The angle is accurate to within 2/1000 of a radian.
+    /// - The origin has an angle of 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::DTrig;
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize();
+    ///
+    /// let angle = d_trig.arctangent2(-4000, -3000);
+    ///
+    /// println!("The point (-3000, -4000) is at an angle of {}/{} radians.", angle.0, angle.1);
+    ///
+    /// }
+    /// ```
+    pub fn arctangent2(&self, y: i32, x: i32) -> Fraction {
+        Fraction(self.arctangent2_thousandths(y, x, false), 1000)
+    }
+
+    // Outputs the angle of a point in thousandths of a radian, between -PI and PI or, if unsigned, between 0 and 2 PI.
+    fn arctangent2_thousandths(&self, y: i32, x: i32, is_unsigned: bool) -> i32 {
+        if x == 0 && y == 0 {
+            return 0;
+        }
+
+        // The arctangent only has thousandths precision. The quarter and half turns added to it are kept in
+        // billionths, so the sum is only rounded to thousandths once.
+        let mut angle_billionths = if i64::from(y).abs() <= i64::from(x).abs() {
+            // Keeps the ratio between -1 and 1, where the thousandths arctangent table is used.
+            let arctangent = i64::from(self.arctangent((y, x)).0) * 1000000;
+
+            if x > 0 {
+                arctangent
+            } else if y >= 0 {
+                arctangent + PI_BILLIONTHS
+            } else {
+                arctangent - PI_BILLIONTHS
+            }
+        } else {
+            let arctangent = i64::from(self.arctangent((x, y)).0) * 1000000;
+
+            if y > 0 {
+                HALF_PI_BILLIONTHS - arctangent
+            } else {
+                -HALF_PI_BILLIONTHS - arctangent
+            }
+        };
+
+        // Converts from between -PI and PI to between 0 and 2 PI.
+        if is_unsigned && angle_billionths < 0 {
+            angle_billionths += TWO_PI_BILLIONTHS;
+        }
+
+        utility::divide_and_round(i128::from(angle_billionths), 1000000) as i32
+    }
+}
+
+// Converts an intermediate result to an i32 by clamping it to the i32 range.
+fn clamp_to_i32(value: i128) -> i32 {
+    value.clamp(i128::from(i32::MIN), i128::from(i32::MAX)) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ DTrig, Fraction, RoundingMode };
+
+    #[test]
+    fn test_polar_to_cartesian() {
+        let d_trig = DTrig::initialize();
+
+        assert_eq!(d_trig.polar_to_cartesian(1000, (0, 1000)), (1000, 0));
+        assert_eq!(d_trig.polar_to_cartesian(-1000, (0, 1000)), (-1000, 0));
+        assert_eq!(d_trig.polar_to_cartesian(1000, (1571, 1000)), (0, 1000));
+        assert_eq!(d_trig.polar_to_cartesian(5000, (785, 1000)), (3535, 3535));
+
+        // Radii past the i32 range are clamped instead of overflowing.
+        assert_eq!(d_trig.polar_to_cartesian(i64::MAX, (0, 1000)), (i32::MAX, 0));
+        assert_eq!(d_trig.polar_to_cartesian(i64::MIN, (0, 1000)), (i32::MIN, 0));
+
+        // The coordinates are rounded with the mode of the DTrig. The cosine and sine of 1047/1000 are 500/1000 and
+        // 866/1000.
+        for (mode, point, opposite) in [
+            (RoundingMode::HalfAwayFromZero, (1, 1), (-1, -1)),
+            (RoundingMode::HalfEven, (0, 1), (0, -1)),
+            (RoundingMode::Floor, (0, 0), (-1, -1)),
+            (RoundingMode::Ceiling, (1, 1), (0, 0)),
+            (RoundingMode::Truncate, (0, 0), (0, 0)),
+        ] {
+            let rounded = DTrig::initialize().with_rounding(mode);
+            assert_eq!(rounded.polar_to_cartesian(1, (1047, 1000)), point, "{:?}", mode);
+            assert_eq!(rounded.polar_to_cartesian(-1, (1047, 1000)), opposite, "{:?}", mode);
+        }
+    }
+
+    #[test]
+    fn test_cartesian_to_polar() {
+        let d_trig = DTrig::initialize();
+
+        assert_eq!(d_trig.cartesian_to_polar(0, 0), (0, Fraction(0, 1000)));
+        assert_eq!(d_trig.cartesian_to_polar(3000, 4000), (5000, Fraction(927, 1000)));
+        assert_eq!(d_trig.cartesian_to_polar(-5, 0), (5, Fraction(3142, 1000)));
+        assert_eq!(d_trig.cartesian_to_polar(0, -5), (5, Fraction(4712, 1000)));
+        assert_eq!(d_trig.cartesian_to_polar(1000000, -1), (1000000, Fraction(0, 1000)));
+        assert_eq!(d_trig.cartesian_to_polar(i32::MIN, i32::MIN).0, 3037000500);
+        assert_eq!(d_trig.cartesian_to_polar(i32::MAX, i32::MAX).0, 3037000499);
+
+        // Compares the angle against floating point on a grid around the origin.
+        for x in (-3000..3001).step_by(7) {
+            for y in (-3000..3001).step_by(11) {
+                let angle = d_trig.cartesian_to_polar(x, y).1.0;
+                let mut expected = (y as f64).atan2(x as f64) * 1000.0;
+                if expected < 0.0 {
+                    expected += 2000.0 * std::f64::consts::PI;
+                }
+
+                let error = (expected - (angle as f64)).abs();
+                assert!(error <= 2.0 || (6283.185 - error).abs() <= 2.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let d_trig = DTrig::initialize();
+
+        let mut points = Vec::new();
+        for x in (-2000..2001).step_by(13) {
+            for y in (-2000..2001).step_by(17) {
+                points.push((x, y));
+            }
+        }
+        for scale in [1000, 1000000, 2147483] {
+            points.push((scale * 1000, -scale * 7));
+            points.push((-scale * 999, scale * 123));
+        }
+        points.push((i32::MAX, i32::MIN));
+        points.push((i32::MIN, i32::MIN));
+
+        for (x, y) in points {
+            let (radius, angle) = d_trig.cartesian_to_polar(x, y);
+            let (round_x, round_y) = d_trig.polar_to_cartesian(radius, angle);
+
+            // Both the angle and the table values are rounded, so the error grows with the radius.
+            let bound = (radius * 3) / 2000 + 1;
+
+            assert!((i64::from(round_x) - i64::from(x)).abs() <= bound);
+            assert!((i64::from(round_y) - i64::from(y)).abs() <= bound);
+        }
+    }
+}
@@ -0,0 +1,204 @@
+/* This module contains a small seeded random number generator. It uses the SplitMix64 algorithm, which only needs
+wrapping 64 bit integer arithmetic, so the same seed produces the same sequence on every compiler and architecture. */
+
+use crate::{ DTrig, Fraction };
+use crate::DVec2;
+
+/// A seeded pseudo random number generator that produces the same sequence on every platform.
+///
+/// - Uses the SplitMix64 algorithm with wrapping integer arithmetic only.
+/// - Two generators created with the same seed always produce the same values in the same order.
+/// - Not suitable for cryptography.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::DRng;
+///
+/// fn main (){
+///
+/// let mut rng = DRng::new(12345);
+///
+/// let roll = rng.next_in_range(1, 6);
+///
+/// println!("The die rolled a {}.", roll);
+///
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DRng {
+    state: u64,
+}
+
+impl DRng {
+    /// Creates a generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        DRng { state: seed }
+    }
+
+    /// Generates a random u64 with every value equally likely.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut mixed = self.state;
+        mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94d049bb133111eb);
+        mixed ^ (mixed >> 31)
+    }
+
+    /// Generates a random u32 with every value equally likely.
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Generates a random integer between a low and high value including both ends.
+    ///
+    /// - Every value in the range is equally likely.
+    ///
+    /// # Panics
+    ///
+    /// - A low value greater than the high value.
+    pub fn next_in_range(&mut self, low: i32, high: i32) -> i32 {
+        if low > high {
+            panic!("Random range with a low value greater than the high value.");
+        }
+
+        let span = (i64::from(high) - i64::from(low) + 1) as u64;
+
+        // Multiplies into the span and rejects the few values that would make some results more likely than others.
+        let threshold = span.wrapping_neg() % span;
+        loop {
+            let product = u128::from(self.next_u64()) * u128::from(span);
+            if (product as u64) >= threshold {
+                return (i64::from(low) + ((product >> 64) as i64)) as i32;
+            }
+        }
+    }
+
+    /// Generates a random angle in radians.
+    ///
+    /// - The output fraction represents the angle as a numerator and denominator.
+    /// - Every thousandth from 0/1000 to 6282/1000 is equally likely, which matches the steps of the trig tables.
+    pub fn next_angle(&mut self) -> Fraction {
+        Fraction(self.next_in_range(0, 6282), 1000)
+    }
+
+    /// Generates a random direction as a vector with a length of about 1000.
+    ///
+    /// - The components are the cosine and sine of a random angle from `next_angle`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::{ DRng, DTrig };
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize();
+    ///
+    /// let mut rng = DRng::new(2024);
+    ///
+    /// let direction = rng.next_unit_vector(&d_trig);
+    ///
+    /// println!("The random direction is ({}/1000, {}/1000).", direction.x, direction.y);
+    ///
+    /// }
+    /// ```
+    pub fn next_unit_vector(&mut self, d_trig: &DTrig) -> DVec2 {
+        let angle = self.next_angle();
+
+        DVec2::new(d_trig.cosine(angle).0, d_trig.sine(angle).0)
+    }
+
+    /// Generates a random integer point inside or on the edge of a disk centered on the origin.
+    ///
+    /// - Every integer point in the disk is equally likely.
+    ///
+    /// # Panics
+    ///
+    /// - A negative radius.
+    pub fn next_point_in_disk(&mut self, radius: i32) -> DVec2 {
+        if radius < 0 {
+            panic!("Disk with a negative radius.");
+        }
+
+        // Draws points from the enclosing square until one lands in the disk.
+        loop {
+            let point = DVec2::new(
+                self.next_in_range(-radius, radius),
+                self.next_in_range(-radius, radius)
+            );
+
+            if point.length_squared() <= i128::from(radius) * i128::from(radius) {
+                return point;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ DRng, DTrig };
+
+    // These values must never change, since saved games and replays depend on them.
+    #[test]
+    fn test_sequence_is_stable() {
+        let mut rng = DRng::new(0);
+        assert_eq!(rng.next_u64(), 0xe220a8397b1dcdaf);
+        assert_eq!(rng.next_u64(), 0x6e789e6aa1b965f4);
+        assert_eq!(rng.next_u64(), 0x06c45d188009454f);
+
+        let mut rng = DRng::new(20240722);
+        let values: Vec<i32> = (0..8).map(|_| rng.next_in_range(-100000, 100000)).collect();
+        assert_eq!(values, [38599, -19972, 99977, -74455, -23768, 1843, -8625, -69315]);
+    }
+
+    #[test]
+    fn test_next_in_range() {
+        let mut rng = DRng::new(7);
+        let mut counts = [0; 6];
+
+        for _ in 0..60000 {
+            let roll = rng.next_in_range(1, 6);
+            assert!((1..=6).contains(&roll));
+            counts[(roll - 1) as usize] += 1;
+        }
+
+        // Each face should come up close to 10,000 times.
+        for count in counts {
+            assert!((9500..10500).contains(&count));
+        }
+
+        assert_eq!(rng.next_in_range(5, 5), 5);
+
+        for _ in 0..1000 {
+            rng.next_in_range(i32::MIN, i32::MAX);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_next_in_range_panics_when_empty() {
+        DRng::new(7).next_in_range(1, 0);
+    }
+
+    #[test]
+    fn test_angles_and_vectors() {
+        let d_trig = DTrig::initialize();
+        let mut rng = DRng::new(99);
+
+        for _ in 0..10000 {
+            let angle = rng.next_angle();
+            assert!(angle.0 >= 0 && angle.0 <= 6282 && angle.1 == 1000);
+
+            let direction = rng.next_unit_vector(&d_trig);
+            assert!((direction.length() - 1000).abs() <= 1);
+
+            let point = rng.next_point_in_disk(250);
+            assert!(point.length_squared() <= 250 * 250);
+        }
+
+        assert_eq!(rng.next_point_in_disk(0), crate::DVec2::ZERO);
+    }
+}
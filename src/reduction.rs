@@ -0,0 +1,130 @@
+/* This module contains the exact reduction of angles to one turn. Rather than taking the remainder of the angle by a
+rounded value of 2 PI, whose error grows with every turn, the angle is multiplied by a long fixed point value of
+1/(2000 PI), the same way as the Payne-Hanek reduction does. The whole turns of the product are dropped, and the
+fraction of a turn that is left is multiplied back by 2000 PI to get the reduced angle in thousandths.
+
+The value of 1/(2000 PI) has 384 bits after the point, so for any angle that fits in an i128 the fraction of a turn is
+off by less than 2^-256. Keeping the top 128 bits of the fraction and multiplying by 2000 PI with 128 bits after the
+point leaves the reduced angle off by less than 10^-34 thousandths. The continued fraction of 4000 PI shows that no
+input made of i64 values reduces to within 5 * 10^-20 thousandths of halfway between two thousandths, so rounding the
+reduced angle gives the same thousandth as rounding the exact reduced angle for every one of them. */
+
+use crate::utility;
+use crate::Compatibility;
+
+// 2^384 / (2000 PI) rounded down, in 64 bit limbs from the lowest to the highest.
+const TURNS_PER_THOUSANDTH: [u64; 6] = [
+    0xef9e1929ea1d4ce6,
+    0xdb3eebcdb5e7924a,
+    0xc8d4e264964380c0,
+    0xf7dce37e4f7066a0,
+    0x39fba867ecab5759,
+    0x000a6e2d468c2d51,
+];
+
+// 2000 PI times 2^128 rounded down, in 64 bit limbs from the lowest to the highest.
+const THOUSANDTHS_PER_TURN: [u64; 3] = [0x3787877ade848841, 0x2f704a9409b4f105, 0x000000000000188b];
+
+// Normalizes an angle in thousandths to 0 to 6282 thousandths the way the compatibility setting asks for.
+pub (in crate) fn normalize_angle(thousandths: i128, compatibility: Compatibility) -> i64 {
+    match compatibility {
+        Compatibility::Current => reduce_angle(thousandths),
+        Compatibility::Version0_1 => utility::normalize_wide_angle(thousandths),
+    }
+}
+
+// Reduces an angle in thousandths to the nearest thousandth from 0 to 6282 of the exact angle minus whole turns.
+pub (in crate) fn reduce_angle(thousandths: i128) -> i64 {
+    if (0..6283).contains(&thousandths) {
+        return thousandths as i64;
+    }
+
+    let size = thousandths.unsigned_abs();
+
+    // The turns in the size of the angle with 384 bits after the point.
+    let turns: [u64; 8] = multiply(&[size as u64, (size >> 64) as u64], &TURNS_PER_THOUSANDTH);
+
+    // Drops the whole turns and keeps the top 128 bits of the fraction of a turn. A negative angle is the same as one
+    // turn minus the fraction of its size.
+    let fraction = (u128::from(turns[5]) << 64) | u128::from(turns[4]);
+    let fraction = if thousandths < 0 { fraction.wrapping_neg() } else { fraction };
+
+    // The reduced angle in thousandths with 256 bits after the point.
+    let angle: [u64; 5] = multiply(&[fraction as u64, (fraction >> 64) as u64], &THOUSANDTHS_PER_TURN);
+
+    // Rounds to the nearest thousandth using the highest bit after the point. An angle that rounds up to a full turn is
+    // the same as 0.
+    let rounded = angle[4] + (angle[3] >> 63);
+    if rounded == 6283 { 0 } else { rounded as i64 }
+}
+
+// Multiplies two numbers held in 64 bit limbs from the lowest to the highest. The output must have room for as many
+// limbs as both inputs together.
+fn multiply<const LIMBS: usize>(first: &[u64], second: &[u64]) -> [u64; LIMBS] {
+    let mut product = [0u64; LIMBS];
+
+    for (first_index, first_limb) in first.iter().enumerate() {
+        let mut carry = 0u128;
+
+        for (second_index, second_limb) in second.iter().enumerate() {
+            // This can not overflow, as (2^64 - 1)^2 + 2 * (2^64 - 1) is 2^128 - 1.
+            let sum =
+                u128::from(*first_limb) * u128::from(*second_limb) +
+                u128::from(product[first_index + second_index]) +
+                carry;
+            product[first_index + second_index] = sum as u64;
+            carry = sum >> 64;
+        }
+
+        product[first_index + second.len()] = carry as u64;
+    }
+
+    product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_old_normalization_for_small_angles() {
+        // The 12 digit value of 2 PI is off by less than a ten millionth of a thousandth over these few turns, so the
+        // old normalization rounds the same way.
+        for thousandths in -1000000..=1000000 {
+            assert_eq!(reduce_angle(thousandths), utility::normalize_wide_angle(thousandths), "{}", thousandths);
+        }
+    }
+
+    #[test]
+    fn test_turns() {
+        // 2 PI is 6283.185 thousandths, so every whole number of thousandths is at least 0.185 from a turn.
+        assert_eq!(reduce_angle(6283), 0);
+        assert_eq!(reduce_angle(-1), 6282);
+        assert_eq!(reduce_angle(12566), 0);
+        assert_eq!(reduce_angle(12567), 1);
+
+        // 1000000 turns is 6283185307.18 thousandths.
+        assert_eq!(reduce_angle(6283185307), 0);
+        assert_eq!(reduce_angle(6283185308), 1);
+        assert_eq!(reduce_angle(-6283185307), 0);
+    }
+
+    #[test]
+    fn test_extremes() {
+        // These were reduced with 1500 bit arithmetic.
+        assert_eq!(reduce_angle(i128::MIN), 100);
+        assert_eq!(reduce_angle(i128::MIN + 1), 101);
+        assert_eq!(reduce_angle(i128::MAX), 6182);
+        assert_eq!(reduce_angle(i128::from(i64::MIN) * 1000), 4724);
+        assert_eq!(reduce_angle(i128::from(i64::MAX) * 1000), 559);
+        assert_eq!(reduce_angle(i128::from(i32::MAX) * 1000), 3953);
+        assert_eq!(reduce_angle(i128::from(i32::MIN) * 1000), 1331);
+        assert_eq!(reduce_angle(i128::from(i32::MAX)), 4006);
+    }
+
+    #[test]
+    fn test_multiply() {
+        let product: [u64; 4] = multiply(&[u64::MAX, u64::MAX], &[u64::MAX, u64::MAX]);
+        assert_eq!(product, [1, 0, u64::MAX - 1, u64::MAX]);
+    }
+}
@@ -0,0 +1,186 @@
+/* This module contains the rounding modes used when an input fraction is converted to thousandths and when a fraction
+is rescaled. Every mode is computed exactly from the integer quotient and remainder, so the result only depends on the
+value of the fraction and never on the signs chosen for the numerator and denominator. */
+
+/// The ways a fraction can be rounded to a whole number of thousandths or to a new denominator.
+///
+/// - The default is `HalfAwayFromZero`, which is how the library has always rounded.
+/// - The mode for the trig functions is set with `DTrig::with_rounding`.
+/// - The mode for a single rescale is set with `Fraction::rescale_with_rounding`.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::{ DTrig, RoundingMode };
+///
+/// fn main (){
+///
+/// let d_trig = DTrig::initialize().with_rounding(RoundingMode::Floor);
+///
+/// // 3/2000 is 1.5 thousandths, which is rounded down to 1/1000.
+/// let sine = d_trig.sine((3, 2000));
+///
+/// println!("The sine of 3/2000 radians with floor rounding is {}/{}.", sine.0, sine.1);
+///
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoundingMode {
+    /// Rounds to the nearest value with halves rounded away from zero.
+    #[default]
+    HalfAwayFromZero,
+    /// Rounds to the nearest value with halves rounded to the even neighbor, also known as banker's rounding.
+    HalfEven,
+    /// Rounds toward negative infinity.
+    Floor,
+    /// Rounds toward positive infinity.
+    Ceiling,
+    /// Rounds toward zero.
+    Truncate,
+}
+
+impl RoundingMode {
+    // Divides two integers and rounds the result with this mode.
+    pub (in crate) fn divide(self, numerator: i128, denominator: i128) -> i128 {
+        if denominator == 0 {
+            panic!("Division by zero, which is undefined.");
+        }
+
+        // Truncated quotient and remainder, with the remainder having the sign of the numerator.
+        let quotient = numerator / denominator;
+        let remainder = numerator % denominator;
+
+        if remainder == 0 {
+            return quotient;
+        }
+
+        // The exact result lies between the truncated quotient and the next integer away from zero.
+        let away_from_zero = if (numerator < 0) == (denominator < 0) { quotient + 1 } else { quotient - 1 };
+        let is_negative = (numerator < 0) != (denominator < 0);
+        let twice_remainder = remainder.abs() * 2;
+
+        match self {
+            RoundingMode::HalfAwayFromZero => {
+                if twice_remainder >= denominator.abs() { away_from_zero } else { quotient }
+            }
+            RoundingMode::HalfEven => {
+                // Exact halves go to whichever neighbor is even.
+                let is_half = twice_remainder == denominator.abs();
+                if twice_remainder > denominator.abs() || (is_half && quotient % 2 != 0) {
+                    away_from_zero
+                } else {
+                    quotient
+                }
+            }
+            RoundingMode::Floor => {
+                if is_negative { away_from_zero } else { quotient }
+            }
+            RoundingMode::Ceiling => {
+                if is_negative { quotient } else { away_from_zero }
+            }
+            RoundingMode::Truncate => quotient,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoundingMode;
+    use crate::utility;
+
+    const MODES: [RoundingMode; 5] = [
+        RoundingMode::HalfAwayFromZero,
+        RoundingMode::HalfEven,
+        RoundingMode::Floor,
+        RoundingMode::Ceiling,
+        RoundingMode::Truncate,
+    ];
+
+    // Rounds the way each mode is defined using floating point, which is exact for these small values.
+    fn float_round(value: f64, mode: RoundingMode) -> i128 {
+        (match mode {
+            RoundingMode::HalfAwayFromZero => value.round(),
+            RoundingMode::HalfEven => value.round_ties_even(),
+            RoundingMode::Floor => value.floor(),
+            RoundingMode::Ceiling => value.ceil(),
+            RoundingMode::Truncate => value.trunc(),
+        }) as i128
+    }
+
+    #[test]
+    fn test_halves_and_signs() {
+        // Each row is the value in halves followed by the results for the modes in order.
+        let expected = [
+            (5, [3, 2, 2, 3, 2]),
+            (3, [2, 2, 1, 2, 1]),
+            (1, [1, 0, 0, 1, 0]),
+            (-1, [-1, 0, -1, 0, 0]),
+            (-3, [-2, -2, -2, -1, -1]),
+            (-5, [-3, -2, -3, -2, -2]),
+        ];
+
+        for (halves, results) in expected {
+            for (mode, result) in MODES.iter().zip(results) {
+                // The same value with the sign on the numerator, the denominator or both.
+                assert_eq!(mode.divide(halves, 2), result);
+                assert_eq!(mode.divide(-halves, -2), result);
+                assert_eq!(mode.divide(halves * 7, 14), result);
+                assert_eq!(mode.divide(-halves * 7, -14), result);
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_mode_matches_its_definition() {
+        for mode in MODES {
+            for numerator in -3000..3001i128 {
+                for denominator in (-40..41i128).filter(|denominator| *denominator != 0) {
+                    assert_eq!(
+                        mode.divide(numerator, denominator),
+                        float_round((numerator as f64) / (denominator as f64), mode),
+                        "{:?} {}/{}",
+                        mode,
+                        numerator,
+                        denominator
+                    );
+                }
+            }
+
+            // Large values do not overflow.
+            assert_eq!(mode.divide(i128::from(i64::MAX) * 1000, 1000), i128::from(i64::MAX));
+            assert_eq!(mode.divide(i128::from(i64::MIN) * 1000, -1), i128::from(i64::MIN) * -1000);
+        }
+    }
+
+    #[test]
+    fn test_default_matches_the_original_conversion() {
+        assert_eq!(RoundingMode::default(), RoundingMode::HalfAwayFromZero);
+
+        for numerator in -5000..5001i64 {
+            for denominator in [1i64, -1, 2, -2, 3, -3, 7, -8, 999, 1001, -2000, 4096] {
+                assert_eq!(
+                    utility::wide_denominator_to_1000_with_rounding(
+                        (numerator, denominator),
+                        RoundingMode::HalfAwayFromZero
+                    ),
+                    utility::wide_denominator_to_1000((numerator, denominator))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_conversion_to_thousandths() {
+        for mode in MODES {
+            for numerator in -2000..2001i64 {
+                for denominator in [-2000i64, -1000, -7, -2, 2, 3, 1000, 2000, 4000] {
+                    assert_eq!(
+                        utility::wide_denominator_to_1000_with_rounding((numerator, denominator), mode),
+                        float_round(((numerator * 1000) as f64) / (denominator as f64), mode)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,338 @@
+/* This module contains the vector versions of the table lookup used by the slice functions. Angles from -6283 to 12565
+thousandths only need 6283 added or subtracted to become a table index, which the vector instructions do for several
+angles at once. A group of angles with any angle outside of that range is looked up one at a time with the same
+normalization as the single value functions, so every path gives exactly the same results.
+
+On x86_64 the AVX2 path is chosen at runtime when the processor supports it and also gathers the table values with
+vector instructions. Otherwise the SSE2 path, which every x86_64 processor supports, computes the indices. On aarch64
+the NEON path computes the indices. Every other target uses the scalar path. */
+
+use crate::batch::table_index;
+use crate::{ Angle, Compatibility };
+
+// The number of entries in the sine and cosine tables.
+const TABLE_LENGTH: i32 = 6283;
+
+// Angles at or above this number of thousandths need the full normalization.
+const FAST_PATH_END: i32 = 12566;
+
+// Fills the output with the table values for every angle using the fastest path the processor supports.
+pub (in crate) fn lookup(
+    table: &[i16; 6283],
+    angles: &[Angle],
+    output: &mut [i32],
+    compatibility: Compatibility
+) {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("avx2") {
+            // Safety: the processor supports AVX2, which was just checked.
+            unsafe { x86::lookup_avx2(table, angles, output, compatibility) };
+        } else {
+            // Safety: every x86_64 processor supports SSE2.
+            unsafe { x86::lookup_sse2(table, angles, output, compatibility) };
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        // Safety: every aarch64 processor supported by Rust has NEON.
+        unsafe { arm::lookup_neon(table, angles, output, compatibility) };
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    {
+        lookup_scalar(table, angles, output, compatibility);
+    }
+}
+
+// Fills the output with the table values for every angle one at a time.
+pub (in crate) fn lookup_scalar(
+    table: &[i16; 6283],
+    angles: &[Angle],
+    output: &mut [i32],
+    compatibility: Compatibility
+) {
+    for (angle, value) in angles.iter().zip(output.iter_mut()) {
+        *value = i32::from(table[table_index(angle.thousandths(), compatibility)]);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+pub (in crate) mod x86 {
+    use super::{ lookup_scalar, FAST_PATH_END, TABLE_LENGTH };
+    use crate::{ Angle, Compatibility };
+    use std::arch::x86_64::*;
+
+    // Looks up eight angles at a time, gathering the table values with AVX2.
+    //
+    // Safety: the processor must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub (in crate) unsafe fn lookup_avx2(
+        table: &[i16; 6283],
+        angles: &[Angle],
+        output: &mut [i32],
+        compatibility: Compatibility
+    ) {
+        let length = angles.len().min(output.len());
+        let chunks = length / 8;
+
+        let below_start = _mm256_set1_epi32(-TABLE_LENGTH - 1);
+        let end = _mm256_set1_epi32(FAST_PATH_END);
+        let last_index = _mm256_set1_epi32(TABLE_LENGTH - 1);
+        let turn = _mm256_set1_epi32(TABLE_LENGTH);
+        let zero = _mm256_setzero_si256();
+        let all_ones = _mm256_set1_epi32(-1);
+
+        // The last entry is read separately because a 32 bit gather at its address would read past the table.
+        let last_value = _mm256_set1_epi32(i32::from(table[6282]));
+
+        for chunk in 0..chunks {
+            let start = chunk * 8;
+
+            // Angle is a transparent wrapper around an i32, so eight angles can be loaded as eight i32 values.
+            let angle = _mm256_loadu_si256(angles.as_ptr().add(start) as *const __m256i);
+
+            let in_range = _mm256_and_si256(
+                _mm256_cmpgt_epi32(angle, below_start),
+                _mm256_cmpgt_epi32(end, angle)
+            );
+            if _mm256_movemask_epi8(in_range) != -1 {
+                lookup_scalar(table, &angles[start..start + 8], &mut output[start..start + 8], compatibility);
+                continue;
+            }
+
+            // Adds a turn to negative angles and subtracts a turn from angles past the end of the table.
+            let negative = _mm256_cmpgt_epi32(zero, angle);
+            let past_end = _mm256_cmpgt_epi32(angle, last_index);
+            let index = _mm256_sub_epi32(
+                _mm256_add_epi32(angle, _mm256_and_si256(negative, turn)),
+                _mm256_and_si256(past_end, turn)
+            );
+
+            // Gathers 32 bits starting at each i16 entry and keeps the low 16 bits with their sign.
+            let not_last = _mm256_xor_si256(_mm256_cmpeq_epi32(index, last_index), all_ones);
+            let gathered = _mm256_mask_i32gather_epi32::<2>(
+                last_value,
+                table.as_ptr() as *const i32,
+                index,
+                not_last
+            );
+            let values = _mm256_srai_epi32::<16>(_mm256_slli_epi32::<16>(gathered));
+
+            _mm256_storeu_si256(output.as_mut_ptr().add(start) as *mut __m256i, values);
+        }
+
+        lookup_scalar(table, &angles[chunks * 8..length], &mut output[chunks * 8..length], compatibility);
+    }
+
+    // Looks up four angles at a time, computing the indices with SSE2 and reading the table one entry at a time.
+    //
+    // Safety: the processor must support SSE2, which every x86_64 processor does.
+    pub (in crate) unsafe fn lookup_sse2(
+        table: &[i16; 6283],
+        angles: &[Angle],
+        output: &mut [i32],
+        compatibility: Compatibility
+    ) {
+        let length = angles.len().min(output.len());
+        let chunks = length / 4;
+
+        let below_start = _mm_set1_epi32(-TABLE_LENGTH - 1);
+        let end = _mm_set1_epi32(FAST_PATH_END);
+        let last_index = _mm_set1_epi32(TABLE_LENGTH - 1);
+        let turn = _mm_set1_epi32(TABLE_LENGTH);
+        let zero = _mm_setzero_si128();
+        let mut indices = [0i32; 4];
+
+        for chunk in 0..chunks {
+            let start = chunk * 4;
+
+            // Angle is a transparent wrapper around an i32, so four angles can be loaded as four i32 values.
+            let angle = _mm_loadu_si128(angles.as_ptr().add(start) as *const __m128i);
+
+            let in_range = _mm_and_si128(_mm_cmpgt_epi32(angle, below_start), _mm_cmpgt_epi32(end, angle));
+            if _mm_movemask_epi8(in_range) != 0xffff {
+                lookup_scalar(table, &angles[start..start + 4], &mut output[start..start + 4], compatibility);
+                continue;
+            }
+
+            let negative = _mm_cmpgt_epi32(zero, angle);
+            let past_end = _mm_cmpgt_epi32(angle, last_index);
+            let index = _mm_sub_epi32(
+                _mm_add_epi32(angle, _mm_and_si128(negative, turn)),
+                _mm_and_si128(past_end, turn)
+            );
+
+            _mm_storeu_si128(indices.as_mut_ptr() as *mut __m128i, index);
+            for lane in 0..4 {
+                output[start + lane] = i32::from(table[indices[lane] as usize]);
+            }
+        }
+
+        lookup_scalar(table, &angles[chunks * 4..length], &mut output[chunks * 4..length], compatibility);
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+pub (in crate) mod arm {
+    use super::{ lookup_scalar, FAST_PATH_END, TABLE_LENGTH };
+    use crate::{ Angle, Compatibility };
+    use std::arch::aarch64::*;
+
+    // Looks up four angles at a time, computing the indices with NEON and reading the table one entry at a time.
+    //
+    // Safety: the processor must support NEON, which every aarch64 processor supported by Rust does.
+    pub (in crate) unsafe fn lookup_neon(
+        table: &[i16; 6283],
+        angles: &[Angle],
+        output: &mut [i32],
+        compatibility: Compatibility
+    ) {
+        let length = angles.len().min(output.len());
+        let chunks = length / 4;
+
+        let start_of_range = vdupq_n_s32(-TABLE_LENGTH);
+        let end = vdupq_n_s32(FAST_PATH_END);
+        let table_length = vdupq_n_s32(TABLE_LENGTH);
+        let zero = vdupq_n_s32(0);
+        let mut indices = [0i32; 4];
+
+        for chunk in 0..chunks {
+            let start = chunk * 4;
+
+            // Angle is a transparent wrapper around an i32, so four angles can be loaded as four i32 values.
+            let angle = vld1q_s32(angles.as_ptr().add(start) as *const i32);
+
+            let in_range = vandq_u32(vcgeq_s32(angle, start_of_range), vcltq_s32(angle, end));
+            if vminvq_u32(in_range) != u32::MAX {
+                lookup_scalar(table, &angles[start..start + 4], &mut output[start..start + 4], compatibility);
+                continue;
+            }
+
+            let negative = vreinterpretq_s32_u32(vcltq_s32(angle, zero));
+            let past_end = vreinterpretq_s32_u32(vcgeq_s32(angle, table_length));
+            let index = vsubq_s32(
+                vaddq_s32(angle, vandq_s32(negative, table_length)),
+                vandq_s32(past_end, table_length)
+            );
+
+            vst1q_s32(indices.as_mut_ptr(), index);
+            for lane in 0..4 {
+                output[start + lane] = i32::from(table[indices[lane] as usize]);
+            }
+        }
+
+        lookup_scalar(table, &angles[chunks * 4..length], &mut output[chunks * 4..length], compatibility);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ DRng, DTrig };
+
+    // Angles covering the vector range, its edges and angles that need the full normalization.
+    fn test_angles() -> Vec<Angle> {
+        let mut rng = DRng::new(37);
+        let mut thousandths: Vec<i32> = (-20000..20001).collect();
+
+        // Single angles outside the vector range placed in every lane of a group.
+        for lane in 0..8 {
+            let mut group: Vec<i32> = (0..8).map(|offset| 100 * offset).collect();
+            group[lane] = 12566;
+            thousandths.extend(group);
+        }
+
+        thousandths.extend([i32::MIN, i32::MAX, -6284, -6283, 6282, 6283, 12565, 12566]);
+        thousandths.extend((0..20000).map(|_| rng.next_in_range(-7000, 13000)));
+        thousandths.extend((0..20000).map(|_| rng.next_in_range(i32::MIN, i32::MAX)));
+
+        // An odd length leaves a remainder for the scalar path.
+        thousandths.push(6282);
+        thousandths.into_iter().map(Angle::from_thousandths).collect()
+    }
+
+    // Checks a lookup function against the single value sine and cosine for every test angle and slice offset.
+    fn check(lookup_function: fn(&[i16; 6283], &[Angle], &mut [i32], Compatibility)) {
+        let angles = test_angles();
+
+        for compatibility in [Compatibility::Current, Compatibility::Version0_1] {
+            let d_trig = DTrig::initialize().with_compatibility(compatibility);
+
+            for offset in 0..8 {
+                let mut sines = vec![0; angles.len() - offset];
+                let mut cosines = vec![0; angles.len() - offset];
+                lookup_function(&d_trig.sine_array, &angles[offset..], &mut sines, compatibility);
+                lookup_function(&d_trig.cosine_array, &angles[offset..], &mut cosines, compatibility);
+
+                for (index, angle) in angles[offset..].iter().enumerate() {
+                    assert_eq!(d_trig.sine(*angle), (sines[index], 1000), "{:?}", angle);
+                    assert_eq!(d_trig.cosine(*angle), (cosines[index], 1000), "{:?}", angle);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_scalar_lookup() {
+        check(lookup_scalar);
+    }
+
+    #[test]
+    fn test_selected_lookup() {
+        check(lookup);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_sse2_lookup() {
+        // Safety: every x86_64 processor supports SSE2.
+        check(|table, angles, output, compatibility| unsafe { x86::lookup_sse2(table, angles, output, compatibility) });
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_avx2_lookup() {
+        if std::is_x86_feature_detected!("avx2") {
+            // Safety: the processor supports AVX2, which was just checked.
+            check(|table, angles, output, compatibility| unsafe {
+                x86::lookup_avx2(table, angles, output, compatibility)
+            });
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[test]
+    fn test_neon_lookup() {
+        // Safety: every aarch64 processor supported by Rust has NEON.
+        check(|table, angles, output, compatibility| unsafe { arm::lookup_neon(table, angles, output, compatibility) });
+    }
+
+    // Compares the slice functions with the single value functions for every angle in and around the vector range, with
+    // each angle in every lane position, and for the angles on either side of where the fallback begins.
+    #[test]
+    fn test_every_angle() {
+        let mut thousandths: Vec<i32> = (-7000..13000).collect();
+        for boundary in [-TABLE_LENGTH, FAST_PATH_END, i32::MIN, i32::MAX] {
+            thousandths.extend((-8..=8).map(|offset| boundary.saturating_add(offset)));
+        }
+        let angles: Vec<Angle> = thousandths.into_iter().map(Angle::from_thousandths).collect();
+
+        for compatibility in [Compatibility::Current, Compatibility::Version0_1] {
+            let d_trig = DTrig::initialize().with_compatibility(compatibility);
+
+            // Starting the slice one angle later moves every angle to the next lane.
+            for offset in 0..8 {
+                let mut sines = vec![0; angles.len() - offset];
+                let mut cosines = vec![0; angles.len() - offset];
+                d_trig.sine_cosine_slice(&angles[offset..], &mut sines, &mut cosines);
+
+                for (index, angle) in angles[offset..].iter().enumerate() {
+                    assert_eq!(d_trig.sine(*angle).0, sines[index], "{:?}", angle);
+                    assert_eq!(d_trig.cosine(*angle).0, cosines[index], "{:?}", angle);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,512 @@
+/* This module contains the 2D matrix and affine transform types. Matrix entries are stored as numerators out of 1000,
+which is the same fixed point representation used by the trig function outputs, and every product is rounded to the
+nearest thousandth with halves rounded away from zero so that composition gives the same result on every machine. */
+
+use std::ops::Mul;
+
+use crate::utility;
+use crate::{ DTrig, IntoFraction };
+use crate::DVec2;
+
+/// A 2x2 matrix for rotating and scaling vectors.
+///
+/// - Each column holds the image of a basis vector as numerators out of 1000.
+/// - The identity matrix has a column of (1000, 0) followed by a column of (0, 1000).
+/// - Products are rounded to the nearest thousandth with halves rounded away from zero.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::{ DTrig, DMat2, DVec2 };
+///
+/// fn main (){
+///
+/// let d_trig = DTrig::initialize();
+///
+/// let quarter_turn = DMat2::from_angle(&d_trig, (1571, 1000));
+///
+/// let rotated = quarter_turn.mul_vec2(DVec2::new(100, 0));
+///
+/// println!("The point (100, 0) rotated a quarter turn is ({}, {}).", rotated.x, rotated.y);
+///
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DMat2 {
+    /// The first column, which is where the matrix sends (1, 0), as numerators out of 1000.
+    pub x_axis: DVec2,
+    /// The second column, which is where the matrix sends (0, 1), as numerators out of 1000.
+    pub y_axis: DVec2,
+}
+
+impl DMat2 {
+    /// The matrix that leaves every vector unchanged.
+    pub const IDENTITY: DMat2 = DMat2 { x_axis: DVec2::new(1000, 0), y_axis: DVec2::new(0, 1000) };
+
+    /// Creates a matrix from two columns given as numerators out of 1000.
+    pub const fn from_cols(x_axis: DVec2, y_axis: DVec2) -> Self {
+        DMat2 { x_axis, y_axis }
+    }
+
+    /// Creates a counterclockwise rotation matrix from an angle in radians.
+    ///
+    /// - The input represents the angle as a numerator and denominator.
+    /// - The entries come from the sine and cosine tables.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator.
+    pub fn from_angle<F: IntoFraction>(d_trig: &DTrig, angle_fraction: F) -> Self {
+        let angle_fraction = angle_fraction.into_fraction();
+        let sine = d_trig.sine(angle_fraction).0;
+        let cosine = d_trig.cosine(angle_fraction).0;
+
+        DMat2 { x_axis: DVec2::new(cosine, sine), y_axis: DVec2::new(-sine, cosine) }
+    }
+
+    /// Creates a matrix that scales along the x and y axes.
+    ///
+    /// - The inputs represent the scale factors as numerators and denominators.
+    /// - The scale factors are rounded to the nearest thousandth with halves away from zero. There is no DTrig here, so a
+    ///   rounding mode set with `DTrig::with_rounding` does not apply.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for either denominator.
+    /// - A scale factor too large to be stored as a numerator out of 1000 in an i32.
+    pub fn from_scale<F: IntoFraction, G: IntoFraction>(x_scale_fraction: F, y_scale_fraction: G) -> Self {
+        let x_scale = narrow(utility::wide_denominator_to_1000(x_scale_fraction.into_fraction()));
+        let y_scale = narrow(utility::wide_denominator_to_1000(y_scale_fraction.into_fraction()));
+
+        DMat2 { x_axis: DVec2::new(x_scale, 0), y_axis: DVec2::new(0, y_scale) }
+    }
+
+    /// Multiplies a vector by the matrix and rounds each component to the nearest integer.
+    ///
+    /// # Panics
+    ///
+    /// - A result component that does not fit in an i32.
+    pub fn mul_vec2(&self, vector: DVec2) -> DVec2 {
+        DVec2::new(
+            narrow(
+                utility::divide_and_round(
+                    i128::from(self.x_axis.x) * i128::from(vector.x) +
+                        i128::from(self.y_axis.x) * i128::from(vector.y),
+                    1000
+                )
+            ),
+            narrow(
+                utility::divide_and_round(
+                    i128::from(self.x_axis.y) * i128::from(vector.x) +
+                        i128::from(self.y_axis.y) * i128::from(vector.y),
+                    1000
+                )
+            )
+        )
+    }
+
+    /// Composes two matrices so that `other` is applied first and then `self`.
+    ///
+    /// # Panics
+    ///
+    /// - A result entry that does not fit in an i32.
+    pub fn mul_mat2(&self, other: &DMat2) -> DMat2 {
+        DMat2 { x_axis: self.mul_vec2(other.x_axis), y_axis: self.mul_vec2(other.y_axis) }
+    }
+
+    /// Swaps the rows and columns of the matrix.
+    pub fn transpose(&self) -> Self {
+        DMat2 {
+            x_axis: DVec2::new(self.x_axis.x, self.y_axis.x),
+            y_axis: DVec2::new(self.x_axis.y, self.y_axis.y),
+        }
+    }
+
+    /// Calculates the determinant as a numerator out of 1,000,000.
+    pub fn determinant(&self) -> i128 {
+        self.x_axis.cross(self.y_axis)
+    }
+
+    /// Inverts a pure rotation matrix.
+    ///
+    /// - Only correct for matrices without scale or skew, such as those from `from_angle` or `orthonormalize`.
+    /// - The inverse of a rotation is its transpose, so no rounding takes place.
+    pub fn inverse_rotation(&self) -> Self {
+        self.transpose()
+    }
+
+    /// Removes accumulated scale and skew so that the matrix is a pure rotation again.
+    ///
+    /// - Keeps the direction of the first column and rescales it to a length of 1000.
+    /// - Replaces the second column with the first column turned a quarter turn.
+    /// - Keeps reflections as reflections.
+    /// - Returns the identity matrix if the first column is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::{ DTrig, DMat2 };
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize();
+    ///
+    /// let step = DMat2::from_angle(&d_trig, (1, 1000));
+    ///
+    /// let mut heading = DMat2::IDENTITY;
+    ///
+    /// for _ in 0..10000 {
+    ///     heading = step.mul_mat2(&heading).orthonormalize();
+    /// }
+    ///
+    /// println!("The determinant after 10,000 steps is {}/1000000.", heading.determinant());
+    ///
+    /// }
+    /// ```
+    pub fn orthonormalize(&self) -> Self {
+        let length = self.x_axis.length();
+
+        if length == 0 {
+            return DMat2::IDENTITY;
+        }
+
+        let x_axis = DVec2::new(
+            narrow(utility::divide_and_round(i128::from(self.x_axis.x) * 1000, i128::from(length))),
+            narrow(utility::divide_and_round(i128::from(self.x_axis.y) * 1000, i128::from(length)))
+        );
+
+        // A negative determinant means the matrix is a reflection.
+        if self.determinant() < 0 {
+            DMat2 { x_axis, y_axis: -x_axis.perpendicular() }
+        } else {
+            DMat2 { x_axis, y_axis: x_axis.perpendicular() }
+        }
+    }
+}
+
+impl Default for DMat2 {
+    fn default() -> Self {
+        DMat2::IDENTITY
+    }
+}
+
+impl Mul for DMat2 {
+    type Output = DMat2;
+
+    fn mul(self, other: DMat2) -> DMat2 {
+        self.mul_mat2(&other)
+    }
+}
+
+impl Mul<DVec2> for DMat2 {
+    type Output = DVec2;
+
+    fn mul(self, vector: DVec2) -> DVec2 {
+        self.mul_vec2(vector)
+    }
+}
+
+/// A 2D affine transform made of a matrix followed by a translation.
+///
+/// - Points are multiplied by the matrix and then moved by the translation.
+/// - Vectors are only multiplied by the matrix.
+/// - Useful for parent and child transforms such as a turret attached to a vehicle.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::{ DTrig, DAffine2, DVec2 };
+///
+/// fn main (){
+///
+/// let d_trig = DTrig::initialize();
+///
+/// let vehicle = DAffine2::from_angle_translation(&d_trig, (785, 1000), DVec2::new(5000, 2000));
+/// let turret = DAffine2::from_translation(DVec2::new(0, 300));
+///
+/// let turret_in_world = vehicle * turret;
+///
+/// let muzzle = turret_in_world.transform_point(DVec2::new(150, 0));
+///
+/// println!("The muzzle is at ({}, {}).", muzzle.x, muzzle.y);
+///
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DAffine2 {
+    /// The rotation and scale part of the transform.
+    pub matrix: DMat2,
+    /// The translation part of the transform in world units.
+    pub translation: DVec2,
+}
+
+impl DAffine2 {
+    /// The transform that leaves every point unchanged.
+    pub const IDENTITY: DAffine2 = DAffine2 { matrix: DMat2::IDENTITY, translation: DVec2::ZERO };
+
+    /// Creates a transform from a matrix and a translation.
+    pub const fn from_mat2_translation(matrix: DMat2, translation: DVec2) -> Self {
+        DAffine2 { matrix, translation }
+    }
+
+    /// Creates a transform that only moves points.
+    pub const fn from_translation(translation: DVec2) -> Self {
+        DAffine2 { matrix: DMat2::IDENTITY, translation }
+    }
+
+    /// Creates a transform that rotates counterclockwise about the origin by an angle in radians.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator.
+    pub fn from_angle<F: IntoFraction>(d_trig: &DTrig, angle_fraction: F) -> Self {
+        DAffine2 { matrix: DMat2::from_angle(d_trig, angle_fraction), translation: DVec2::ZERO }
+    }
+
+    /// Creates a transform that rotates by an angle in radians and then moves points.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for the denominator.
+    pub fn from_angle_translation<F: IntoFraction>(
+        d_trig: &DTrig,
+        angle_fraction: F,
+        translation: DVec2
+    ) -> Self {
+        DAffine2 { matrix: DMat2::from_angle(d_trig, angle_fraction), translation }
+    }
+
+    /// Creates a transform that scales along the x and y axes.
+    ///
+    /// - The scale factors are rounded the same way as `DMat2::from_scale`.
+    ///
+    /// # Panics
+    ///
+    /// - A zero as the input for either denominator.
+    /// - A scale factor too large to be stored as a numerator out of 1000 in an i32.
+    pub fn from_scale<F: IntoFraction, G: IntoFraction>(x_scale_fraction: F, y_scale_fraction: G) -> Self {
+        DAffine2 {
+            matrix: DMat2::from_scale(x_scale_fraction, y_scale_fraction),
+            translation: DVec2::ZERO,
+        }
+    }
+
+    /// Applies the transform to a point.
+    ///
+    /// # Panics
+    ///
+    /// - A result component that does not fit in an i32.
+    pub fn transform_point(&self, point: DVec2) -> DVec2 {
+        let moved = self.matrix.mul_vec2(point);
+
+        DVec2::new(
+            narrow(i128::from(moved.x) + i128::from(self.translation.x)),
+            narrow(i128::from(moved.y) + i128::from(self.translation.y))
+        )
+    }
+
+    /// Applies the transform to a vector, which ignores the translation.
+    ///
+    /// # Panics
+    ///
+    /// - A result component that does not fit in an i32.
+    pub fn transform_vector(&self, vector: DVec2) -> DVec2 {
+        self.matrix.mul_vec2(vector)
+    }
+
+    /// Composes two transforms so that `other` is applied first and then `self`.
+    ///
+    /// # Panics
+    ///
+    /// - A result component that does not fit in an i32.
+    pub fn mul_affine2(&self, other: &DAffine2) -> DAffine2 {
+        DAffine2 {
+            matrix: self.matrix.mul_mat2(&other.matrix),
+            translation: self.transform_point(other.translation),
+        }
+    }
+
+    /// Inverts a transform made only of a rotation and a translation.
+    ///
+    /// - Only correct for transforms without scale or skew.
+    /// - Use `world_to_local` to convert points through transforms that also scale.
+    ///
+    /// # Panics
+    ///
+    /// - A result component that does not fit in an i32.
+    pub fn inverse_rigid(&self) -> Self {
+        let matrix = self.matrix.inverse_rotation();
+
+        DAffine2 { matrix, translation: -matrix.mul_vec2(self.translation) }
+    }
+
+    /// Converts a point in world space into the local space of the transform.
+    ///
+    /// - This is the inverse of `transform_point` and works for any invertible transform.
+    /// - The point is solved for exactly and then rounded to the nearest integer.
+    ///
+    /// # Panics
+    ///
+    /// - A transform with a determinant of zero, which cannot be inverted.
+    /// - A result component that does not fit in an i32.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deterministic_trigonometry::{ DTrig, DAffine2, DVec2 };
+    ///
+    /// fn main (){
+    ///
+    /// let d_trig = DTrig::initialize();
+    ///
+    /// let vehicle = DAffine2::from_angle_translation(&d_trig, (785, 1000), DVec2::new(5000, 2000));
+    ///
+    /// let target = vehicle.world_to_local(DVec2::new(6000, 3000));
+    ///
+    /// println!("The target is at ({}, {}) relative to the vehicle.", target.x, target.y);
+    ///
+    /// }
+    /// ```
+    pub fn world_to_local(&self, point: DVec2) -> DVec2 {
+        let determinant = self.matrix.determinant();
+
+        if determinant == 0 {
+            panic!("Transform with a determinant of zero, which cannot be inverted.");
+        }
+
+        let relative_x = i128::from(point.x) - i128::from(self.translation.x);
+        let relative_y = i128::from(point.y) - i128::from(self.translation.y);
+
+        // Multiplies by the adjugate and divides by the determinant, which is out of 1,000,000.
+        DVec2::new(
+            narrow(
+                utility::divide_and_round(
+                    (i128::from(self.matrix.y_axis.y) * relative_x -
+                        i128::from(self.matrix.y_axis.x) * relative_y) *
+                        1000,
+                    determinant
+                )
+            ),
+            narrow(
+                utility::divide_and_round(
+                    (i128::from(self.matrix.x_axis.x) * relative_y -
+                        i128::from(self.matrix.x_axis.y) * relative_x) *
+                        1000,
+                    determinant
+                )
+            )
+        )
+    }
+
+    /// Removes accumulated scale and skew from the matrix while keeping the translation.
+    pub fn orthonormalize(&self) -> Self {
+        DAffine2 { matrix: self.matrix.orthonormalize(), translation: self.translation }
+    }
+}
+
+impl Default for DAffine2 {
+    fn default() -> Self {
+        DAffine2::IDENTITY
+    }
+}
+
+impl Mul for DAffine2 {
+    type Output = DAffine2;
+
+    fn mul(self, other: DAffine2) -> DAffine2 {
+        self.mul_affine2(&other)
+    }
+}
+
+// Converts an intermediate result back to an i32.
+fn narrow(value: i128) -> i32 {
+    match i32::try_from(value) {
+        Ok(narrowed) => narrowed,
+        Err(_) => panic!("Transform result too large for an i32."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ DAffine2, DMat2, DTrig, DVec2 };
+
+    #[test]
+    fn test_rotation() {
+        let d_trig = DTrig::initialize();
+
+        let quarter_turn = DMat2::from_angle(&d_trig, (1571, 1000));
+        assert_eq!(quarter_turn.mul_vec2(DVec2::new(1000, 0)), DVec2::new(0, 1000));
+        assert_eq!(quarter_turn.mul_vec2(DVec2::new(0, 1000)), DVec2::new(-1000, 0));
+
+        // Rotating and then rotating back stays within rounding of the start for every thousandth angle.
+        for angle in 0..6283 {
+            let rotation = DMat2::from_angle(&d_trig, (angle, 1000));
+            let point = DVec2::new(7000, -3000);
+            let there_and_back = rotation.inverse_rotation().mul_vec2(rotation.mul_vec2(point));
+
+            assert!((there_and_back.x - point.x).abs() <= 10);
+            assert!((there_and_back.y - point.y).abs() <= 10);
+        }
+    }
+
+    #[test]
+    fn test_composition_and_inverse() {
+        let d_trig = DTrig::initialize();
+
+        let vehicle = DAffine2::from_angle_translation(&d_trig, (785, 1000), DVec2::new(5000, 2000));
+        let turret = DAffine2::from_angle_translation(&d_trig, (1571, 1000), DVec2::new(0, 300));
+        let turret_in_world = vehicle * turret;
+
+        let local_point = DVec2::new(150, 40);
+        let chained = vehicle.transform_point(turret.transform_point(local_point));
+        let composed = turret_in_world.transform_point(local_point);
+        assert!((chained.x - composed.x).abs() <= 1);
+        assert!((chained.y - composed.y).abs() <= 1);
+
+        // The rigid inverse and the exact solve both undo the transform.
+        let world_point = turret_in_world.transform_point(local_point);
+        let via_inverse = turret_in_world.inverse_rigid().transform_point(world_point);
+        let via_solve = turret_in_world.world_to_local(world_point);
+        assert!((via_inverse.x - local_point.x).abs() <= 2);
+        assert!((via_inverse.y - local_point.y).abs() <= 2);
+        assert!((via_solve.x - local_point.x).abs() <= 1);
+        assert!((via_solve.y - local_point.y).abs() <= 1);
+
+        // The exact solve also undoes scaling.
+        let scaled = DAffine2::from_translation(DVec2::new(-40, 90)) *
+            DAffine2::from_scale((3, 1), (1, 4));
+        assert_eq!(scaled.transform_point(DVec2::new(10, 20)), DVec2::new(-10, 95));
+        assert_eq!(scaled.world_to_local(DVec2::new(-10, 95)), DVec2::new(10, 20));
+
+        // Vectors ignore the translation.
+        assert_eq!(scaled.transform_vector(DVec2::new(10, 20)), DVec2::new(30, 5));
+    }
+
+    #[test]
+    fn test_orthonormalize_prevents_drift() {
+        let d_trig = DTrig::initialize();
+        let step = DMat2::from_angle(&d_trig, (37, 1000));
+
+        let mut heading = DMat2::IDENTITY;
+        for _ in 0..100000 {
+            heading = (step * heading).orthonormalize();
+
+            // The columns stay perpendicular and close to unit length.
+            assert_eq!(heading.x_axis.dot(heading.y_axis), 0);
+            assert!((heading.x_axis.length() - 1000).abs() <= 1);
+            assert!((heading.determinant() - 1000000).abs() <= 2000);
+        }
+
+        // Reflections stay reflections.
+        let mirror = DMat2::from_scale((-2, 1), (1, 1)).orthonormalize();
+        assert_eq!(mirror, DMat2::from_cols(DVec2::new(-1000, 0), DVec2::new(0, 1000)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_world_to_local_panics_when_singular() {
+        DAffine2::from_scale((0, 1), (1, 1)).world_to_local(DVec2::new(1, 1));
+    }
+}
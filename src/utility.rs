@@ -1,8 +1,10 @@
-// Outputs the nearest integer fraction out of 1000 to the argument fraction.
-pub (in super) fn denominator_to_1000(argument_fraction_i32: (i32, i32)) -> i64 {
+use crate::RoundingMode;
+
+// Outputs the nearest integer fraction out of 1000 to an argument fraction made of i64 values.
+pub (in super) fn wide_denominator_to_1000(argument_fraction_i64: (i64, i64)) -> i128 {
     let argument_fraction = (
-        i64::from(argument_fraction_i32.0),
-        i64::from(argument_fraction_i32.1),
+        i128::from(argument_fraction_i64.0),
+        i128::from(argument_fraction_i64.1),
     );
 
     // This handles the case of zero denominators
@@ -12,7 +14,7 @@ pub (in super) fn denominator_to_1000(argument_fraction_i32: (i32, i32)) -> i64
 
     // No need to do anything if input is already out of 1000.
     if argument_fraction.1 == 1000 {
-        return i64::from(argument_fraction.0);
+        return argument_fraction.0;
     } else {
         // Handles other denominators by rounding to nearest thousandth.
         if argument_fraction.1 % 2 == 0 {
@@ -45,30 +47,53 @@ pub (in super) fn denominator_to_1000(argument_fraction_i32: (i32, i32)) -> i64
     }
 }
 
-// Normalizes angles to 0 to 6282 thousandth radians
+// Outputs the nearest integer fraction out of 1000 to an argument fraction made of i32 values.
+#[cfg(test)]
+pub (in super) fn denominator_to_1000(argument_fraction_i32: (i32, i32)) -> i64 {
+    wide_denominator_to_1000((i64::from(argument_fraction_i32.0), i64::from(argument_fraction_i32.1))) as i64
+}
+
+// Outputs the fraction out of 1000 nearest to an argument fraction made of i64 values using a rounding mode.
+pub (in super) fn wide_denominator_to_1000_with_rounding(
+    argument_fraction_i64: (i64, i64),
+    rounding: RoundingMode
+) -> i128 {
+    if argument_fraction_i64.1 == 0 {
+        panic!("Input denominator of zero, which is undefined.")
+    }
 
+    rounding.divide(i128::from(argument_fraction_i64.0) * 1000, i128::from(argument_fraction_i64.1))
+}
+
+// Normalizes angles to 0 to 6282 thousandth radians
+#[cfg(test)]
 pub (in super) fn normalize_angle(thousandth_angle: i64) -> i64 {
+    normalize_wide_angle(i128::from(thousandth_angle))
+}
+
+// Normalizes angles held in an i128 to 0 to 6282 thousandth radians
+pub (in super) fn normalize_wide_angle(thousandth_angle: i128) -> i64 {
     let mut return_angle = thousandth_angle;
 
     // Handles the case in which the angle is greater than or equal to 2 pi radians.
     if return_angle > 6282 {
         // Multiplied by 1000000 to allow more precision.
-        let mut angle_times_a_billion = i128::from(return_angle) * 1000000000;
+        let mut angle_times_a_billion = return_angle * 1000000000;
 
         // Normalizes angle
         angle_times_a_billion = angle_times_a_billion % 6283185307180;
 
         // Divides by 1 million and rounds to the nearest integer.
         if angle_times_a_billion % 1000000000 > 499999999 {
-            return_angle = (angle_times_a_billion / 1000000000 + 1) as i64;
+            return_angle = angle_times_a_billion / 1000000000 + 1;
         } else {
-            return_angle = (angle_times_a_billion / 1000000000) as i64;
+            return_angle = angle_times_a_billion / 1000000000;
         }
 
         // Handles negative angles.
     } else if return_angle < 0 {
         // Multiplied by 1 billion to allow more precision.
-        let mut angle_times_a_billion = i128::from(return_angle) * 1000000000;
+        let mut angle_times_a_billion = return_angle * 1000000000;
 
         // Normalizes angle
         angle_times_a_billion = angle_times_a_billion % 6283185307180;
@@ -78,9 +103,9 @@ pub (in super) fn normalize_angle(thousandth_angle: i64) -> i64 {
 
         // Divides by 1 billion and rounds to the nearest integer.
         if angle_times_a_billion % 1000000000 > 499999999 {
-            return_angle = (angle_times_a_billion / 1000000000 + 1) as i64;
+            return_angle = angle_times_a_billion / 1000000000 + 1;
         } else {
-            return_angle = (angle_times_a_billion / 1000000000) as i64;
+            return_angle = angle_times_a_billion / 1000000000;
         }
     }
 
@@ -90,7 +115,72 @@ pub (in super) fn normalize_angle(thousandth_angle: i64) -> i64 {
     }
 
     // Returns angle in thousandth angle.
-    return return_angle;
+    return return_angle as i64;
+}
+
+// Divides two integers and rounds the result to the nearest integer with halves rounded away from zero.
+pub (in super) fn divide_and_round(numerator: i128, denominator: i128) -> i128 {
+    if denominator == 0 {
+        panic!("Division by zero, which is undefined.");
+    }
+
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+
+    // Rounds away from zero if the remainder is at least half of the denominator.
+    if remainder.abs() * 2 >= denominator.abs() {
+        if (numerator < 0) == (denominator < 0) {
+            quotient + 1
+        } else {
+            quotient - 1
+        }
+    } else {
+        quotient
+    }
+}
+
+// Outputs the largest integer whose square is less than or equal to the input.
+pub (in super) fn integer_square_root(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    // Newton's method starting from a power of two that is guaranteed to be above the root.
+    let mut estimate = 1u128 << ((128 - value.leading_zeros()) / 2 + 1);
+
+    loop {
+        let next_estimate = (estimate + value / estimate) / 2;
+        if next_estimate >= estimate {
+            return estimate;
+        }
+        estimate = next_estimate;
+    }
+}
+
+// Outputs the integer nearest to the square root of the input.
+pub (in super) fn rounded_square_root(value: u128) -> u128 {
+    let root = integer_square_root(value);
+
+    // The root rounds up when the value is past (root + 1/2) squared, which is root squared plus root plus 1/4.
+    if value - root * root > root {
+        root + 1
+    } else {
+        root
+    }
+}
+
+// Outputs the greatest common divisor of two integers, or zero if both are zero.
+pub (in super) fn greatest_common_divisor(first: u128, second: u128) -> u128 {
+    let mut a = first;
+    let mut b = second;
+
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+
+    a
 }
 
 #[cfg(test)]
@@ -180,4 +270,58 @@ mod tests {
         }
         assert_eq!(test, true);
     }
+
+    // This tests that division rounds halves away from zero for every combination of signs.
+    #[test]
+    fn test_divide_and_round() {
+        assert_eq!(divide_and_round(7, 2), 4);
+        assert_eq!(divide_and_round(-7, 2), -4);
+        assert_eq!(divide_and_round(7, -2), -4);
+        assert_eq!(divide_and_round(-7, -2), 4);
+        assert_eq!(divide_and_round(5, 3), 2);
+        assert_eq!(divide_and_round(4, 3), 1);
+        assert_eq!(divide_and_round(-4, 3), -1);
+
+        for a in -3000..3001 {
+            for b in (-70..71).filter(|b| *b != 0) {
+                assert_eq!(
+                    divide_and_round(a, b),
+                    ((a as f64) / (b as f64)).round() as i128
+                );
+            }
+        }
+    }
+
+    // This tests the square roots around every perfect square up to a large bound and at the top of the range.
+    #[test]
+    fn test_square_roots() {
+        for root in 0..100000u128 {
+            assert_eq!(integer_square_root(root * root), root);
+            assert_eq!(rounded_square_root(root * root), root);
+            if root > 0 {
+                assert_eq!(integer_square_root(root * root - 1), root - 1);
+                assert_eq!(rounded_square_root(root * root + root), root);
+                assert_eq!(rounded_square_root(root * root + root + 1), root + 1);
+            }
+        }
+
+        assert_eq!(integer_square_root(u128::MAX), u128::from(u64::MAX));
+        assert_eq!(rounded_square_root(u128::from(u64::MAX)), 1u128 << 32);
+    }
+
+    // This tests the greatest common divisor against the definition for small values.
+    #[test]
+    fn test_greatest_common_divisor() {
+        assert_eq!(greatest_common_divisor(0, 0), 0);
+        assert_eq!(greatest_common_divisor(0, 7), 7);
+        assert_eq!(greatest_common_divisor(12, 18), 6);
+        assert_eq!(greatest_common_divisor(1 << 31, 1 << 40), 1 << 31);
+
+        for a in 1..200u128 {
+            for b in 1..200u128 {
+                let expected = (1..=a.min(b)).filter(|d| a % d == 0 && b % d == 0).max().unwrap();
+                assert_eq!(greatest_common_divisor(a, b), expected);
+            }
+        }
+    }
 }
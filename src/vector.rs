@@ -0,0 +1,155 @@
+/* This module contains the two dimensional integer vector type that is used for points and directions by the
+transform code. */
+
+use std::ops::{ Add, Neg, Sub };
+
+/// A two dimensional vector with integer components.
+///
+/// - Used for both points and directions.
+/// - The units are whatever units the caller uses for positions.
+/// - Direction vectors built from trig results are scaled so that a length of 1000 is a unit vector.
+/// - Addition, subtraction and negation panic on overflow in every build profile, so a result never depends on
+///   whether overflow checks are on.
+///
+/// # Example
+///
+/// ```
+/// use deterministic_trigonometry::DVec2;
+///
+/// fn main (){
+///
+/// let position = DVec2::new(3, 4);
+///
+/// println!("The point ({}, {}) is {} units from the origin.", position.x, position.y, position.length());
+///
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DVec2 {
+    /// The x component.
+    pub x: i32,
+    /// The y component.
+    pub y: i32,
+}
+
+impl DVec2 {
+    /// The vector with both components equal to zero.
+    pub const ZERO: DVec2 = DVec2 { x: 0, y: 0 };
+
+    /// Creates a vector from its x and y components.
+    pub const fn new(x: i32, y: i32) -> Self {
+        DVec2 { x, y }
+    }
+
+    /// Calculates the dot product of two vectors without overflow.
+    pub fn dot(self, other: DVec2) -> i128 {
+        i128::from(self.x) * i128::from(other.x) + i128::from(self.y) * i128::from(other.y)
+    }
+
+    /// Calculates the z component of the cross product of two vectors without overflow.
+    ///
+    /// - Positive if `other` is counterclockwise from `self`.
+    /// - Negative if `other` is clockwise from `self`.
+    /// - Zero if the vectors are parallel.
+    pub fn cross(self, other: DVec2) -> i128 {
+        i128::from(self.x) * i128::from(other.y) - i128::from(self.y) * i128::from(other.x)
+    }
+
+    /// Calculates the squared length of the vector without overflow.
+    pub fn length_squared(self) -> i128 {
+        self.dot(self)
+    }
+
+    /// Calculates the length of the vector rounded to the nearest integer.
+    pub fn length(self) -> i64 {
+        crate::utility::rounded_square_root(self.length_squared() as u128) as i64
+    }
+
+    /// Rotates the vector by a quarter turn counterclockwise.
+    ///
+    /// # Panics
+    ///
+    /// - A y component of i32::MIN, whose negative does not fit in an i32.
+    pub fn perpendicular(self) -> Self {
+        DVec2 { x: checked(self.y.checked_neg()), y: self.x }
+    }
+}
+
+impl Add for DVec2 {
+    type Output = DVec2;
+
+    fn add(self, other: DVec2) -> DVec2 {
+        DVec2 { x: checked(self.x.checked_add(other.x)), y: checked(self.y.checked_add(other.y)) }
+    }
+}
+
+impl Sub for DVec2 {
+    type Output = DVec2;
+
+    fn sub(self, other: DVec2) -> DVec2 {
+        DVec2 { x: checked(self.x.checked_sub(other.x)), y: checked(self.y.checked_sub(other.y)) }
+    }
+}
+
+impl Neg for DVec2 {
+    type Output = DVec2;
+
+    fn neg(self) -> DVec2 {
+        DVec2 { x: checked(self.x.checked_neg()), y: checked(self.y.checked_neg()) }
+    }
+}
+
+impl From<(i32, i32)> for DVec2 {
+    fn from(components: (i32, i32)) -> Self {
+        DVec2 { x: components.0, y: components.1 }
+    }
+}
+
+impl From<DVec2> for (i32, i32) {
+    fn from(vector: DVec2) -> Self {
+        (vector.x, vector.y)
+    }
+}
+
+// Unwraps a component computed with checked arithmetic, panicking on overflow the same way in every build profile.
+fn checked(component: Option<i32>) -> i32 {
+    match component {
+        Some(component) => component,
+        None => panic!("Vector component too large for an i32."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DVec2;
+
+    #[test]
+    fn test_products_and_length() {
+        let a = DVec2::new(3, 4);
+        let b = DVec2::new(-4, 3);
+
+        assert_eq!(a.dot(b), 0);
+        assert_eq!(a.cross(b), 25);
+        assert_eq!(b.cross(a), -25);
+        assert_eq!(a.length(), 5);
+        assert_eq!(a.perpendicular(), b);
+
+        // The largest components must not overflow.
+        let corner = DVec2::new(i32::MIN, i32::MIN);
+        assert_eq!(corner.length_squared(), 1i128 << 63);
+        assert_eq!(corner.length(), 3037000500);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector component too large for an i32.")]
+    fn test_addition_overflow_panics() {
+        let _ = DVec2::new(i32::MAX, 0) + DVec2::new(1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector component too large for an i32.")]
+    fn test_negation_overflow_panics() {
+        let _ = -DVec2::new(0, i32::MIN);
+    }
+}
@@ -0,0 +1,181 @@
+/* This module contains the checks shared by the fuzz targets in fuzz/ and the fuzz test in tests/fuzz_test.rs. Each
+check takes a structured input built from arbitrary bytes, skips the inputs that are documented to panic, and panics if
+anything else goes wrong, so a fuzzer reports any other panic, any overflow, or any wrong result as a crash.
+
+The conversion check rounds the input to thousandths independently in i128 with the rounding mode the input chooses,
+and compares the result with what the library does. The arctangent check steps across the tier boundaries at 4, 20,
+100 and 1000 and the end of the tables, and checks that the output never falls and never jumps by more than 1/1000 per
+thousandth. It is run with both compatibility settings, as version 0.1 truncates the negative inputs of the ones tier
+rather than rounding them. */
+
+#![allow(dead_code)]
+
+use arbitrary::Arbitrary;
+use deterministic_trigonometry::{ Angle, Compatibility, DTrig, Fraction, RoundingMode };
+
+// Every rounding mode, so a byte of input can choose one.
+const ROUNDING_MODES: [RoundingMode; 5] = [
+    RoundingMode::HalfAwayFromZero,
+    RoundingMode::HalfEven,
+    RoundingMode::Floor,
+    RoundingMode::Ceiling,
+    RoundingMode::Truncate,
+];
+
+// The thousandths at which the arctangent changes tiers, and the thousandth past which it stays at 1571/1000.
+const TIER_BOUNDARIES: [i32; 5] = [4000, 20000, 100000, 1000000, 3374653];
+
+/// A fraction of i64 values and a rounding mode for the conversion to thousandths.
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub struct ConversionInput {
+    pub numerator: i64,
+    pub denominator: i64,
+    pub rounding: u8,
+}
+
+/// A thousandth near one of the arctangent tier boundaries, and a second one from anywhere.
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub struct TierInput {
+    pub boundary: u8,
+    pub offset: i16,
+    pub negative: bool,
+    pub anywhere: i32,
+}
+
+/// Initializes one DTrig for every rounding mode, in the order a byte of input chooses them.
+pub fn d_trig_for_every_rounding_mode() -> Vec<DTrig> {
+    ROUNDING_MODES.iter().map(|rounding| DTrig::initialize().with_rounding(*rounding)).collect()
+}
+
+/// Initializes one DTrig for every compatibility setting.
+pub fn d_trig_for_every_compatibility() -> Vec<DTrig> {
+    [Compatibility::Current, Compatibility::Version0_1]
+        .iter()
+        .map(|compatibility| DTrig::initialize().with_compatibility(*compatibility))
+        .collect()
+}
+
+/// Checks that an input is rounded to thousandths correctly everywhere it can be converted.
+pub fn check_conversion(d_trigs: &[DTrig], input: ConversionInput) {
+    // A zero denominator is a documented panic.
+    if input.denominator == 0 {
+        return;
+    }
+
+    let fraction = (input.numerator, input.denominator);
+    let mode = usize::from(input.rounding) % ROUNDING_MODES.len();
+    let (d_trig, rounding) = (&d_trigs[mode], ROUNDING_MODES[mode]);
+    let expected = round_to_thousandths(input.numerator, input.denominator, rounding);
+
+    // Angle::from_fraction always rounds halves away from zero and panics past the range of an i32.
+    let half_away_from_zero = round_to_thousandths(fraction.0, fraction.1, RoundingMode::HalfAwayFromZero);
+    if let Ok(thousandths) = i32::try_from(half_away_from_zero) {
+        assert_eq!(Angle::from_fraction(fraction).thousandths(), thousandths, "Angle::from_fraction{:?}", fraction);
+    }
+
+    // Fraction::rescale_with_rounding panics if the rescaled numerator does not fit in an i32.
+    if let (Ok(numerator), Ok(denominator), Ok(thousandths)) = (
+        i32::try_from(input.numerator),
+        i32::try_from(input.denominator),
+        i32::try_from(expected),
+    ) {
+        assert_eq!(
+            Fraction(numerator, denominator).rescale_with_rounding(1000, rounding),
+            Fraction(thousandths, 1000),
+            "Fraction{:?}.rescale_with_rounding(1000, {:?})",
+            (numerator, denominator),
+            rounding
+        );
+    }
+
+    match i64::try_from(expected) {
+        // The result must be the same as for the rounded input.
+        Ok(thousandths) => {
+            let rounded = (thousandths, 1000);
+            assert_eq!(d_trig.sine(fraction), d_trig.sine(rounded), "sine{:?} with {:?}", fraction, rounding);
+            assert_eq!(d_trig.cosine(fraction), d_trig.cosine(rounded), "cosine{:?} with {:?}", fraction, rounding);
+            assert_eq!(d_trig.tangent(fraction), d_trig.tangent(rounded), "tangent{:?} with {:?}", fraction, rounding);
+            assert_eq!(
+                d_trig.arctangent(fraction),
+                d_trig.arctangent(rounded),
+                "arctangent{:?} with {:?}",
+                fraction,
+                rounding
+            );
+
+            // Inputs outside of -1 to 1 after rounding are a documented panic of the arcsine and arccosine.
+            if (-1000..=1000).contains(&thousandths) {
+                assert_eq!(d_trig.arcsine(fraction), d_trig.arcsine(rounded), "arcsine{:?}", fraction);
+                assert_eq!(d_trig.arccosine(fraction), d_trig.arccosine(rounded), "arccosine{:?}", fraction);
+            }
+        }
+        // Thousandths past the range of an i64 can not be written as a fraction out of 1000, so these only have to
+        // convert without panicking or overflowing.
+        Err(_) => {
+            d_trig.sine(fraction);
+            d_trig.cosine(fraction);
+            d_trig.tangent(fraction);
+            assert_eq!(d_trig.arctangent(fraction).0, if expected < 0 { -1571 } else { 1571 });
+        }
+    }
+}
+
+/// Checks that the arctangent is continuous and never falls across the tier boundaries.
+pub fn check_arctangent_tiers(d_trig: &DTrig, input: TierInput) {
+    let boundary = i64::from(TIER_BOUNDARIES[usize::from(input.boundary) % TIER_BOUNDARIES.len()]);
+    let near_boundary = boundary + i64::from(input.offset);
+    let near_boundary = if input.negative { -near_boundary } else { near_boundary };
+
+    check_arctangent_step(d_trig, near_boundary);
+    check_arctangent_step(d_trig, i64::from(input.anywhere));
+}
+
+// Checks one step of a thousandth, and that the same step written with another denominator gives the same outputs.
+fn check_arctangent_step(d_trig: &DTrig, thousandths: i64) {
+    let low = d_trig.arctangent((thousandths, 1000));
+    let high = d_trig.arctangent((thousandths + 1, 1000));
+
+    // Every tier moves to at most the next table entry per thousandth. Neighboring entries are less than 1/1000 apart
+    // before rounding, as the slope of the arctangent is at most 1, and at most 1/17 past the thousandths tier, so
+    // after rounding they are at most 1/1000 apart.
+    assert!(
+        high.0 >= low.0 && high.0 - low.0 <= 1,
+        "arctangent jumps from {}/1000 to {}/1000 between {}/1000 and {}/1000",
+        low.0,
+        high.0,
+        thousandths,
+        thousandths + 1
+    );
+
+    assert_eq!(d_trig.arctangent((thousandths * 7, 7000)), low, "arctangent({}/1000) as sevenths", thousandths);
+}
+
+/// Rounds numerator / denominator to thousandths with a rounding mode, computed from the floor of the quotient.
+pub fn round_to_thousandths(numerator: i64, denominator: i64, rounding: RoundingMode) -> i128 {
+    let (numerator, denominator) = if denominator < 0 {
+        (-i128::from(numerator) * 1000, -i128::from(denominator))
+    } else {
+        (i128::from(numerator) * 1000, i128::from(denominator))
+    };
+
+    let floor = numerator.div_euclid(denominator);
+    let remainder = numerator.rem_euclid(denominator);
+
+    if remainder == 0 {
+        return floor;
+    }
+
+    match rounding {
+        RoundingMode::Floor => floor,
+        RoundingMode::Ceiling => floor + 1,
+        RoundingMode::Truncate => if floor < 0 { floor + 1 } else { floor },
+        RoundingMode::HalfAwayFromZero | RoundingMode::HalfEven => {
+            match (remainder * 2).cmp(&denominator) {
+                std::cmp::Ordering::Less => floor,
+                std::cmp::Ordering::Greater => floor + 1,
+                std::cmp::Ordering::Equal if rounding == RoundingMode::HalfEven => floor + (floor & 1),
+                std::cmp::Ordering::Equal => if floor < 0 { floor } else { floor + 1 },
+            }
+        }
+    }
+}
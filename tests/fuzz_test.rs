@@ -0,0 +1,61 @@
+/* This test runs the fuzz checks on stable Rust without cargo-fuzz. It builds the structured inputs from a fixed
+stream of DRng bytes, so every run checks the same inputs, and adds the edge cases a fuzzer would take longest to find.
+The fuzz targets in fuzz/ run the same checks on inputs found by libFuzzer. */
+
+use arbitrary::{ Arbitrary, Unstructured };
+use deterministic_trigonometry::DRng;
+
+mod fuzz_checks;
+use fuzz_checks::{ ConversionInput, TierInput };
+
+// The number of structured inputs built for each check.
+const INPUTS: usize = 100000;
+
+// Builds structured inputs from a seeded stream of bytes.
+fn inputs<T: for<'a> Arbitrary<'a>>(seed: u64) -> Vec<T> {
+    let mut rng = DRng::new(seed);
+    let bytes: Vec<u8> = (0..INPUTS * 32).map(|_| rng.next_u32() as u8).collect();
+    let mut unstructured = Unstructured::new(&bytes);
+
+    (0..INPUTS).map(|_| T::arbitrary(&mut unstructured).expect("The stream of bytes ran out.")).collect()
+}
+
+#[test]
+fn test_fuzz_conversion() {
+    let d_trigs = fuzz_checks::d_trig_for_every_rounding_mode();
+
+    for input in inputs::<ConversionInput>(1) {
+        fuzz_checks::check_conversion(&d_trigs, input);
+    }
+
+    // The extremes of i64, exact halves and denominators that only just divide 1000.
+    let numerators = [0, 1, -1, 3, -3, 5, -5, 999, 1001, -1001, i64::MAX, i64::MIN, i64::MAX / 1000, i64::MIN / 1000];
+    let denominators = [1, -1, 2, -2, 6, 1000, -1000, 2000, -2000, 3000, i64::MAX, i64::MIN, i64::MIN + 1];
+
+    for numerator in numerators {
+        for denominator in denominators {
+            for rounding in 0..5 {
+                fuzz_checks::check_conversion(&d_trigs, ConversionInput { numerator, denominator, rounding });
+            }
+        }
+    }
+}
+
+#[test]
+fn test_fuzz_arctangent_tiers() {
+    for d_trig in fuzz_checks::d_trig_for_every_compatibility() {
+        for input in inputs::<TierInput>(2) {
+            fuzz_checks::check_arctangent_tiers(&d_trig, input);
+        }
+
+        // Every thousandth within 100 of each boundary, on both sides and with both signs.
+        for boundary in 0..5 {
+            for offset in -100..=100 {
+                for negative in [false, true] {
+                    let input = TierInput { boundary, offset, negative, anywhere: i32::MIN };
+                    fuzz_checks::check_arctangent_tiers(&d_trig, input);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,213 @@
+/* These tests pin every output of the trig functions over a large fixed set of inputs, so any change in behavior fails
+loudly, even one that stays within the documented accuracy. Each input set is hashed with 64 bit FNV-1a over the
+numerator and denominator of every output, and the hash is compared with the golden value recorded below. Two releases
+of this crate that pass these tests give the same results for every one of these inputs, which is what keeps programs
+running in lockstep compatible.
+
+If a change to the outputs is intended, the failure message gives the new hash to record here. The change should also
+be listed in the changelog, as it breaks lockstep compatibility with earlier releases. The outputs with the results of
+version 0.1 have their own hashes, which must never change. */
+
+use deterministic_trigonometry::{ Compatibility, DTrig, Fraction };
+
+// The golden hash and the number of outputs of every input set.
+struct Goldens {
+    sine_angles: (u64, usize),
+    cosine_angles: (u64, usize),
+    tangent_angles: (u64, usize),
+    arcsine_inputs: (u64, usize),
+    arccosine_inputs: (u64, usize),
+    arctangent_sweep: (u64, usize),
+    sine_awkward: (u64, usize),
+    cosine_awkward: (u64, usize),
+    tangent_awkward: (u64, usize),
+    arctangent_awkward: (u64, usize),
+    arcsine_awkward: (u64, usize),
+    arccosine_awkward: (u64, usize),
+}
+
+// The outputs of this version of the library.
+const CURRENT: Goldens = Goldens {
+    sine_angles: (0x1126362e21ecba16, 18850),
+    cosine_angles: (0x446b83f17b8479eb, 18850),
+    tangent_angles: (0xf3e89184bb7e3289, 18850),
+    arcsine_inputs: (0xc953e3332ca00e89, 2001),
+    arccosine_inputs: (0x6e26c6ff3fd75c29, 2001),
+    arctangent_sweep: (0x3d95dad8bb56542a, 357201),
+    sine_awkward: (0x0a24276565547075, 182),
+    cosine_awkward: (0xbfba61a2c6afac2f, 182),
+    tangent_awkward: (0xee425a6a13a7077b, 182),
+    arctangent_awkward: (0x204b7a14a88fd007, 182),
+    arcsine_awkward: (0x8c73a40b00e4d775, 98),
+    arccosine_awkward: (0x5201fe524b016ac0, 98),
+};
+
+// The outputs of version 0.1, which Compatibility::Version0_1 keeps.
+const VERSION_0_1: Goldens = Goldens {
+    sine_angles: (0x1126362e21ecba16, 18850),
+    cosine_angles: (0x446b83f17b8479eb, 18850),
+    tangent_angles: (0xf3e89184bb7e3289, 18850),
+    arcsine_inputs: (0xc953e3332ca00e89, 2001),
+    arccosine_inputs: (0x6e26c6ff3fd75c29, 2001),
+    arctangent_sweep: (0xd68542dc168e2250, 357201),
+    sine_awkward: (0x0a24276565547075, 182),
+    cosine_awkward: (0x7ad0c4ac8547a018, 182),
+    tangent_awkward: (0x35ad67a4f9a419c4, 182),
+    arctangent_awkward: (0x204b7a14a88fd007, 182),
+    arcsine_awkward: (0x8c73a40b00e4d775, 98),
+    arccosine_awkward: (0x5201fe524b016ac0, 98),
+};
+
+// Every compatibility setting along with its golden hashes.
+fn versions() -> [(DTrig, &'static Goldens); 2] {
+    [
+        (DTrig::initialize(), &CURRENT),
+        (DTrig::initialize().with_compatibility(Compatibility::Version0_1), &VERSION_0_1),
+    ]
+}
+
+// A 64 bit FNV-1a hash of a list of outputs.
+struct GoldenHash {
+    hash: u64,
+    count: usize,
+}
+
+impl GoldenHash {
+    fn new() -> Self {
+        GoldenHash { hash: 0xcbf29ce484222325, count: 0 }
+    }
+
+    fn add(&mut self, output: Fraction) {
+        for byte in output.0.to_le_bytes().into_iter().chain(output.1.to_le_bytes()) {
+            self.hash ^= u64::from(byte);
+            self.hash = self.hash.wrapping_mul(0x100000001b3);
+        }
+        self.count += 1;
+    }
+
+    fn check(&self, name: &str, golden: (u64, usize)) {
+        assert!(
+            (self.hash, self.count) == golden,
+            "The {} outputs changed. They now hash to ({:#018x}, {}) in place of ({:#018x}, {}).",
+            name,
+            self.hash,
+            self.count,
+            golden.0,
+            golden.1
+        );
+    }
+}
+
+// Hashes a function over a list of inputs and checks the hash.
+fn check_outputs(
+    name: &str,
+    d_trig: &DTrig,
+    golden: (u64, usize),
+    inputs: &[(i32, i32)],
+    function: impl Fn(&DTrig, (i32, i32)) -> Fraction
+) {
+    let mut hash = GoldenHash::new();
+    for input in inputs {
+        hash.add(function(d_trig, *input));
+    }
+    hash.check(&format!("{} ({:?})", name, d_trig.compatibility()), golden);
+}
+
+// Every thousandth of a radian over three turns, starting one turn below zero.
+fn angles() -> Vec<(i32, i32)> {
+    (-6283..=12566).map(|numerator| (numerator, 1000)).collect()
+}
+
+// Every arcsine and arccosine input in thousandths.
+fn unit_inputs() -> Vec<(i32, i32)> {
+    (-1000..=1000).map(|numerator| (numerator, 1000)).collect()
+}
+
+/* Every thousandth up to 25, which covers the thousandths and hundredths tiers of the arctangent completely, every
+seventh thousandth beyond that up to 1100, which crosses the tenths and ones tiers and runs past the end of the tables,
+and every input within 3/1000 of a tier boundary. All of these are taken with both signs. */
+fn arctangent_inputs() -> Vec<(i32, i32)> {
+    let mut numerators: Vec<i32> = (-25000..=25000).collect();
+
+    for numerator in (25001..=1100000).step_by(7) {
+        numerators.push(numerator);
+        numerators.push(-numerator);
+    }
+
+    for boundary in [4000, 20000, 100000, 1000000] {
+        for numerator in boundary - 3..=boundary + 3 {
+            numerators.push(numerator);
+            numerators.push(-numerator);
+        }
+    }
+
+    numerators.into_iter().map(|numerator| (numerator, 1000)).collect()
+}
+
+// Fractions that are awkward to reduce to thousandths: odd and prime denominators, negative denominators, and the
+// extremes of i32.
+fn awkward_inputs() -> Vec<(i32, i32)> {
+    let numerators = [0, 1, -1, 2, 7, -22, 355, -355, 1571, 104729, -104729, i32::MAX, i32::MIN, i32::MIN + 1];
+    let denominators = [1, -1, 3, -3, 7, 113, 999, 1001, -1001, 65537, i32::MAX, i32::MIN, i32::MIN + 1];
+
+    let mut inputs = Vec::new();
+    for numerator in numerators {
+        for denominator in denominators {
+            inputs.push((numerator, denominator));
+        }
+    }
+    inputs
+}
+
+// Only the awkward fractions between -1 and 1, which the arcsine and arccosine accept.
+fn awkward_unit_inputs() -> Vec<(i32, i32)> {
+    awkward_inputs()
+        .into_iter()
+        .filter(|(numerator, denominator)| i64::from(*numerator).abs() <= i64::from(*denominator).abs())
+        .collect()
+}
+
+#[test]
+fn test_golden_sine_cosine_tangent() {
+    let angles = angles();
+
+    for (d_trig, goldens) in versions() {
+        check_outputs("sine", &d_trig, goldens.sine_angles, &angles, DTrig::sine);
+        check_outputs("cosine", &d_trig, goldens.cosine_angles, &angles, DTrig::cosine);
+        check_outputs("tangent", &d_trig, goldens.tangent_angles, &angles, DTrig::tangent);
+    }
+}
+
+#[test]
+fn test_golden_arcsine_arccosine() {
+    let inputs = unit_inputs();
+
+    for (d_trig, goldens) in versions() {
+        check_outputs("arcsine", &d_trig, goldens.arcsine_inputs, &inputs, DTrig::arcsine);
+        check_outputs("arccosine", &d_trig, goldens.arccosine_inputs, &inputs, DTrig::arccosine);
+    }
+}
+
+#[test]
+fn test_golden_arctangent() {
+    let inputs = arctangent_inputs();
+
+    for (d_trig, goldens) in versions() {
+        check_outputs("arctangent", &d_trig, goldens.arctangent_sweep, &inputs, DTrig::arctangent);
+    }
+}
+
+#[test]
+fn test_golden_awkward_fractions() {
+    let inputs = awkward_inputs();
+    let unit_inputs = awkward_unit_inputs();
+
+    for (d_trig, goldens) in versions() {
+        check_outputs("awkward sine", &d_trig, goldens.sine_awkward, &inputs, DTrig::sine);
+        check_outputs("awkward cosine", &d_trig, goldens.cosine_awkward, &inputs, DTrig::cosine);
+        check_outputs("awkward tangent", &d_trig, goldens.tangent_awkward, &inputs, DTrig::tangent);
+        check_outputs("awkward arctangent", &d_trig, goldens.arctangent_awkward, &inputs, DTrig::arctangent);
+        check_outputs("awkward arcsine", &d_trig, goldens.arcsine_awkward, &unit_inputs, DTrig::arcsine);
+        check_outputs("awkward arccosine", &d_trig, goldens.arccosine_awkward, &unit_inputs, DTrig::arccosine);
+    }
+}
@@ -1,12 +1,12 @@
-use deterministic_trigonometry::DTrig;
+use deterministic_trigonometry::{ DRng, DTrig };
 
 #[test]
 fn main() {
     // Assigns 10,000 random numbers between -100,000 and 100,000
-    let random_array_1 = random_array_1();
+    let random_array_1 = random_array(1);
 
     // Assigns 10,000 random numbers between -100,000 and 100,000
-    let random_array_2 = random_array_2();
+    let random_array_2 = random_array(2);
 
     let d_trig = DTrig::initialize();
 
@@ -257,1570 +257,16 @@ fn test_all_functions(numerator: i32, denominator: i32, d_trig: &DTrig) {
     assert!(test == true);
 }
 
-fn random_array_1() -> [i32; 10000] {
-    return [
-        8923, 91750, 605, -68668, 97699, 29935, -66713, -39775, 53832, 7895, 74098, -77044, -74520, 37135,
-        34322, -37124, 23092, 28608, -48531, -99352, 40755, 82624, 3822, 55985, 18778, -2047,
-        52702, -35875, 12418, -94292, 76534, 49648, -70531, 54128, -93940, 87198, -23824, -89023,
-        -77919, 65182, -63407, -19773, 19719, 65411, 3419, 98485, 13050, -6207, 42316, 24442,
-        79193, 79185, 25819, 62479, 59394, -26958, -24100, 42946, 47703, -36440, 18778, 1279,
-        32444, 31609, -66195, -70644, 50274, 92380, 29319, -58891, 60012, 13321, -66167, 63592,
-        1300, 65268, 14061, -26998, 28473, 44569, 75482, 55166, 6784, 32637, -64661, -52588, 56282, 52334,
-        -45681, -28425, -77928, 87398, 61966, 91458, 34750, 5998, 93782, 8822, 1285, 70236, -1134, 20734,
-        -61163, 62785, 5979, 22929, 34653, -98085, 7100, 37373, 86385, 88848, 98878, 46922, 97131, 9387,
-        -76827, 14224, 46161, 31150, 61547, 61608, 14375, 69857, 41082, 60377, -3121, 22958, 58847, -54050,
-        -9852, 97188, -36702, 21312, -59883, -25471, 66191, 2107, -83653, 71019, 35989, -16880,
-        99192, -81395, 41017, 46448, 88030, -46373, 13835, 13274, 80676, -92737, 37929, 84357,
-        -6736, 38446, -72147, 77017, 77908, 83727, 54591, -56992, 40692, 45394, -87642, 81118,
-        86382, -91021, 62341, -96199, 29599, -50719, 82507, 68786, 64374, 4329, 37147, 29235,
-        36145, 1447, 79255, 62669, 34765, 54161, 48969, 34671, 24618, 24178, -69645, -30720, 15704, 11256,
-        45225, 59789, -48440, 7963, 29539, -11113, -93481, 33467, 30951, 79944, 31652, -44575,
-        72138, 37239, 26517, 36806, 10183, 78912, -71468, 33862, 76211, 54060, -66060, 22054,
-        -59872, -9875, 56503, 9748, 94597, 19110, -91835, -81956, 36814, 18180, 37255, 77418,
-        73744, 38114, 56643, 7060, 73182, 83210, 47122, 47315, 70903, -68883, -46618, 86294, 73129, 57454,
-        -17620, 69043, 34136, 75275, -10242, -70319, 44476, 95228, 80277, 96401, 14378, -21752,
-        91680, 73255, 52184, 62519, 73662, -66856, 36687, 39025, -40952, 16274, 16785, 34269,
-        -8731, -18414, 5975, 52297, 81821, 38486, -29806, 19874, 12911, -94071, 86461, -73688,
-        85671, 29404, -31785, 86574, 6516, -81705, 34509, -2442, 46438, 87886, 6699, 71089, -48395, 70040,
-        15873, 62989, -60564, -8672, 43199, 54674, 44709, 43809, 67059, -13043, -48921, -12082,
-        47145, -84323, 28892, 68892, 87138, 54214, 53878, -39974, 87812, -32134, 87223, 32933,
-        18759, 94908, -16882, 68979, 81726, 74465, 37106, 85623, 75341, 74765, 18327, -11111,
-        92120, 86748, 29901, 20780, 57787, 97962, 46075, 2767, 64872, -89906, 28302, 40174, 25558, 482,
-        34751, 78962, 23352, 87907, -64165, 66517, 59026, 58435, 85335, -38390, 94170, 65770, 2683, -27799,
-        91285, 37895, -33708, -34372, 9951, 4835, 70547, 27778, 59247, 70136, 60767, 8457, 44290, 78463,
-        60066, 30687, 91076, 77978, 38622, 86289, 17981, 36049, 18250, 35032, 70013, 65057, 80046, -43953,
-        92454, 91766, 50021, 87334, -69611, -98370, -2010, 27459, 76868, 85083, 77816, -669,
-        -78229, 77094, 43554, -63598, 94189, 47620, 50508, 94517, 7714, -29039, 99142, 15861,
-        15445, -34344, 99706, 45590, 77265, -20091, -84247, 32760, -73108, 93147, 27366, 13491,
-        -17941, 3284, 82054, 70607, 79248, 22936, 55652, 60902, 64356, 69834, 43601, 65840, 95632, 79415,
-        -68560, 71751, -22080, 45529, 98288, 59916, 52627, -56148, 66096, 29015, 40327, -84476,
-        14372, -66391, 29546, 63238, 17490, 31337, 38063, 98861, 93984, 5400, 66794, 7696, 12031, 34031,
-        6704, -99927, -53970, 80117, 52437, -5797, 57382, 17208, 79977, 35349, 72907, 78329, 17445, 4736,
-        84370, -56689, -43173, -61428, 79948, 48761, 78488, 7118, 89431, 31001, 67466, 62145,
-        62395, 68597, 15649, 38228, 23002, -17130, 35179, 83575, 13929, 7683, -46530, 78925, 78835, 80167,
-        -75423, 6407, -68414, 18708, 22986, -31595, 75050, -88824, -31073, 42370, -96939, -95920,
-        76130, 94247, -60436, 73245, 29865, 93531, 77410, 18482, 21078, 15134, -99400, 24607,
-        -40732, 63656, 12026, 77440, -89409, 15441, 1464, 6393, -69408, 48308, 18294, 19993, 8252, -33291,
-        -54852, 91008, -78542, -99215, 27005, 52591, 99641, 40219, 86388, -96720, 71479, 82776,
-        -53837, -84813, -71748, 36802, 39027, 33272, -43411, 4541, 17995, 11964, 33849, -46478,
-        39073, 73180, 67622, 63742, -21836, 96758, 79187, 5158, 18927, 34844, 61533, 13101, -54889, 40825,
-        -27137, 64229, 6827, 87430, 48652, 92455, -23645, 60691, 39717, 74080, 65628, 831, 31962, -65926,
-        55992, 338, -77494, 28977, -83392, 53680, 37403, 50853, 27479, 43045, -1582, 779, 61037, 27453,
-        39675, 26057, 52362, 43373, 33676, -50734, 53849, 66016, 7065, 55991, -61380, 39466, 83289, 49844,
-        -1609, -57658, -45507, 60731, 48317, 46603, 42875, 28895, -5514, -28551, -75835, 23947,
-        49362, 74466, 85888, -31763, 37387, -8051, -98564, 15628, 96529, -77468, 78378, 61897,
-        98170, 24766, 95294, 38540, 86522, 69228, 98844, 41684, 6035, 27106, 4446, 44734, 24895, 61604,
-        -12534, -21393, 511, 12521, 56386, 92674, 64913, -27697, 990, -78521, 60861, -2138, -7738, 30547,
-        66965, -11328, 82937, 91758, -80936, 83118, 92289, 12530, 96793, 88492, 42657, -87664,
-        -36828, -73461, 86981, 38639, -95038, 18603, 1663, 72624, 53139, -4701, 49961, 23881,
-        28904, -48067, -35418, -27967, 52158, 98586, 66437, -51990, 50119, -12338, 9145, 80941,
-        12724, 99740, 99630, 27076, 91046, 2781, 26633, -4591, 58830, 61531, -41133, 29400, 25486, 3772,
-        -17732, 51109, 33360, 30390, 92453, -17471, 80366, 91903, 33727, 74509, 48030, 47166,
-        48389, 86304, 70560, 15195, 98687, -76087, 16441, 95399, -85885, -53757, -98910, 70358,
-        -79246, -77684, 94242, 88938, 59428, -34811, 17151, 90944, 4860, 51245, 77537, -57475,
-        5066, -99724, 48858, -12195, 7792, 36443, 78362, 65540, 16581, -1515, 37221, 86081, 56213, 47148,
-        39953, 68199, 42203, -14054, -75806, 61176, -92656, 73590, 61443, -84163, -4543, -78754,
-        73897, 67653, 1509, 73122, 99445, 83583, 56704, 97464, 52438, 53362, -64441, 63116, -90457, -13747,
-        88412, 30185, 32588, 36944, -39523, 57291, 23182, 90933, 79850, 85481, -35160, 29881,
-        -25239, 80569, 3114, 9447, -36371, 31069, 67019, -78977, 84184, 63742, -53254, -97638,
-        15211, -55689, 12412, 78305, -65424, 20598, 89103, 96143, -17575, 19119, 37050, 13102,
-        6282, 3028, 95739, -74377, 11836, 60996, -78800, 28531, -52680, -66197, 99509, 69545,
-        -9395, -56320, 61223, 88381, 17635, 56897, 54924, 93163, 80432, 61863, 27391, -19035,
-        -46462, 67072, 841, -97188, 57470, 43633, 30739, -38246, 76530, 31338, 4503, 60009, 76604, -96014,
-        83766, 70386, 80266, 13762, 99393, -35476, -46270, -63596, 80693, 29287, 42036, 46712,
-        58988, -62246, 15699, 54604, -27574, -51493, 19170, 11064, 93865, 94680, 89941, 64222,
-        55720, -54014, 23492, 31748, 11306, -38109, 60659, -79090, 22294, 77574, 77678, 21975,
-        46874, 76612, 53154, 70563, -82308, 55193, 58436, 98215, 87769, 29730, 36209, 30046, 79232, 24648,
-        -32522, 54104, 63495, -68409, 72343, -71911, 72638, 43147, 8765, 95752, 88862, 34681,
-        78807, 59967, 91338, -95890, -4228, 66069, 37709, -24900, 38482, 83269, 51189, 56669,
-        70584, -42951, 58247, 14948, 98791, 54154, 11218, 6062, 27255, 705, 41666, 11525, 37265, -12902,
-        15532, 68115, -87872, -43385, 17632, -37710, -51274, -56911, 27375, 46209, 97287, -38385,
-        70722, 98323, 12524, 80912, 19207, 67302, 89424, 98466, 88047, 47094, 75024, -38968,
-        -83808, 52317, 23053, 1432, 45682, 37108, 18118, -53402, -40576, 73222, 46057, 42035,
-        49151, 9539, 60215, 93741, -29050, 30479, 50253, 94111, 72033, 52052, 26993, 19101, -63921, -65880,
-        58486, 2687, -15255, 46690, 39935, 30227, 76870, -51100, -93040, 63027, 30974, 82310,
-        -48730, 35565, 4513, 74812, 72471, 44237, -36679, 98077, 16257, -75884, 69412, 33769,
-        -33704, 18725, 35172, 79520, 68539, 89378, 62946, -91046, 11918, 84509, 97852, -34002,
-        19910, 55420, 7721, 73848, 54239, -86634, 71093, 216, -15330, 68273, 34480, -60872, 97377, -21617,
-        70247, 306, 42300, 39225, 76445, 8567, 67478, -69277, 40372, 59097, 23137, -81065, 80226, 84957,
-        -8100, -41097, 1623, 50674, 49100, 88649, 90046, 69409, 73409, -36806, -68878, 74130,
-        27022, 52510, 62352, 17617, -71609, 87743, -4054, 77853, 27728, -42379, 94272, 81779,
-        92564, 29805, -72413, 62341, 22514, 1003, 29018, 30136, 38682, 59206, 64347, 5191, 2415, 47222,
-        90098, 98662, 23726, 80931, 47101, 83148, 71181, 18078, 39802, -77132, 75180, -33297,
-        59367, -88360, 70127, 42763, -76588, 79074, 38815, 51921, 21956, 33529, 13346, 66598,
-        -90199, -77948, 21045, 12950, 42943, 16408, 10637, 10584, 17832, 58086, 68549, 56253,
-        22773, 54255, 86196, 35993, -402, -62399, 63718, 57866, 55137, 70498, 6061, -44909, 69520, 16299,
-        14304, -87348, -2976, 33261, 1653, 38850, 17994, 80342, 60290, 80044, 37213, -37150, 87239, 21061,
-        -64061, 88179, 70623, 92003, 81235, 93886, 58253, 28858, -66611, 18729, 73468, 75336,
-        -27540, -53755, 14874, 48697, 62421, -87394, 32281, 82606, -57140, 55278, 38235, -66551,
-        60769, -82621, 94810, -42829, -75454, -87653, 64325, 59790, 38055, 25202, 4358, -95833,
-        34880, 14991, -84104, -15084, -99049, -86288, 11624, 33070, 17708, 38376, 30866, -9903,
-        94392, 93516, 59833, 31634, -56421, 56293, 41788, 12389, 91104, 40189, -28551, 72616,
-        -43145, 79076, 6728, 11888, 70093, -70574, -18076, -78097, 61653, 48993, 10995, -10161,
-        98404, 28900, 44581, 76476, -2205, 83786, -10873, 33958, -73574, 95646, 56738, 21300,
-        57623, 7834, 54518, -91655, 24209, 68416, 63245, -39482, 551, 35991, 56236, 6476, 20618, 20602,
-        45879, -46268, -28529, 57923, 73471, 1366, 52576, -87568, 55499, 58780, 66573, 56806, 9230, 4239,
-        86534, 95464, 54217, -12082, 28875, 34936, -81785, 19481, -630, 29741, 1010, 55510, 42188, 42367,
-        86241, 48364, 64254, 23438, -37863, 71964, -57947, 3175, 53327, 86430, 52433, 79232,
-        -25969, -26918, 48085, 774, 63613, 2217, -48803, -80464, 52314, 43935, 65326, 32936, 50523, 89130,
-        85436, 94654, 39847, -39180, -39065, -1896, 57119, 2913, 63004, 17648, 80002, 49742, 80787, 17153,
-        5766, 20191, 69670, -53930, 95405, -80712, 15606, 21109, -26176, 20836, 82786, 55212,
-        -15671, 10204, 80442, 5646, 44297, -72395, 13060, 15375, 62560, 61625, 42538, -27477,
-        20364, 8599, -91547, 76911, 35859, 13687, -47239, 42549, 8244, 6954, -69312, 85969, 79878, -89844,
-        60009, 71684, -76315, 25536, 15158, -94084, 9205, 19141, 62367, 4619, 62139, -24450, 61120, 41,
-        90467, 35770, 35438, 63663, 8010, 8343, 63214, 88769, 13709, 25617, 54664, 51129, 91128, 19136,
-        37926, 84705, 42079, -91581, 83646, 95771, 99453, 89378, 7224, 34783, -98845, -63118, 3144, 85867,
-        43579, 50635, 38449, 42924, 1538, 41721, 98, 81757, 42299, 48738, 90310, 29856, 78181, 70742,
-        97385, 32201, 57284, -66367, 78437, 19019, -53240, 76917, 54680, 94479, 1243, 37179, 6458, -18121,
-        65076, 64272, 81845, 76591, 77862, 63533, 29214, -38233, 20262, 88384, 43534, -32235,
-        -88546, 28647, 23469, 2280, -93898, -42459, 32090, 78568, -80600, 48429, 62550, -96419,
-        56590, 94140, 85493, 15606, 74640, -15525, -4857, 67168, 47593, -36447, -239, 76514, 88696, 44692,
-        -94260, -67444, -19236, 83881, 49124, 6055, 8428, -11084, 17873, 59395, -91449, 93998,
-        -19349, 94350, 84414, 79114, 66525, -45621, 71868, 36721, 23809, 83481, -63652, 71907,
-        48333, -62396, 48120, -22046, -54591, -90090, 20290, 73329, -89669, -98443, 82988, 40209,
-        -80551, -58304, 91156, -71236, 51444, 56722, 95697, 64649, -32925, -19255, 22422, -95935,
-        -95719, 9116, 33592, 57760, -75584, 57447, 16582, 68985, 96539, -73723, 40759, 7237, 2334, -90101,
-        65409, 98532, 28752, 66897, 30359, 10660, 90710, 85311, 93082, -94893, 34292, -90873,
-        71382, 17260, 6214, 44795, 90569, 1319, -29777, 71768, 89744, 32140, 58431, -57163, 88363, -96560,
-        58179, 41275, 54231, 65823, 69312, 36202, 36338, 9169, 38382, -2273, 94495, -41176, -22417, 93346,
-        15089, 3031, 28074, 79381, 19425, 74880, 84809, 71822, 10579, -64287, 98772, 24153, -37386, -8085,
-        78420, -56664, 14286, 26985, 16340, 70549, 53231, 54673, 42645, 69728, 55077, 98023, 20194, 66569,
-        99646, 44605, 89989, 81982, 55116, -85620, 15821, 75446, 86192, 96915, 96491, 56944,
-        -73032, 19457, -10267, 89837, 62541, 62918, -35335, 16303, 19341, 39293, 99360, 54294,
-        93700, 33898, -65110, 75484, 53599, 33499, 31092, 39564, 90853, 53168, -5181, -31632,
-        -1491, -87693, 14299, 3772, 39454, -83686, 15375, -34952, 89792, 49508, 21060, 55951,
-        58057, 79649, 54765, 35616, -40314, 87673, 56130, 5437, 95924, 20415, 4857, 17834, -95423, 44837,
-        84139, 79452, 46334, 81294, 98188, 52852, -6662, -34780, -22992, -70467, 31682, 68063,
-        57349, -891, 77434, 32342, 83913, -70776, -73198, 95770, 18159, 70340, 28231, 91676, 74429, -99319,
-        28030, 8605, 31431, 29282, 71240, 7567, 43460, 770, 83810, 37667, 10090, 18253, 99252, 71478,
-        19679, 23279, -79141, 46867, 46264, 23456, 21016, 45174, 25369, 14180, -8421, 63789, 91972, 40101,
-        42217, 55910, 99555, -63335, 93763, 77707, 95404, -63115, -65329, 65463, 22160, 40955,
-        20492, 60419, 67284, -69172, 21180, 43441, 22067, -33064, 27123, 79322, -56562, 17688,
-        22229, -19520, -69823, 93118, 37938, 29954, 52880, 31586, 68050, 25180, 18638, 73652,
-        -21326, 30158, 23373, 42692, -91720, 57313, 89267, 50168, 85166, 18402, 17820, -36409,
-        57393, 58818, -61321, 82589, -84702, 78390, 39569, 30383, 31145, 96249, -3183, 68697, 5421, 99950,
-        60030, 88634, 87157, 87633, 50546, 1750, -81657, 27020, 86819, -25879, 17103, -68470,
-        -85960, 71487, 55825, -48490, 78164, 64769, -96893, -72102, 21387, -75556, 52320, 14165,
-        95419, 78278, 30615, -84027, -27098, 23257, 61035, 801, -576, 28105, 95686, -30333, -68689, -95610,
-        80880, 197, 38732, 26680, 54021, 79571, 93609, 8876, 44301, 98993, -22490, 60091, -66066, 44015,
-        48068, -31682, 39279, 75512, 90096, 59845, 6976, 3855, 76443, 43445, 80080, 50965, -17240, -51839,
-        60046, 57975, -59923, 73046, 77251, 96113, 87669, 46738, -32496, -77046, -89453, 34001,
-        -87869, 3492, 44166, 97194, -27474, 37170, 88469, 56847, 90298, -77934, 512, 70175, -97298, -95813,
-        59978, 48800, 85189, 1762, 25453, 32507, -63216, 81882, 27312, 79076, 63545, -55325,
-        -97428, 85670, 68094, -56731, 28370, 84744, 94387, 73766, 95014, -80227, 48572, 73917,
-        -70139, 98574, -9043, -40932, 5877, -85419, -96291, 62759, 21614, 63068, 35955, 4927,
-        45109, 69000, -14471, 37811, 17634, -70819, -57265, 4397, 38397, 45074, 417, 80282, 43773, -66784,
-        38340, 18095, 23267, 91086, 71963, 78819, 69644, 41177, 82701, -66850, 47794, 93879, 31621, 52237,
-        -95640, 12590, -57029, 78768, 56639, 39212, -2894, 15452, -9959, -78131, 8716, -33681,
-        80478, -34757, 30259, -47526, -16076, 58932, 46642, 79742, 90683, -74842, -82760, 5757,
-        13353, 79753, 1967, -25150, 45701, 72029, 21297, 36672, 23116, 9943, 72230, -74663, 34493, 51337,
-        90041, -21065, 90201, 91158, -97386, -55415, 55222, 61413, 18214, 59084, -31745, -73781,
-        22804, 76818, 64373, 79482, 17138, 21498, 89868, 93686, 85362, -40983, -5097, 48331, 43732, 31291,
-        79877, 33972, 97947, 66555, 29276, 49131, 78682, -7581, 93300, -98406, -74659, -84556,
-        38449, -70969, -43726, 86249, 78790, 14843, 45558, 64740, 57276, 87321, 70819, 92597,
-        87739, 65740, 37899, 71743, -52027, 98779, 75187, 11682, 23609, -91821, 26114, 97634,
-        -83755, 5832, -22678, -53554, 15102, -43030, -66477, 64746, 38233, 83598, 74778, -36536,
-        27307, 23191, -6083, -97767, -76601, 60713, 69587, 25343, 87654, 34563, -53840, 46489,
-        -26718, 60234, -90807, 12495, 22608, -9404, 44770, 96662, -85930, -88134, 52153, -54969,
-        87188, 90660, -5679, -49403, -58056, -5370, 11229, 30904, -34975, 99487, 44817, 34175,
-        30927, 11201, 65792, 22952, 96331, 48195, 34668, 52472, 46762, -58379, 34970, 19616, 30764, 95798,
-        -45379, 48472, 16064, 23502, 43986, -70084, -82438, 50281, 58474, 94010, -24840, 46684,
-        95872, 30227, 48088, 59087, 1094, 47323, 77828, 3126, -88504, -5574, 93193, 69514, 90965, 91087,
-        45994, 82868, 12550, 55101, 82120, 63631, -78568, 85124, 41549, 59945, 85336, -95408,
-        26161, -81484, 14311, 97767, 42738, -41025, -25189, 90258, -27306, -92985, 40350, 93683,
-        48110, 34566, 70452, 42342, -68893, 40263, -45753, 69262, -17536, 18737, 61618, 35147,
-        84483, 4204, -50126, 94892, -64613, 6970, -32119, 98782, 92082, -5849, -42075, 27863,
-        97163, -29713, 35070, 54119, -86976, 19462, 11284, 34387, 83576, 22456, 66713, 62142,
-        86980, -64093, 36156, 12339, 50210, 77910, -19719, 72561, 21201, 35997, -53204, 43699,
-        72195, -46035, -48472, 75857, 21629, 86847, 29214, 58466, 26601, 21020, 2882, -1347, 27884, 3845,
-        45402, 55921, 46874, 48305, 90042, 49830, 51443, 24494, 58205, 85632, 54099, 81617, -95608, 35128,
-        60697, -97474, 32179, 9250, -37206, -68678, 62490, 84159, 36727, -9446, -68535, 43660,
-        -69763, 68776, 59706, -82014, 79073, 44485, 4898, 32109, 76116, 33553, 3878, 27081, -58588, 80776,
-        13751, 25198, 50188, 61697, 83517, 62006, -41567, -72083, 25828, 18156, 59366, 70971,
-        87583, 89481, 81315, 60746, 90930, 48419, 16915, 39811, 4180, 79710, -55327, 90799, 29557, 39166,
-        332, 78275, 38247, -94666, 52980, 74941, 22573, 77293, 35275, 12556, 52463, 84393, 80021, -47052,
-        66263, 6064, -97923, 53918, 6711, 7861, 13734, -14216, -24823, 65375, 70399, 2690, 39662, 93272,
-        29791, 80957, 51765, 62334, 96977, 91930, 22937, 31453, 24394, -72839, 78935, 84264, 43942, -25408,
-        -48862, 26684, 411, 3107, 13400, 55250, 82233, 13454, 60161, -77000, 21424, 90159, -82164, 28481,
-        25686, 3099, 64935, 62329, 71172, -41632, 16750, -69276, 52620, 46652, 18217, 86961,
-        -21696, 2335, 79214, 66146, -60908, 78295, 29488, -98982, -11803, 49014, -55135, 27781,
-        3344, 52731, 61478, 22467, -15545, 40056, -1037, -27876, -1182, 75873, 5916, -96275, 58663, -9097,
-        66720, -44509, 74908, 6065, 87335, -24590, 31886, 5509, 76882, 78194, 73097, -95336, 93886, 24540,
-        -36446, -71185, -93045, 69374, 71021, 48639, 33149, 16501, 54806, 71692, 57224, 77304,
-        42277, -8359, -84853, -37870, 44942, 26474, -93814, -71724, 25035, 97218, 48227, 4698,
-        -48776, 66272, 86992, -79507, -51501, 99061, 2757, 17727, 90595, 20020, 76558, 53691,
-        58909, -43171, 60469, 25156, 3161, 40973, 22853, -55125, -74876, -60836, 1456, -12345,
-        98369, 48795, 46344, 29593, -4709, 49373, 75257, 79482, -6450, 2401, 7265, 43572, -37252, 27401,
-        98659, 6991, -91232, 96446, 15693, 71782, 42043, -34616, 45923, 69852, 26857, 60451, 5191, 96224,
-        -4583, -85393, -5671, -2247, -63890, -72399, 93068, -50, 74222, -5440, 6563, 55233, 54701, 9684,
-        44584, -53547, 51657, -37724, 42019, 4525, 70237, -52540, 60452, 39267, 61974, 14663,
-        -88232, 79012, 86355, -934, -40718, -71151, 32989, 77098, -4726, 52962, 61307, 26334,
-        91390, 18193, 61633, 79314, 79190, 42542, 2037, 16668, 91191, 81982, 98600, 96662, 3328, 45807,
-        -18025, -84084, 57362, 86523, 15553, -17556, -6071, 41193, -22419, 25224, -39382, 76229,
-        34935, 93075, 10405, 53153, 85179, 78065, 42918, 69672, 67084, 53241, 47954, 834, -35904, -95707,
-        76220, 88666, 79630, -88711, 80761, 48776, 65850, 82422, -13448, 15336, -89794, -69683,
-        85371, -90984, 68923, 64787, 83468, -49633, 40888, 38777, 70432, 42899, 79482, 64248,
-        35823, 48355, 68578, 20552, -32514, -82541, -1086, 32317, 95960, 8556, -51141, 14337,
-        34947, 36564, 21470, 25407, 93675, 57430, -73919, 70000, 67828, 82907, -51951, -60162,
-        59305, 19014, 12235, -96898, -17152, 79617, 91081, 12570, 11261, -99277, 47291, 99108,
-        55926, 33325, 93222, 86865, -43479, -88957, 27566, 17537, 37577, 51072, -81245, 39047,
-        59459, -61331, 67344, -98075, 25027, 79008, 75556, 66719, 98578, 32207, 66656, -18179,
-        69356, -73624, 29473, 14168, -54454, 82114, -84245, 14219, -14792, 67703, 1148, 39481,
-        -7088, 48847, 3932, 88773, 57151, -89554, 3209, 61053, -1883, 30383, 55505, 78611, -5393, 55393,
-        -93725, 25130, 43786, 94490, 83262, 30321, 80511, 64921, 20672, 96782, -65405, 245, -70876, 7860,
-        7287, 41129, 61907, 16684, -36935, -46882, 8025, -71011, 31996, -63349, 5965, 84350, 89599, -59371,
-        72467, 38998, -81178, 13754, 55085, -7068, 41945, 99992, 46002, 4916, 88495, -41418, 99196, -13689,
-        16509, 38962, 74694, 90476, 40809, 80760, 57048, -60049, 98146, -54230, 65584, 36864,
-        71405, -8304, 77676, -44401, 26938, 77323, 22887, 61374, -28929, 71123, 90413, 7782,
-        -28927, 34544, 2921, 69568, -83046, 71621, 91788, 63172, 95000, 16788, 59882, 9161, -16173, 78096,
-        60345, 47520, 26942, 25058, 73809, -8403, 78920, 55023, 15150, -12974, 47945, 77075,
-        -11764, 76243, 61419, -85547, 63026, 14262, -66094, -2875, 70783, 92153, -72325, -24056,
-        -23178, 88955, 55860, 24148, 71516, 73759, -68741, 7307, 9402, 54551, 75726, 82451, -13717, -52390,
-        26544, 63851, 66752, -35949, 60859, 47703, -3200, 68491, 94721, 95157, 26308, 31346, 72238, 67119,
-        -58828, 68195, 31944, 57105, -40805, 89499, -72747, 85346, 84961, -9838, 89074, 11913,
-        69760, -53891, 37446, 12669, 47167, 5944, 34677, 7721, -86285, 17617, -6242, -47816, 81982, 84885,
-        -35074, 88478, 53428, 52513, 26428, 76201, 17453, 840, -63051, -74478, 48846, 85051,
-        -28139, 34219, 59695, 84823, 6816, 74891, -75439, 92205, -26721, 4632, 54974, 10119, 34831, 44629,
-        66251, -39551, 60990, -31729, 45071, 40785, 1768, 43144, -37650, 45773, 472, 31923, 14743, 29559,
-        93379, 47977, 7408, 65162, 94327, 66830, 32690, -21319, 32717, -33721, 28975, 47627, 19232, 38461,
-        -29831, 64704, 95904, 54400, 26351, 37575, 32265, 17955, 20651, 77493, 93340, -18528,
-        29183, -84830, 46761, -21117, 16930, 55464, 58664, 28437, -15458, -65880, -45697, 83678,
-        -34284, 42108, 12967, 59908, 25161, 83682, -19952, 63428, 99283, 84335, 43279, 72604,
-        -25583, -58022, 92254, 84452, 30892, 15669, 28727, 63748, -45874, 95663, 26538, 83807,
-        85729, 87194, 69388, 60041, 40548, 97506, 80659, 52094, 21825, 71983, 19991, 8270, 79343, -90000,
-        90125, -23072, -46189, 39862, 46616, 65815, 73598, 2349, -38748, -26083, 18768, 62477,
-        55691, -46988, -90075, 28457, 16652, -72308, 5308, 62943, 98205, -51697, 86432, 11395,
-        89133, 45182, 77586, 10066, 489, 96969, 43280, 37651, 49325, 54729, 31399, 50222, -54137, 20198,
-        79006, 62103, 45860, 47273, 70203, 55319, 37095, -62372, 51347, 73555, 75859, 17759, 54926, 48948,
-        95963, 55265, 65353, 64939, 33124, -978, 22059, 96100, -54000, 29687, 39036, 88179, 72080, 20385,
-        1600, 59051, 13956, 3804, 47964, 3143, 44434, 76419, 32749, 9038, 64181, -77353, 51648, 96418,
-        72355, 45402, 80669, 66036, 76066, -55609, 55051, 31105, 87116, -6881, 78696, 10949,
-        -70266, 67264, 37926, 79992, 59225, -17582, 47362, 86165, 52875, -4228, 65976, -85012,
-        98339, 84082, 77897, 61058, 99595, 98290, 98375, 53677, 79084, 6751, -14672, 76736, 56595, 4298,
-        7045, 26260, 8570, -86635, 49094, 2980, 2837, -98509, 35719, 56064, 32747, -87625, -33679, 67042,
-        31553, 45423, 80234, 46014, 27359, 14919, 26318, -84209, 20361, -90531, 7962, 24843, 80273, 91651,
-        -67951, 69326, -22817, 45398, -44810, 88102, 44708, 33891, 25289, 11999, 5604, -99228,
-        74514, 60233, 38740, 36186, 47280, -1550, 7061, 26853, 98271, -65266, -29230, 70429, 34489, 34587,
-        4218, -40685, 62363, 70164, 22127, 2060, 4157, 1260, -40770, 98934, 30662, 83219, 86241, 64384,
-        15257, -65289, 58128, 65636, -80936, 49688, 57040, 67059, 35322, 65222, -74264, -30686,
-        -76082, 48153, 73444, 55328, 70793, -78755, 60308, 54146, -97115, 75744, 82302, 92985,
-        -81890, -39092, 92520, 73478, 58869, 49060, 16211, 15000, 15701, 80727, -68400, 34079,
-        38477, 2682, -98649, 11997, -12855, 9564, 93627, 21462, 16433, -7291, 95334, -57180, 85526, 48432,
-        5280, 90704, 64636, 14692, -59874, -3104, -61889, 46447, 19839, 71756, -5460, 74776, 49849, 43321,
-        68267, -21955, 81103, 82906, 28154, 66839, -14656, 74115, -73172, 80207, -12535, 81783,
-        31541, 48819, 98480, 65284, -56990, 61315, -32266, -76869, 73294, -47743, 83072, 70784,
-        57432, 39741, 47314, 17168, 20190, 76482, 73574, -46788, -37272, 68093, 23384, 66039,
-        55854, 96775, 72625, 45066, 35731, 7444, 86265, 91097, 92255, 55392, 52196, -11307, 67418, -37922,
-        -15148, -85198, 80373, -40191, 49317, -7006, 47699, 44750, 27489, -1001, -32418, 35490,
-        28121, 94121, 43879, 64547, -39279, -31922, 36521, 8709, 44463, -53045, 76959, 60916,
-        -48992, 32879, -87942, 97418, 81822, 98078, -375, 30099, -87922, 19336, 97605, 32470,
-        94776, 62932, 15542, 52980, 2788, 38181, 16505, 40734, 6235, 65563, 78886, 76181, 36666, 2559,
-        12542, 51213, 99738, 70530, 94426, -67256, 97781, 44369, 74261, 54914, 63386, 45945, 98166, 39435,
-        74634, -31588, 28167, 26500, 87802, 62630, -5774, 53481, 10873, 34277, -93918, -99477,
-        45650, 65175, -58805, -31517, -41261, -73091, 57367, -58860, -71880, -50140, 54226, 1658,
-        73460, 54360, -62225, 84213, 11527, 70607, 2470, 11260, 21512, -86700, -42098, -81275,
-        91278, 49102, 42403, 88838, -39675, 5121, 87700, 86905, 50779, 34026, 62319, -22720,
-        -31643, -89710, 63452, -52635, -75989, 90531, -95060, 50760, 82996, 22129, 17088, 72017,
-        -65674, 80721, 7667, 81927, 21369, -25853, -41727, 44614, 61489, 50341, -45766, 30419,
-        75773, 71776, 75348, 20521, 87741, 19574, 92684, 73957, 10219, 20126, 8942, 78372, 51602, 10746,
-        30894, 24084, -93461, 16942, 82729, 71691, 41962, 16404, 17008, 96407, 3836, 91564, 82506, 88485,
-        -64244, 30690, 98753, -99290, 11318, 8410, 36106, 94259, 95752, 22429, 98241, 41543,
-        -18354, 60367, 37038, 96759, 54637, 90102, 68257, 49831, 59150, 44074, 43810, 66694,
-        -82569, 21787, 59267, -50813, 68011, 61891, -19491, -44492, 25529, 59403, 14808, -12051,
-        54011, -7469, 16157, 36908, -31593, 70079, 54964, 43137, 67037, 7595, -18669, -17908,
-        49018, 61363, -14537, 71182, -53835, 50142, 41391, 71378, -82311, 13607, 91355, 39139,
-        70422, 65913, 43904, 98964, 46219, 83316, -23739, 22, 52537, 60223, 63445, 91685, 76578, 85316,
-        94232, 60841, 13670, 15153, 68556, 805, -87185, 98365, 44729, 78584, 76733, 28479, 41851, 52464,
-        76580, -11369, 12466, 48666, 37054, 5911, 54475, 6385, 56210, -7614, -83957, -54648, 16236, 37354,
-        96286, 68216, 23284, -46518, 36150, 54741, 54131, 69082, 22302, 51859, 48619, 30682, 12851, 12892,
-        87592, 19749, -46825, 33233, -24293, 97453, 74123, -5685, 39703, 96464, -8060, 82423,
-        38040, -86158, -96636, 33314, 88148, 84210, 8095, -64581, 74642, -55788, 15708, 31749,
-        65579, -95372, 32913, 36131, 84710, 95211, 4316, -84096, -18012, 63984, -92089, 99636,
-        -21690, 81701, -66288, 61133, 75895, -13758, 84137, -35340, 98681, -39416, -97972, 62234,
-        29828, -72408, 60526, 99649, 20595, 2870, -95543, 55308, 34434, 12266, 14950, 16452, 74645, 27827,
-        -72208, -45602, 62516, 50411, -13833, 57076, -15386, 4851, 57777, 87980, 64064, -25083,
-        34105, -24129, 76169, 29825, -37673, -44188, -45474, -51282, 8284, -4754, 54892, -87488,
-        -46607, 51513, -53684, 67134, 25968, 29579, 5555, -76439, 38095, 72673, 84603, 80943,
-        42621, 91107, 64140, -35977, 26486, 95568, 54594, 9391, -24810, -61079, 72044, 64719,
-        17960, 41544, 38986, -85108, 64070, -94046, 21490, 39661, -11008, 30039, -90600, 70307,
-        -82975, 18204, 59586, -20547, 83909, 51135, 19765, 22640, 96000, -75483, 42670, -17541,
-        52177, 29850, -67669, 55014, 58001, 47055, -92377, 71025, -86553, -15906, -89509, 32407,
-        91062, 17464, 37897, 5811, 61898, -96426, 98130, 332, 62920, 48975, 41998, 82402, 33454, 80187,
-        98232, 49128, 13360, 68484, -20205, 47930, 38113, 43841, -44002, 71572, 2883, 61945, 28779, 98437,
-        -71837, 88490, 89659, 67418, 97409, -58524, 45157, 40814, -18270, -22275, 81426, 81966,
-        -31238, 57477, 86242, 74413, -3788, -72370, 58905, -35063, -79471, -8512, 10732, 29153,
-        -53289, -42392, 86219, -76415, 64734, -83267, 54035, 30050, -30837, 41946, 3065, 59219,
-        62548, 65760, 543, 9256, 59251, 8916, 24059, 67281, 84987, 36829, -27979, 16325, 20500, -48553,
-        -66890, -80097, 30144, 118, 8088, -71464, -59084, 11491, 56223, 523, 87340, -41891, 46062, -98665,
-        9712, 27988, -34265, 80184, 98427, 38237, 83939, 96920, 91789, 57279, -71579, 19415, 59875, 23746,
-        25398, 44841, 9214, 36770, 55608, 2450, 93819, 80492, -94189, 1643, -74658, 22506, -54267, -33971,
-        57951, 47352, 80406, 95676, -58392, 43871, -63599, 9572, 69133, 72017, -72345, 51332,
-        95826, 23953, 50982, 55831, 129, 56638, 62993, -46567, 20572, 78776, -68857, -24424, 59233, -71291,
-        70405, 71766, -72045, 18785, -75585, 38688, 38077, -96435, 81310, 4413, 46236, -10886,
-        57327, -66540, 61202, 30310, 86940, -30588, -41342, 9535, 71722, -36555, 68965, 86550,
-        27915, 49413, 10122, -55128, 92781, 78062, -67369, 81278, 48135, 39163, 36805, -64359,
-        -30392, -54554, -2579, 39135, 18553, 29886, 25641, 22062, -14959, 32491, 36200, 14981,
-        92786, 17158, 31295, 43820, 19797, 80627, 40442, 13118, -81719, 10667, 17857, 58425, 48139, 13483,
-        74345, 90944, 78000, 89924, 9065, 89344, -27629, 62910, -73064, 64478, 87082, 7542, 68569, 44937,
-        55945, -41950, 37131, -50676, -85418, 68246, -4261, 58715, 76638, 85530, 26941, 97786,
-        60855, 64928, 81318, 71229, 38099, 59401, 63384, 79774, -31742, 25684, 30899, 59205, 75967, -68747,
-        19904, 9913, 81630, -20668, 36650, 11498, 17703, 33363, 52813, 36877, 30040, -32979,
-        -29388, -28649, -88446, 42509, 25697, 27386, -3165, 33836, 86176, 10340, 17872, 15335,
-        -7004, -20219, 91718, 8228, 19214, 70681, -80770, 14552, 88639, 98792, -39202, -67383,
-        89267, 2196, 88007, 74656, 87873, -14108, 26682, 20416, 20997, 97881, -5934, 62105, 67374, 18528,
-        87262, 54778, 12201, 24281, 79608, 85062, -3971, -12703, -72943, -42186, 25030, -33422,
-        31206, 62845, 69938, 61897, 53583, 71298, 83180, 67403, 57286, -36696, 60726, -54078,
-        16831, 21417, -4872, -60184, 51673, 92172, 93473, 7268, -91104, 19252, -43164, 81041,
-        94152, -41614, 67737, -30294, -3445, 76943, 67957, 77128, 10668, -94762, 74887, -69357,
-        44492, 92448, -88360, 82656, 73912, 63197, 3934, 88395, 80421, 97414, -72548, 29, 633, 56749,
-        55729, -69047, 70336, 48797, -29744, 53693, -77825, -82236, 24700, 70317, -31501, -50519,
-        28783, 13439, 9210, 78223, 91490, 35837, 73255, -65973, 11970, 85616, 52772, 67861, -54907, 37924,
-        -61884, 10005, 47255, -77108, -24632, 48137, -39730, 56701, -72944, 57156, 76902, 91584,
-        36632, 83589, 25029, 90209, -92181, -47987, 38429, -68767, -37158, 44977, 38680, 42826,
-        -89960, 83833, -30992, 37336, -73425, 32433, 68044, 17321, 22888, -58813, -87288, 31717,
-        68048, 19255, 61487, 24225, 20083, 94496, -53879, 18210, -70247, 66072, 19294, -73748,
-        75431, 75759, 65412, 22042, 30947, -71295, -42022, 41313, 9653, 68573, 71253, -50710,
-        66487, 51273, 16122, 69486, 82084, -64432, 90958, -76974, 59974, 1491, 21418, 12077, 74585, 87269,
-        35541, 43776, 89663, 41364, 7286, 67710, -18350, 56852, -75187, -3162, 41620, 79477,
-        -85502, 64907, -89327, 92606, 49366, 64201, 61451, 52226, 10803, 36844, 98925, -47274,
-        46589, 91679, 22478, 22349, 91453, 69247, 57919, 70140, -41862, -97999, 79716, 84989,
-        72826, 66102, -37044, 59484, 50062, 77854, -42180, 7899, 21471, 27060, 34484, 23820, 28109, -82897,
-        61837, 16753, 36291, -32211, 18155, 69484, 42261, -84515, 60032, 1359, 18617, 19602,
-        -60650, 98133, -24945, -38197, 29299, 50725, 5610, 7530, 24021, 71229, 99935, 64829, 16006, -89641,
-        69307, 6383, -14664, -18459, 80590, 14418, 99992, 86343, 43559, 21222, -51663, 75799,
-        50730, -86641, 80008, 64516, 90936, 79939, 85438, 15400, -17852, -97545, 4174, -35993,
-        -93340, 78185, 82199, 30971, 51655, 66519, 43286, 22247, 97544, 12870, 84165, 55107, 6213, 56157,
-        65592, 92808, 49719, -79546, 52158, 58837, 87458, -96169, -82534, 33214, -31504, 73518,
-        99242, 81716, 91301, -75417, 4051, 69470, 29070, 43575, -39443, 9261, 53207, -91157,
-        -45469, 92030, 60865, 15683, -46708, 71036, 158, 14531, -47753, -59927, 81850, 63890,
-        29053, -85459, 14615, 44899, 33268, 2257, -48827, 18601, -84941, -88571, 93632, 10850,
-        69141, 51059, 14771, 4817, 33918, 50720, 96478, 76181, 81911, 63551, -27794, -59145, 40104, 70758,
-        21127, 18077, 49905, 69059, 56379, 12601, -36600, 28549, 4292, 60027, 63681, 94065, -19705, 29588,
-        -77406, -82804, 38659, 43925, -8685, 41863, -55729, 15709, 36579, -12990, -2967, 88704,
-        97495, 9740, 52295, -97694, 90819, 1343, -64374, 51224, 13097, -82864, -69081, 62275,
-        19107, 18393, 67933, 74907, 66886, 13976, 73608, 5278, 63950, 34569, 7688, -73584, 9186, 26626,
-        44439, 84980, 58434, -38480, 18484, 11258, 52499, -33868, 15982, 64587, -70501, 93386,
-        -82164, 77502, -62293, 92002, -31005, 25704, 61533, 98073, 32349, 90557, 12150, 22554,
-        57968, 44859, 82766, 88904, 20406, -55, 22948, 26481, 36815, 15715, 35029, 34378, -73622, 32602,
-        13398, -42728, 30251, 2271, 7790, 69251, 95012, 56444, 72457, 62359, 19705, -4853, -19925, 3630,
-        38026, 20573, 16568, 18530, 56217, -77056, -32523, 24156, 2836, 18928, -43997, 70739,
-        33579, -96755, 94604, 16280, 20459, 26856, 14723, 11128, 94633, 97970, 34005, 24058, 78177, 41507,
-        1938, -50688, 99408, 31604, 31062, 69957, 81585, 17924, 4427, -8718, 32868, -74337, 94002, 55207,
-        67666, 23290, 95517, 43859, 37701, -82894, 33492, 48816, 12284, 36051, 23360, -34286,
-        -75870, 28650, 59386, 80874, 88836, -97767, -72155, 67040, 26123, 56292, -38865, 10449,
-        46870, 9885, 63079, -88014, 17184, 51900, 83119, 91241, 24889, 33086, -63191, -80290,
-        95719, 4985, 32242, 9674, 90214, 96053, 30645, 24875, 4035, 30064, -94874, 57220, 64287, -26925,
-        52563, -34741, 96055, -67421, 91420, 76863, -94450, 88745, -17388, 78880, 28039, 92377,
-        79007, 96409, 65457, 26021, 86462, 45141, 28207, -3080, -87746, 65999, 27778, -34070,
-        43223, -99635, -87918, -80842, -12348, -52198, -21097, 49437, 73619, 33153, 13566, 48069,
-        -72528, -12950, 17523, -30987, -60682, 34228, 10603, -71074, 23576, 70871, 13422, 39184,
-        -4810, 91444, 12596, -10338, 80405, 86560, 27486, 47173, 16089, -42542, -98364, -99916,
-        48134, 99440, 2576, 2477, 86870, 40799, 24613, 94847, 91380, 14659, 31573, 19898, 55585, 27366,
-        -64501, 21921, 66286, 5478, 37857, -50142, 63304, 63600, 976, 31022, -50919, -67608, 55649, 89837,
-        19619, -2434, -41821, -29705, 22278, -85385, -93297, 3894, 4699, -95373, 30576, -49405,
-        17259, 54628, -41414, -60812, -35925, 92999, 26282, -54350, -11858, 2903, 80986, 97355,
-        81662, 8817, 85751, 42387, 93797, 98458, 56035, 96016, 21177, -15818, 78376, 40090, 90747, 79802,
-        64704, 97726, 67561, 22447, 20477, 66883, 75518, 72351, 22616, 20481, -76688, 59597, 63312, 99456,
-        2438, 88156, 584, 33362, 37972, 79030, 42355, -15291, 8453, -34928, 76969, -89525, 2011, 37000,
-        -28377, 94624, 66056, 11332, 27220, -59958, 66270, 62665, 54538, -95990, -53938, -6097,
-        49744, -34850, 41017, -12883, 2681, 66406, 23748, -6463, 33210, -94625, 66333, 67416,
-        -58334, -56967, -32322, 58417, 71881, 52514, 52025, 82774, -3666, 63072, 89486, 91399,
-        72842, -34075, 90019, 50976, 50946, -54329, 81606, 46699, 35665, -71064, 3608, -98816,
-        59204, -97738, 20979, 17871, 45174, 6696, 80058, 97436, 40131, 15786, 84862, 7141, -58541, 37346,
-        -7575, 86738, -87670, 65033, -41938, 8737, 84837, -52757, 32711, -13882, -89061, 60977,
-        38588, 44914, 90718, -82462, 42011, 81341, 94534, 22547, 61222, 86255, 81672, 50584, 19078, 31955,
-        12250, 47753, 46309, 32783, -21742, 31038, -88216, 51774, 79424, 55338, 44662, 12767,
-        88359, -77086, 21692, 98597, -88761, 14491, 7464, 49060, 42333, 88479, 45593, 41724, 81721, -17950,
-        68560, 19406, 99748, 85067, 91936, 48214, 6657, 22342, 21009, 65351, 75447, 5463, -74094, 39595,
-        59141, 2289, 83687, 10000, 98505, -24931, 34489, 1910, 17700, -17240, 36943, 42697, 10817, 58428,
-        54960, 99045, -9160, -2286, 68721, -47427, 63456, 82903, 12771, 13213, -95594, 98035, 3240, 30481,
-        48624, 28135, 46508, 89953, -58788, 73704, -1895, -25815, 42914, 908, 51570, -21179, 81176, 51605,
-        -62070, 65927, 5627, 71161, 70053, 19931, 23672, 70635, 16561, 63663, -77685, 25748,
-        -62339, -45015, 84198, -1504, 24204, 19015, -5403, 10853, 64906, 39691, 52689, 15933,
-        23522, 35208, 74635, 15908, 74655, 53222, -90314, 61126, 79354, -17522, -31077, 81009,
-        26036, 67313, 25109, 81765, 99481, 22009, 84358, 66310, 52953, 1973, 18598, 88126, 94199, 77519,
-        68941, 63776, -91818, 73935, 93529, 7763, 92462, 16228, -84153, -1835, 65971, -8096, 80487, -82692,
-        45012, 83810, 62768, -44982, 23058, 8128, -42270, 47990, 28121, 57087, 5625, 42239, 20172, 49475,
-        12941, 73255, 98339, 53362, 25953, -3575, 87082, -58672, 95460, 57642, -50230, 96733,
-        36711, 11535, 60538, 36837, -92968, 8917, -82423, 14704, 76618, 24276, -73844, 9329, 94103, 69768,
-        56539, -26319, -78846, 27994, 57373, 89823, 44766, 16046, 6548, 48941, 30986, 99517, 23827, 84901,
-        63612, 20804, -45924, -32513, 13424, -93586, 84486, 71584, 90581, 96674, 61357, 6238,
-        31428, 63985, -72446, 89819, 39145, 93386, 88461, 78736, 54569, -81217, 97153, -75209,
-        85788, -51546, 56067, 47588, 70544, 92151, -23110, 18705, 70528, -79039, 39988, 85961,
-        -13800, 76575, 71953, -21285, -37078, 61811, 84452, 60551, 11051, -47654, -76911, 33772,
-        41130, 97253, 57026, -78280, 62996, 73185, 33275, -18435, 15627, 51235, 73196, 54491, 5884, -44022,
-        24016, -80417, 84650, 8841, 68883, 41599, -20894, 46897, -16203, 42498, 92067, 6563,
-        -14186, 40877, 82488, 27520, 86070, 63720, 32941, 32960, -75299, 39089, 24049, 57951,
-        22338, 397, 54688, 44653, 2807, 39193, 33171, 98193, 8362, 86160, 55042, -6025, -52506, 17505,
-        54824, -13301, -71658, 51805, 54633, -40993, 81504, 16840, 23751, 74489, 23470, 35616,
-        -82603, 47085, -57067, 5065, 16009, 79528, 53961, 7793, 10582, 93413, 60746, 79127, 87448, 43051,
-        -66126, 50415, 23715, 85547, 75285, 30784, 53228, 79623, 86769, 26655, -90974, -37658,
-        -51783, -21262, 59758, 85023, -15315, 96138, -35324, -99676, 28287, -35556, 50957, 44684,
-        -11991, -93570, -64665, 94300, 10741, 20997, 85594, 66217, 12562, 14243, 85905, 91755,
-        90995, 6877, 75839, -48852, 19806, -67030, 18684, 94714, 60695, 81801, 80759, -32898,
-        -31889, -17552, 38185, 9058, 67354, 41943, -45821, 92040, 30817, 14807, 12695, -64924,
-        10592, 27588, 90278, 24285, -38583, -21247, 15267, -82632, 60396, -38769, 17069, 52645,
-        -77441, 7610, 34658, -47328, 70624, -45429, 19151, -26952, 16775, 75331, 89700, 98071,
-        72856, 48992, 26808, -91507, 95934, 72428, 48252, -20389, -42092, -42579, 70191, 49034,
-        22804, 49097, 7086, 4151, 82385, 54722, 90436, 40046, 17359, 70662, -92423, -88367, 51381, -34244,
-        67782, 27738, 72431, -34484, 13806, 67636, 82440, 19829, 81438, 22752, 77927, 63147, 27790, -21096,
-        97645, 92024, 3142, -8237, 78310, 73971, 14036, -71028, 62057, 40597, 45417, 48337, 98169, 75664,
-        10910, 33910, 6212, 55438, 8215, -52153, 43140, 20330, -22638, 73664, 79417, -15828, 61194, -16446,
-        99739, -20056, 88708, 93589, 17994, -73418, -4944, 91565, -83419, -25139, 40575, 31170,
-        48418, 44171, 86482, 47397, -89694, 61087, 8526, -7008, 90342, 16333, 85822, 4646, -39801, 74095,
-        72035, 74754, 6555, 53348, 84111, 47673, -69624, 22655, 7091, 79621, 92449, -6402, 4864, -70720,
-        -13299, 30074, 43620, 81060, 86053, 80834, 91084, 7299, -92262, -28501, 88564, 57048,
-        31703, 90641, -48989, -23298, 78367, 32709, 21621, -30575, 8852, 32545, 11497, 3904, 15405, 23455,
-        90054, 53038, 16194, 37219, 9812, 3210, 468, 71505, 9807, 5076, 30047, 77781, 24349, -9024,
-        40518, 14832, -63109, -38973, -66203, -5199, -55146, 44007, 90526, 59844, 32830, 10966,
-        -33376, 3383, 8714, 41442, -91574, 20290, 79463, 43498, -32001, 20497, -95258, 62890, 2466, 33218,
-        90169, -90255, 12816, -61070, 74632, 18689, 66783, -11069, 22685, -5007, -42258, 1933,
-        27834, 14371, -54241, -5282, -32674, 61365, -77016, 60108, 59307, 55060, 10398, -91153,
-        89604, 67197, -6793, -62104, 4557, 14770, 27104, 98384, 63145, 8150, -13130, 75051, 51051, 21085,
-        19171, -51395, 40289, 27777, 26566, 35497, 23903, 86139, 99676, 91445, -40046, 22808,
-        -73348, 58540, 40326, 9503, 38022, 63217, 9981, 24212, -70829, 66771, 99827, 63599, 21908, 41209,
-        67086, -96355, -36596, 23305, -52158, 56919, 93441, 23937, 70412, 7024, 52194, 66302,
-        33702, 16065, -39191, 31377, -24778, 40499, 10722, 13106, 88130, 74748, 70247, 89737,
-        38473, 78651, 19615, -51499, 35433, 27706, -64383, 43835, 37941, 24222, 41966, 85435,
-        41442, 18015, 36343, 94721, 6901, 33140, 13023, 62725, 19425, 51843, 97716, 81791, 19235, 2640,
-        74730, 62875, 34085, 4961, 71882, 42363, 31585, 26355, 50963, 88608, 28811, 25696, 59004, -40369,
-        97011, 61407, 70841, 91826, 25551, -2905, 51170, -46085, 755, 6276, 86208, 6928, -40903, 62249,
-        30510, 6809, 81698, 70023, 19800, -98295, 1639, 50330, -39659, 24163, -31239, 73790, 31279, 21299,
-        18020, 28234, 60367, -37109, 22326, 66748, -9796, 3044, 58929, 57917, -74058, 43398, -4042, 53565,
-        -63948, 52964, 86991, -55370, 42560, 18086, 27472, 50773, 34581, 91153, -8881, 34964,
-        -83370, 74230, 82613, 26592, 51222, 13077, -4667, 24367, 49959, 55147, 77426, 65854,
-        -51825, -75576, 59327, -7305, -76497, 97166, 6786, -69179, 63755, 28398, 33932, -82778,
-        7899, 44404, 45035, -45230, 87423, 82074, 91093, 60664, -98895, 54938, 24115, -7831, 8618, 26716,
-        78345, 78100, 80146, 64834, 65508, 84003, 80388, 47395, -86642, 6400, -78144, 48024, 35115, -11449,
-        72694, 98582, 23334, 25267, 92196, 67325, 53625, 6918, 64055, 57638, 74194, 96967, 82006, 25220,
-        44137, 60933, 92547, 90208, 91182, 26993, 20579, -58531, 40861, 73897, 15468, -15632,
-        16743, 17860, 66303, 33375, 31591, 87736, 55320, -42517, 66740, 94762, 55751, 2685, -74930, 45167,
-        -61798, -83105, -88673, 50716, -95541, 35656, 37105, -59223, 77513, 4644, 21765, 32624,
-        -1258, 80858, 1035, -13072, 97818, 68957, 82202, 51576, 82128, 11996, -8600, 73489, 8911, -76447,
-        63854, 80491, 17738, -19209, 67730, 56271, 88282, 16801, 88751, 68069, 78012, 73637, 13438, -9327,
-        24887, 32726, -61918, 55177, 78990, 6315, 71023, 52972, 88919, 27068, -78705, 67511,
-        -83798, 17396, 34429, 36157, 23524, 22415, 549, -3061, -93485, 44955, -16456, 93364, 52519, 70973,
-        35916, 46843, -54674, 48108, 9494, 32477, -61348, -81538, 93526, -21424, 24657, 57373, 329, 47889,
-        21135, 57174, 44654, 52921, 10086, -68782, 93907, -4521, 1361, -75867, 1773, 91317, 84446, -33732,
-        18344, -81978, 95621, 83989, 37064, -47619, 54088, 85336, 31665, -93840, -25837, 18718,
-        5206, 36129, -71555, 27613, 34134, -12389, -62993, 32470, -50900, 4268, -3900, -65558,
-        97691, 7440, 63139, 36216, 45526, -43099, 65765, 32946, 13244, 76740, -11878, -5540, 32732, 11319,
-        -6912, 54299, 73398, -40330, 47388, 18215, 44060, 28109, 88288, 64984, 13561, 91645, 13713, 13978,
-        31730, -73624, 71195, 16750, 33878, 5845, 63655, 75240, 65874, -70032, 75373, 57774, 9553, -41415,
-        56820, -94926, -4447, 1256, 34602, 19966, 78650, 66315, 96073, 99824, 74485, 62777, 38874, -99030,
-        30819, 3333, 22875, 88556, -38096, 38702, -50691, 99288, -88175, 10526, 31154, 42774,
-        18328, -19781, 6023, 41429, 75057, 741, 78320, 38859, 14010, 32236, -98108, 35310, 23585, 46264,
-        67762, -37383, 23915, 12632, 29186, 1328, 34205, 62754, 51436, 99398, 88395, 77462, -38049, 71711,
-        -71336, 45510, 30787, 16003, -25915, -41315, -42229, 51411, 48879, 20637, 63398, 94536,
-        -30108, 10014, -42797, 89181, 74431, 67581, 47832, -84773, 64266, 71237, 66066, 79839,
-        57049, 80087, 78126, 95659, 30087, -35126, 13332, 44266, 8611, -34004, 40345, 36056, 69561, 56485,
-        58903, 68055, 2087, 52477, -95818, 17818, 7017, 71712, 10704, 92056, -83213, 62321, 46768, -67606,
-        61499, 87888, -29786, 57770, 50846, 89228, -34600, 59481, 58638, 54604, 81233, 35845,
-        31682, 26959, 11636, -99963, 57777, -46649, 68051, -82277, 21575, 80183, 27773, 97875,
-        19031, 13021, 84817, 58898, 64446, 97680, -20992, 69720, -58755, 84332, 19847, -83649,
-        31019, 50103, 16931, 84555, -14724, 2654, 20069, 83454, -71221, 73341, 34019, 63254, 31127, -61396,
-        12518, 39156, 85090, -4844, 11949, 7307, 93742, 45914, 8349, 78852, 614, 52587, 3660, 30664,
-        5070, 48175, -90112, -85555, 98923, 81056, 97374, 73470, 42400, 31314, 32199, 12805, 99910, 978,
-        -52315, 17016, 35273, 63416, 59452, 24007, 54426, 79861, 96656, 2973, 73801, 73488, 89244, 98020,
-        57288, 80047, 37825, 19789, 36174, 80495, 59846, 93858, -55332, 52279, 97269, 27548, 43021, 2816,
-        93645, 56375, 16255, 82196, 95533, 90968, 74662, 85455, -41300, 19597, 78890, 60376, 39868, 39390,
-        -88344, 71711, -63513, 79887, 8140, 26811, 69109, 98826, 7544, 49370, 75340, 14743, 74666, -6473,
-        -56959, 46727, 38842, 11456, -76706, 75248, 17737, 20645, 87055, -63214, -5895, -31338,
-        41956, -75229, 51415, 18241, -85538, 63032, -15437, 58801, 66002, -12372, 55328, -56317,
-        41892, -6808, 15462, -32937, 72953, 11101, 60250, -99498, 51557, -30826, 97707, 49022,
-        87264, 23340, 30565, 5605, -9806, -11623, 70204, 44335, -58665, 52085, 97105, -34144,
-        -15465, -59981, 80217, 11344, 25315, -83949, -8622, 47739, 70448, 83685, -91386, -70429,
-        -6760, 51203, 19142, 49156, -3168, -69529, 48102, -47803, 39536, 50392, 72837, 31249,
-        48672, 89412, -12429, 83558, 74214, 29230, 2201, 58873, 70979, 40139, -66027, 25701,
-        -34585, 13513, 33530, 5397, 36889, 4295, 41267, 76051, 25989, 30381, -70488, -15449, 60616, -50627,
-        31890, 58262, 53633, 28341, 32426, 31381, -10806, 18017, 48505, -78627, 94276, 74802,
-        23977, -60388, 42695, 21873, 45643, -57375, 73954, 67107, 91773, -76150, 38117, 22223,
-        41588, 30849, 73593, 48028, 67343, -46011, 88347, 7829, 86397, -84068, 80401, -22272,
-        25256, 96573, -87727, 92876, -37240, 61068, 15755, -83795, 72646, 43688, 67791, 18548,
-        99778, 4994, 14847, 33800, 21432, -86415, 90949, -32649, 88666, -58058, -36984, -54760,
-        95968, 86542, 69058, 8829, 2403, 8734, 74602, 27726, -18169, -33845, 14012, -4944, -95549, 18806,
-        31020, 83801, -1937, 56903, 95083, 15336, 95770, 86838, -28806, 72643, 16054, 37363, 38340, 38241,
-        -29941, -93071, 86461, -79227, -57424, 66427, 21297, 6429, 89860, 21500, 34671, -43614,
-        86169, 19866, 41967, 16406, 34521, -66909, 12822, 12659, 40928, 70555, 77096, 17150, 87583, 46117,
-        13496, 54531, 44296, -99142, 78627, 45503, 407, 87616, 48347, 86097, 35119, 34773, 20960, 80887,
-        3832, 82257, 29535, 34005, 32634, 17728, 43679, -53010, 87158, -6200, 95370, 54211, 30845, 18038,
-        56443, 33513, 2117, 82873, 13094, 790, 80638, 46039, 74311, 95882, 69934, 2070, 71175, 61118,
-        97495, -36044, 65621, 24028, 97663, -55966, 59669, 47591, 44819, 46625, 15294, 29266,
-        -4006, -92539, -58663, 20394, -71329, -57339, 27071, -80278, 92952, 9855, 81472, -34850,
-        23510, 83384, -65765, 55570, 48136, 13888, 56575, -80795, 85079, 31613, 95438, -27200,
-        23035, 78424, 2416, 51934, 54399, 27939, 47936, 87080, -57143, -36803, 92446, 42921, 71213, -68169,
-        47942, 43433, 89244, 1029, 93581, 94581, 90697, 78760, 59014, 66298, 16972, 63819, -3202, 51139,
-        97331, 15982, 83290, 37070, -68219, 57571, -74048, 29098, 30603, 44435, 43890, 49172,
-        93942, 72745, 39835, 41321, -39713, 31603, 2663, 87901, 8653, 41873, 28795, 5427, 14695, 77015,
-        81014, 98899, 534, 13234, 20373, -43172, -39063, -5045, 90206, 75511, 59534, 67408, 46700, -58037,
-        28424, 17037, -59478, 16008, 11187, 74249, 18337, -819, 41249, 85276, 26236, -33615, 25293, 46194,
-        -24122, 24453, 67601, 43421, 78405, 65616, 45887, -40587, -81112, 94855, 40333, 63142,
-        18504, 9998, 91538, 90304, 77212, 76006, 72146, 8963, -29672, 48400, 70511, 857, -28404, 19688,
-        -59295, 24189, 91714, 33012, 95478, 60219, -65777, 5208, 1346, 36593, 85948, 45905, 96134, 6551,
-        34653, 32464, 45287, 60750, 87609, 6909, 33690, 20410, 3183, 43553, 88450, -70120, 48844, -57096,
-        33800, 77260, 12902, 13436, 96965, -33763, 28682, 46988, 75206, 20634, -34131, 79189,
-        29546, 54520, 69198, 11880, 44869, 26294, 11713, -75436, 10816, -78394, 84528, 17801,
-        40079, 66885, 59965, 51987, 77441, 46460, -70441, -63724, 47552, 9000, -38179, -7234,
-        12755, 22799, 6348, -34813, -92307, 35566, -53336, 92753, 10192, 6715, -97325, -93387,
-        61986, -65147, 29043, 82834, 11646, -61996, -14573, -26349, 9894, -1709, 14117, 95129,
-        74903, -85219, 27791, 55553, 31246, 72932, 1860, 5605, 71307, 41243, 50372, -24550, 29900, -95221,
-        7053, 36483, -14671, 78652, 86071, 17929, 25348, 39087, 80798, 29701, 13182, 35254, 40864, 61800,
-        -18897, 80244, 36297, 49378, 86281, -62194, 14297, 37312, 85782, 44419, 66950, 83063,
-        58902, 48663, 77003, 25975, 55072, 65852, -34038, -21671, 97383, -83964, 18848, 99448,
-        17252, 70245, -97819, -68909, -34882, 20081, -48815, 30736, 9113, 41709, 81065, -4409,
-        53899, 77010, 86955, -73285, -62753, 68615, 17560, 4532, 73804, -91721, 15425, 96786,
-        56127, -74126, -14430, 25270, 15943, -78614, -82537, 70080, -79788, 53738, 11279, 50094,
-        86578, 80836, 16895, 83978, 58559, -26697, 19003, 10091, -69025, 35509, 77459, 64976,
-        -77014, -46771, 8623, -50666, 80874, 73467, -24182, -11824, -36088, 40373, 46065, 59990,
-        22409, -34032, 20660, -9379, 74498, 60633, -85111, 48900, 59815, 2601, 1889, 78198, 55055, 55452,
-        15484, 40044, 29740, 46785, -88581, 60642, 76698, 8619, 39355, 15801, -31215, 85568, 29614, 1154,
-        13395, 62010, 79092, 67874, 90249, 50568, -81452, 14256, 77418, -14454, 55948, 26510,
-        50389, -81463, 36900, -40506, 9801, 41147, 36317, 4071, -34700, 44572, 1067, 89290, 20134, -49724,
-        83446, 86010, -4440, 73371, 92295, -85404, -82024, 13494, 80347, 32709, 56626, 86210,
-        -53075, 43046, 92795, 74841, 36490, 69013, -96478, 69090, 24930, 79758, 54052, 52387,
-        74080, -74412, 85653, 74807, 49771, 88244, 55864, -61912, -29415, 51598, 53834, -44957,
-        80067, 3421, 39428, 37653, 6991, -62342, -93453, 16789, -37311, 58855, -1973, 29079, 99698, 73930,
-        96756, 51660, -73216, -61361, 44612, 92203, -32861, 93691, 64897, -29546, 85016, 72934,
-        37581, -23168, 25546, -95382, 8363, -46870, 43143, -20990, 19090, -49348, 52491, 45289,
-        65624, 84168, 15451, 91963, -11603, 19679, 82256, 46714, 74735, 29917, 58291, 9073, 28869, 12423,
-        51735, 14060, 1750, -66166, 42549, 87985, 12062, 40267, 86770, -37127, 79029, 15573, 28880, 25344,
-        34807, 30161, 16428, 48207, 96970, 35676, 48059, 91440, 29725, 80544, -61937, -19531,
-        42309, -44813, -25660, 49954, 21738, 23191, 52454, 24577, 95298, 85415, 93936, -20744,
-        91586, 73770, 21653, 14642, 59000, 34054, 72003, 83668, 77623, 89876, 88445, -73468, 91274, 76071,
-        22473, 95150, 66952, 56945, 41907, 7608, 48914, 14666, 84776, 29899, 18924, -22186, 4591, 77275,
-        24457, 6291, 77925, 74692, 67671, -38777, 22795, 50107, -22762, 84932, 37895, 10235, 84864, 88397,
-        34538, 26442, 94951, 49601, -47161, -78562, 9488, 29224, -23632, 37665, -89564, -57299,
-        32641, 6528, -25015, -73443, 20044, 90008, -55157, 89356, 96280, 67063, 74484, 18444,
-        -69532, 12637, 38218, 10835, 11304, -85450, 49395, -17538, 86166, 34787, -42586, 22098,
-        7119, -87450, 85776, 52963, -97473, 52058, 9595, -47110, -82086, -65788, -95293, 35332,
-        83537, 11695, 81466, -25227, -6201, 56809, -75039, 97685, 48211, 49156, 27406, 53144,
-        64790, 72675, 56819, 60112, 94048, -62933, -49607, 15815, 88750, 16801, 20554, 47992, 8832, 84473,
-        663, -29265, 846, 26813, 98692, 80789, 65315, 53514, 11334, -57945, -18937, 26371, -93145, 57116,
-        -82443, 66683, -81539, 89770, 53637, 88034, 62160, 33313, 69882, 22866, 24871, -89295,
-        36917, -53722, -910, -58508, -9137, -64721, 6514, 99527, 79936, 63808, 61405, 45445, 11078, 59307,
-        12482, 79156, 66009, 77867, -45141, 33132, 15495, -27906, 76040, -52169, 94654, -64509,
-        64709, 44814, 988, -3362, 53893, -43048, 76111, 83332, -51212, -66283, 7977, 56972, -65563, -59932,
-        97000, 2939, 84599, 67999, -40275, 58781, -98265, 38208, 73455, 73469, 2335, 85496, 27993, -63626,
-        20193, 8810, 61382, -62259, 72685, -94276, -98173, -46611, -14243, 45259, 23353, 96298,
-        -6906, 52428, 30362, 78665, -75317, 96577, -10327, -62122, 4674, 39661, 1066, 98157, 20045, 78028,
-        93325, 77659, -98345, 63024, -29392, 53936, 62190, 59458, 15625, 6477, 37346, 65834, 90618, 83885,
-        72078, 40273, -55419, -97892, 26419, -66841, 11552, 20845, 34166, 19196, -70421, 92472,
-        94963, -67920, 68857, 50238, -10455, -7890, 25530, 90211, 65493, 43829, -34535, 34349,
-        97477, -73160, 26594, 83011, 29322, 52355, 28141, 7924, 47373, 22213, 23082, 58831, 36017, 13891,
-        46542, 20870, 21341, -95633, 33637, 41158, 81567, 73418, 28746, -51287, -6016, 95552,
-        95259, -57034, -80579, 83220, 90210, -16476, 71223, 66633, 82572, 6656, 3100, 75402,
-        -70721, -99124, 33930, 43716, 34766, 91982, 1121, 168, 97187, -87197, 1520, -50301, 66925, -80924,
-        12312, -7482, -95294, 26166, -84058, 86922, 80877, 12722, -84467, -77230, -93495, -4040,
-        57766, 78015, 95710, -39422, 3702, 20223, 63216, 3930, 94494, -30987, 83820, 66913, 34722, 63073,
-        80281, 31711, -29743, 83670, -67592, 25620, 10336, 55791, 72908, 34144, -97498, 18182,
-        93634, 24946, 52623, 28958, 51960, 27551, 28672, 93483, 39056, 60893, 78597, 67529, 62941, 92258,
-        96673, 61422, 87179, -37792, -52193, 20872, 50735, 36023, 88939, 19531, 36472, -25936,
-        -9979, 85455, 71470, -62770, 19531, 89881, 20875, 91493, -89333, -51300, 4505, 79831,
-        36994, 85874, 44880, 62145, 14824, 85379, -36512, -25777, 90496, 93434, 1743, 54340, 80195, 61535,
-        54971, 57142, -26864, 76553, 55898, 50236, 50205, 28136, 63107, 816, -73207, 77003, -96359, 59973,
-        38485, 37006, 27426, 70478, 37863, -41029, 88738, -66626, 53927, 29634, 63101, 52287,
-        71630, 4297, 29253, 3494, 8970, 71298, 64526, 4793, 46027, 72977, 94816, -28371, 60731, 34007,
-        6634, 57910, 4886, -96800, 72637, 99517, 4535, 31336, 78785, -58629, 51313, 2086, 75159, 23863,
-        85656, 72041, 47695, 70987, 20469, 94711, 67426, 99825, 92991, 58103, 90084, 88613, 80487, 64393,
-        -12460, 3232, -57626, -34590, -44386, 31765, 92178, 6785, -66718, 48211, -72873, 23300,
-        -11913, 57181, -40668, 44355, 15199, 1767, 83280, 89882, 27926, 39699, 48338, 86455, 85529, -94238,
-        37029, 76724, 60704, 93235, 38560, 51144, 80974, -70512, 62095, 89706, -60612, 73457,
-        80425, 71422, 80002, 86, 25354, 35828, -20259, 55575, 35367, 51218, 25673, -50747, 44846, 43206,
-        5844, -15491, 36181, 60654, -36580, 37470, -16368, 44898, -73956, 34690, 92790, 43931,
-        5565, 69367, 57917, 76739, 3851, 88266, 49813, 56856, 53068, 24982, 35566, 9508, -68695, -34612,
-        -20224, 85329, 62279, 98205, -41586, 76380, 56721, -99519, 27689, 51196, 43963, 12174,
-        -9069, -90545, 59034, 34459, 13750, 8042, -26681, 72624, 2202, 31799, 77672, 16078, 29991, 66016,
-        29959, 89263, 66830, 43379, -82057, 23835, -87344, 9735, 54656, 12426, -74365, 70532,
-        98973, 83465, 85255, 88562, 97712, 93068, 49280, 6075, 9671, 11046, 26246, -93808, -21489, 32088,
-        75961, 11462, 77989, 5138, 92674, -74478, 19968, 55089, -81558, 28616, -91078, 52188,
-        10876, 34637, 52260, -1234, 85459, 21487, -66825, 748, 37527, -97320, 77786, -5079, 4508, 86778,
-        -82210, 4307, 19678, 15771, 35887, 30292, 38847, -70423, 61768, -7658, -5693, 58570,
-        -79550, -11956, 97144, 24777, -10753, 70565, 88298, 9141, 63211, 33795, 22593, 64752,
-        -57984, 88435, 53434, -20329, 22431, -25699, -29971, 57255, -40864, 15823, 73962, 62389,
-        -92706, 84291, 74798, 99883, 89352, 66925, 31070, -16429, -52542, 1693, -57162, 8581,
-        21172, 576, 23046, 54841, -7319, 83267, 58967, 56888, -78791, 5025, 16863, 36079, 57254, -71476,
-        -62994, -21324, 35434, -88307, -454, -4056, 40931, 74382, 29148, 25455, 26006, -36734,
-        -64494, 31972, 92939, -34669, -27961, 42644, 48008, -18925, 72144, -79360, 11751, 61124,
-        43254, 19155, 56596, 85341, 50996, 93124, -22709, -31992, -88302, 4887, 10186, 62434,
-        91676, 61222, -59681, -310, 92503, 85809, -71078, -49773, 78834, -57003, 44471, 68413,
-        74852, 42599, 50164, -68867, 53371, 36514, 13091, -77019, 49705, 89276, 92285, 97985,
-        20151, 76776, 85625, 11657, 41525, -4592, 53257, 43115, 92122, -72904, -89481, 8683, 47488, 3564,
-        29773, 93193, 93109, 36841, -86068, 87184, 84981, 25196, 6602, 44889, 74156, 12760, 20431, 82157,
-        -56284, -99138, 74680, -99193, -26171, 90615, 85756, -10342, -82310, 82250, 71648, 64351,
-        -23410, 64481, 55453, -51678, -85506, 90210, 70870, -91099, 90377, 13447, 50201, -77375,
-        -6747, 89811, 95842, 52872, 50702, 51654, 35133, -80523, 47725, 29621, 54990, 67507, 93250, 68221,
-        10091, 32176, 37965, 39916, -67764, 64401, 67509, -2677, 54818, 70287, -38930, -73047,
-        94121, 51518, 61107, 2806, -22924, 89351, 68212, 9797, 74102, 48037, 89779, 15942, -42078, 78279,
-        -82300, -45658, 22123, 8533, -28324, 62725, 63347, 38040, 24671, 83851, 67552, 7624, -8505, -34106,
-        3091, 9563, 33412, 47763, 10442, -20937, 55660, 38719, 61878, 65496, 83632, 14400, 88392, 51863,
-        59448, 37446, -41910, 54177, -18574, 76613, -39539, 35504, 17332, 28680, -43800, 92768,
-        59648, 52707, 51838, -62697, 38097, 13218, -21588, 62762, 94003, 6564, 17021, 30916,
-        -44173, -20819, 5565, 14028, 23440, 38110, 19744, 4555, 79785, 94064, 15836, 56391, -3062, 36126,
-        80169, 10384, 86053, 31128, 54624, 39864, 38607, 55493, 22394, 75509, 81333, -23918, 53323, 63450,
-        72854, 91950, 32765, -9119, -45093, -84343, 80200, 91758, 38781, 60799, 60632, -85220,
-        78638, 54454, -21107, 52960, 30553, -13835, 49974, 22172, 12251, 33585, -25117, -91045,
-        7149, 94036, 91370, 87751, 68128, -20449, 8006, 8175, 53092, 26066, 66016, 4517, -53202, 21663,
-        60912, 9127, 86718, 30604, -54, 30729, 68656, -97485, 98088, 36339, -61486, 96993, 99579, -8880,
-        -26481, 50444, 45113, 70124, 90382, 56145, -19063, 15272, -18022, 76019, -51298, 13202,
-        54982, 8086, -82684, 91062, 71957, 15905, 46788, 38594, 69722, 10389, -20685, 30279, 53231, 26668,
-        -59866, 9638, 74165, 9412, 26519, -28275, 66955, 93968, -18821, -24543, 81275, 82326,
-        49272, -47419, 17853, -1051, 74335, 58965, 18579, -205, 84614, 15305, -65145, -67378,
-        92023, 85561, 20389, 81963, 1368, 68256, 44146, 14617, -25707, 81509, 30324, -70848, 63691, 27287,
-        70276, -32507, 20529, 61835, 44865, 27077, -24367, -1345, -88560, 20779, -42041, -68214,
-        90816, 43259, 15178, 13178, 56939, 85554, 12483, 97429, 72896, 74846, -82070, 6487, 58042, 17390,
-        28299, -7211, 5212, 73379, 16383, 40064, -73988, 64784, 47491, 7315, -68409, 88959, 54326, 41557,
-        47012, 77714, -24271, -78866, -67585, -15003, -18385, 45034, 77097, 61917, 34262, -50692,
-        -1727, 47044, -85675, 91816, 2998, 73186, 49335, -75476, 52079, 39941, 39064, 29026, 60765, -46642,
-        98264, 6883, 82841, -98548, -64907, 69502, -21381, 24719, 98730, 89403, -38434, 44451,
-        74738, 22692, 20761, 48781, 12733, 76320, 70117, -98543, 2908, 4063, -63613, 48183, 12826, 60336,
-        38014, -3526, 73076, 3687, 40073, 20923, 18174, 56984, 58305, 27816, -58629, -81528, 26729, -50262,
-        57052, -81537, -51175, -19344, 23060, -11675, -62727, -8307, 76242, 9355, 92312, 73460,
-        90063, 56707, 58440, -7071, 4497, 84320, 16530, -12290, 53333, -4835, -43589, 44999, 68218, 88669,
-        -11799, 2162, 27462, 75863, 19725, 90792, 92168, 40893, 7474, 35947, 12444, 2746, 44668, 78231,
-        50058, 8346, 19393, -32598, 44911, 38166, 11621, 81454, 23674, -68613, 49987, -19536,
-        64371, 69243, -22071, 99548, -4695, 90511, 23113, 46077, 27935, 52615, 82635, 21827, 51607, 25342,
-        -93635, 97896, 84423, 23760, 2160, -31845, 39211, -40138, 20768, 81319, 16281, 83289,
-        99516, -85281, -75585, 96809, 25316, -15820, 55917, 68802, 75674, 55488, -9622, 63245,
-        7039, 78409, -91374, -6646, 68985, -64619, 74649, 27907, 42021, 6177, 81535, 46168, 45596, -8877,
-        3392, -60404, 1419, 14865, -17882, 64641, 33347, -7891, 79734, -29013, -2100, -19331,
-        41860, 9468, 13389, 3610, 51289, -49247, 83823, 75362, -81948, 63588, 6774, 10551, 50891, 92564,
-        -38206, 97431, 55826, 47266, 88563, 97759, -1962, 64243, -17052, -65138, 99520, -66126,
-        -14110, 92294, 77690, -10729, -17213, 55194, 15073, 11187, 57698, 18301, 65414, 22475,
-        -63772, 9354, 19572, 855, 98875, 25890, 93495, 24869, -55418, 11241, 32312, 8882, 75679, 48453,
-        15701, 56583, 83040, 49005, -83695, 7051, 49349, 29414, 32208, 91104, 74451, -24321, 23937, 82722,
-        28137, -51249, 84719, 29660, -63984, 98330, 75437, 15189, 9248, 43590, -79344, 12600,
-        83728, 51642, 93874, 79421, -87134, 58526, 97628, 25784, 84594, 90720, -93111, 60428, 4762, 18017,
-        39383, 6689, 38679, 8396, -42407, -69504, -12132, 97, 43458, 89044, 48388, 65135, -3434, 85362,
-        45396, 43462, 83807, 22589, 19801, 53833, -15495, -23318, 94585, -58282, 52490, 39773,
-        -94622, -52985, 58506, 90646, 72062, 2402, -29303, -70198, 52932, -70415, 24047, -35806,
-        -88976, 49080, 71552, 13027, 24644, 75779, 22303, 81025, -7949, -58246, 42189, -84964,
-        98787, 8981, 39879, 45820, -82492, 21249, 95105, 7505, 62384, 8220, -12132, 39915, 3202, 30627,
-        62211, 77588, 59540, 12859, 58435, 20225, 99926, 79385, -70514, 60244, 50267, 72596, 18183, -58073,
-        74678, -75106, -41222, 36121, -30906, 97440, 3601, -54690, 69198, 5067, -18852, 81053,
-        62921, -96277, -3753, -67639, 57859, -30404, -53490, 54400, -27618, 95547, 70474, -85987,
-        -32184, 51882, 56052, 80805, 49470, 65405, 4374, -21660, 53872, 7839, 46767, 2135, -19357, 18559,
-        91561, 74127, 73046, -22589, 91249, -73353, 58567, -76487, 35465, -8037, -54134, 12108,
-        51140, -47956, 50763, 93903, 16002, 16936, 55233, 64133, 49794, 92654, 30345, -32569,
-        21125, 94007, -6071, -30150, 97588, 3047, 92116, -23295, 31546, -36716, 73532, -1321,
-        86698, 97036, 46422, 62123, 42819, -92513, 53578, -7051, -4107, 35070, 65971, 3536, -65846, -8102,
-        17468, 30106, 96369, 75817, 23678, -49623, 20820, 767, -45746, 91208, -67405, -78047,
-        -48916, 86534, 85741, 36585, 94416, 27834, -36970, -16948, 26458, 15139, 76876, -1610,
-        69003, 70608, 83715, 75689, 95535, 29618, 83048, 4915, 21735, -87112, -91886, 8934, 36361, 17435,
-        75824, -58131, 1239, 93387, 61976, -26504, 80601, 39162, -24771, 31357, 1689, 10690,
-        -48345, 48483, 29020, 98858, 48965, 27842, -64738, 85845, -88328, 16632, 2310, 28257,
-        79162, -2842, 31023, -51843, 11608, 27979, -61413, 21864, 64751, 33557, -3452, 19732,
-        12741, 14966, 51850, 73420, 67722, 49234, 57685, 19124, 33251, -13458, -32416, 25507,
-        69676, 36970, 27369, -17583, 6836, 74364, 96556, 49405, 72842, 51436, 82037, 53407, 52511, -56373,
-        28060, 74696, 52960, 44937, 20719, 66308, -1363, -87594, 17693, 20448, 47194, 2394, 82944, 7558,
-        77040, -38037, -33312, 63148, 8176, 98301, 22995, 80004, 18792, -31679, 27420, 93564,
-        -77679, 21193, 10564, 22285, -36965, 37266, 52700, -10122, 21396, 61925, 64030, -75044,
-        7810, 40532, 22405, 94265, 82117, 76958, 46563, 85890, -17433, 72984, 25656, 98618, -32176, 7384,
-        8038, -18879, 22146, 6155, 1993, 28470, 4685, 64800, 48241, 55989, 98307, -27049, -70361, 21846,
-        83713, 61728, 80342, -13180, 91234, 15818, 83177, 68210, 98494, 11623, 43227, 66646, 86566, 71161,
-        74466, -16799, -26707, 69743, 36898, 87396, 4953, 32107, 61364, 85066, 67179, 54556,
-        -12303, 49073, -66199, 83132, -90193, 45944, 56867, -88585, -60794, 95922, 34454, 33173,
-        43009, 63747, 92779, 15627, 16962, 82102, 17708, 73356, 33343, 52961, -83168, 59823, 72437, -4324,
-        -20363, -81421, 78514, 83772, 26400, -22782, 89940, 12019, -2294, 61182, -64677, -80223,
-        88806, 78209, 34750, -24677, 25854, 84890, 92456, 14712, -98308, 81076, 83818, 30086,
-        69502, 76728, 84722, 48446, 42514, 88091, 12223, -36818, 56674, 22212, -69489, 24887,
-        95303, 93021, 66222, -29921, 83467, 54055, 17675, 43500, 29518, 33127, 74366, -42569,
-        70562, -22355, 17120, 18253, 15838, 46549, 3372, 42560, 41463, -75740, 39279, 87903,
-        -32134, 77558, 48499, -98260, 99662, -1834, -76736, 57937, -70960, 38871, -8372, -5437,
-        -59779, 11129, -68967, 50846, 55944, 30556, 79679, 74529, 77694, 15510, 41685, 43016,
-        50809, 97538, 23724, 25822, 90535, 75466, 42256, -92215, 96250, 32824, 56742, -43541,
-        -12021, 23273, 50731, 730, -54125, 51941, 74152, 20342, 43165, 41289, 85720, 33737, -97481, 89679,
-        10081, 51445, 95952, -75463, 35535, 36929, 23363, 36803, -84834, 26857, 32110, -88677,
-        96898, 38841, -43209, 71302, 57736, -71030, 95466, 72161, 15481, 59932, 11502, 88250,
-        81338, -26246, 89655, 88830, 28020, 41246, 8268, -5956, -26652, 62385, 97139, -60449,
-        -14867, 37016, -52052, -76177, -24355, 97058, 70239, 31035, 93115, -94331, -22035, 63050,
-        -96489, -17612, 97599, 68500, 87663, 80911, 99347, 41685, -9436, -21781, -77647, 49359,
-        -19813, 92270, -67397, 98374, -15906, -67539, -12524, 36140, 75353, -79377, 39697, -91165,
-        68984, 46397, 2898, 35653, -7402, 35732, 22670, 3852, 91103, 9285, 33364, 7139, -85546, -25064,
-        25336, -9224, 28861, 55193, 95799, 90914, 87229, 99874, 81020, 43568, 44839, 4570, 21865, 95024,
-        46593, -95213, 5374, 81524, 82168, 56300, 57084, 84393, 91757, 11950, -88276, 29203,
-        -25980, 9323, 32581, 41633, 72809, -61870, 6940, 70370, -46989, 79259, -61022, 42153,
-        91664, 67745, 90154, -85189, 12893, 97314, -56759, 11107, 74532, -67517, 47745, -88545,
-        93580, 81527, 13070, -59925, 73252, 37462, 78357, 77654, 73565, -31842, 33214, 34633,
-        78510, 38525, 57859, 604, -13259, 62170, -66269, 50637, -20191, -45148, 16312, -54605,
-        46570, 2575, 23705, -6111, -30828, 17954, 22832, 78146, 45952, 34136, 79070, 95936, 79466, 4811,
-        83210, 43406, -34073, -33754, -79995, 39851, 28699, 51567, 39732, 13855, 27573, 6397,
-        -15921, 6151, 48404, 12983, 93085, -1470, -85938, 41483, 45548, 79016, 59868, -66235,
-        71092, 90569, 76718, 75141, -73007, -59131, 63034, 69443, 66502, -28981, 74738, 92984,
-        82033, 40825, -44679, -85296, 34436, 74207, 29262, 3617, -73746, 13772, 2989, 30947, 84454, 15820,
-        61940, -94160, 39734, 73650, 97147, 9544, 53550, 79662, 93517, 52988, 34154, 54208, 33278, 94314,
-        27107, 1384, 96087, 56667, 1625, 25981, -323, 97260, -12166, -72492, 61681, 35943, 18242, -69508,
-        24206, -5323, -43137, -22716, 47032, 73321, 93880, -32217, 60251, 81405, -18799, 34841,
-        14701, 8627, 4380, -93319, -94039, 70984, 5700, -91569, 24442, -875, 37701, 29408, 84274, -75437,
-        -23471, 98686, 28177, -97368, 85142, -92966, -69143, -96323, -11356, 57506, 48857, 35826,
-        31358, 78932, 67091, 68818, 44941, -55528, -47387, -42020, 72632, 85627, 59627, 41024,
-        49043, 79870, -83765, 13484, -4735, -37192, 51044, -2821, 87688, -78076, 74493, 58894,
-        52605, -53440, 82755, 87390, -58187, 44680, 54097, 54377, 11168, 7010, 77555, 41349, 70109, 93531,
-        -71070, 84727, -51774, 10288, 94677, 11343, 99525, 29291, 65003, 85054, 95606, 12849,
-        70074, 56852, 24608, -65930, 84178, 24128, 33394, 88508, -61907, 60074, 40754, 68160,
-        49325, 87524, -81668, 18052, 92815, 5536, 17162, 84475, 82883, 78274, 61450, 28096, 27338, 22913,
-        -35776, 88140, 46418, -53081, 24726, 54887, 40469, 84893, 21472, 35060, -38691, 45358,
-        -12626, -82467, 96984, -83709, 44852, 94014, 22496, 27533, 85325, 52348, 15454, 36982,
-        69465, 86346, -95114, 55830, 90568, 21486, 89191, -99031, 11230, 34999, 75693, 71010,
-        -25989, -24996, 90426, -27456, 78176, 62591, 87842, 23448, 50441, 84587, -3110, 40001,
-        34553, 13619, -59130, -86827, 54071, -81652, -37184, -21936, 19417, 58641, 65640, 44211,
-        59717, 97138, 93482, 89073, 93451, 90535, 60579, -71714, 35343, 90420, 46656, 9005, 16833, 86370,
-        30240, -96008, 27758, -60069, -95961, 80664, -10610, 39149, 16846, -72644, 19712, -11610,
-        -35190, 33066, -59742, 93576, 76110, 60956, 41434, 17830, 19814, 93494, -7184, -47766,
-        -53669, 33974, 34606, 73516, 1976, 90530, 59551, 8885, 8729, -59532, 76098, 12592, 80121, -78451,
-        32209, 15095, 61616, -37264, -55384, 74963, 36576, 14693, -86964, 97979, 10296, 29650,
-        44035, -13975, 98064, 6304, 95263, 59321, -35663, 83959, 22976, 47131, 93285, 43514, 19235, 54406,
-        53507, 92830, -78104, 99378, 80191, 13158, -89934, 28363, -57587, 26543, 67303, 37663,
-        40727, 82874, -64062, -36169, -56258, 88733, 85098, -70262, 69121, 87659, 43496, 91851,
-        46805, 83726, 15538, 29016, 28398, -69340, -4199, -76555, 5811, 30258, 98517, 33570, 24423, 60936,
-        79976, 75671, 48741, 79377, -3570, 64490, -68632, 2046, -17596, 41905, 71620, 69876, 64900, 81191,
-        867, 10559, 45005, 33309, 96504, 49022, 97093, 71254, 94290, 24927, -49255, 35398, 5055, -18344,
-        27942, -68827, 29646, 83613, 25495, 24333, 53979, 5454, -67803, 44602, 47294, -34729,
-        -65893, 4158, 61562, 6708, 73237, -61344, 73343, 90520, 71652, 2176, 49290, 32981, 17363, 56863,
-        35841, 22572, 92782, 71257, -74568, 41917, 22281, 31932, 60903, 75485, 8645, 4876, 58767, 86148,
-        3356, 76834, 48350, 86473, 43705, 77920, 13246, -7300, -8452, -38435, 2232, 58992, 6376, 44522,
-        94467, 97158, 23758, 4486, 43412, 25842, 76646, -54234, 37721, -79491, 47221, 10909, 74200, -88075,
-        8250, 94072, 33194, 44603, -65602, -13173, 54199, 64773, 69528, 99572, 72982, 49677, 3252, 40537,
-        -91317, 49017, 46397, 90873, 18970, 47978, -61731, 25434, -14630, -93695, 92803, 14979,
-        65850, 84606, 57919, 18243, -79207, -21915, 61382, 59641, 10798, -22470, -42280, 74847,
-        35792, 49019, 55678, 98912, -97302, 80593, 24332, 43240, -60531, 401, 45904, -37059,
-        -93894, 40382, 97912, 24299, -36101, 11759, 65273, 45435, 71636, 20649, -43244, 68827,
-        94921, 76817, 50998, 36971, 98353, 43102, 64923, 87267, 55405, -67858, 677, 69678, 54978, -7906,
-        -20798, -25095, -31464, 88592, 81705, 92013, 70525, 31324, 80506, 14562, 34822, 98222,
-        42409, 42705, -26517, -18721, 17660, -6600, 61170, 61509, 13843, 78722, 29264, -42398,
-        25688, -16425, 81319, 94657, 66446, 50087, 17330, 24917, 55861, 83575, 78862, 96464, 9308, 68580,
-        25440, 61738, 99190, 8194, 95289, 41801, 95100, -23241, 75699, 68992, -22277, 14162, 29712, 29543,
-        7840, -69561, -78718, 60317, 86107, 91032, 46246, 2582, 29394, 89238, 50031, 17576, 34059, 34727,
-        -24819, 75657, 86323, -74246, 93218, 56291, 7308, -19512, 79126, 15717, 10364, 58842,
-        81380, 94868, -83952, 82345, 6984, 62162, 89498, 20830, 15199, 89209, -76357, 5407, 18862, 10815,
-        -78982, -12146, 3457, 17976, -98435, -82813, 2340, 76374, 82369, 7938, 18837, 88825, 11936, 51562,
-        49393, 93486, 29876, 20193, 28273, 9271, -45692, 37079, -60637, -97530, 18900, 99655,
-        55530, -45867, 8806, 23435, 47172, 10759, -87664, 48990, 95351, 35090, 83045, -12886,
-        -26923, 69545, 66839, 39321, 284, 79778, -60109, 36818, 49993, 76056, 60292, 18181, 42143, 74118,
-        86382, -36619, 61549, 55932, 65891, 19940, 20154, 13508, 17649, -41656, -99146, 69018,
-        51012, 2111, 19815, 24101, -89677, -2647, 9328, 32177, 90952, 49448, 91405, 152, -63633, 40172,
-        27409, 85458, 32244, 6503, 48678, 10916, -12343, 42277, 93141, 54783, -6472, 47727, 99057, 90354,
-        32771, 85814, 80830, 32961, -51979, 34397, -53847, -27865, 82326, -92636, 14954, 51849,
-        50719, 76854, -97210, 45179, 86949, 87836, 63747, 73173, 8106, -50075, -75391, -85035,
-        33495, 73871, 6380, 11789, 95593, 66738, -47159, -96213, 36200, 45274, 52768, -52421,
-        72442, 72459, -37089, 34160, -26407, 29937, 58627, -5471, 35545, 14221, 1429, 56170, 36569, 57545,
-        90580, 9976, -6840, -23252, 78847, 84433, 98784, 34102, -90513, 14851, -48579, 35237,
-        50925, -32340, 74861, 27912, -17779, -18997, 43447, 65642, 30231, 32833, 4227, 58009, 544, -22239,
-        69128, -98861, 34421, 63677, 78434, 23715, 76017, -97318, -72924, 18065, 4034, -23281,
-        54580, 86547, 56129, -83460, 73366, 72206, -34461, 9060, 37920, -69703, 44360, 34134,
-        -58825, -3639, -80193, 37151, 38306, 82831, 85590, 27894, 97489, 98501, -51799, 17427,
-        -79168, 91758, 49208, -4133, -44652, 84580, 18988, 38350, 52147, 1225, 81681, 9990, 10674, 25109,
-        2833, 24261, -37267, 61898, 11547, 16294, 93586, 76583, 94343, 37235, 11553, -76532, 44587, 97425,
-        25134, 40734, 44480, 58350, 93509, 47779, -28994, 46898, 30953, 82951, 68036, 53497, 42420, 65125,
-        86194, 66562, 53858, -39516, 7266, -90749, 72413, 73927, 88279, 87618, 67415, 61277, 74701, -17073,
-        -97441, 90253, -98595, 43412, 56339, 40890, 78242, 28478, 75973, 52243, -99208, -43124,
-        94812, 91789, 44709, 23472, 89125, -87963, 10220, 74036, 5443, -94953, 32317, 10130, 85967, -85374,
-        -38854, 10879, -59821, 52896, 20440, -25748, 66197, 84152, 87002, 69097, 83754, -40216,
-        -68227, 41877, 13579, 90424, -18958, 33343, 24811, -63528, -90239, -69410, 92921, 77546,
-        71958, 59431, 50050, 32408, 3257, 94501, -82290, 51335, 97587, 96443, 50479, -52823,
-        -13592, 20115, 96206, 54501, 94831, -40078, -87163, 59740, -95426, 89533, -56789, 35269,
-        71462, 4721, 85472, -65480, -24785, 36849, 24672, 72306, 82389, 66023, -87415, 12832,
-        -53433, -70402, 99158, 47004, 26458, 65212, -43435, 55674, -91957, 58954, 59368, -52119,
-        -99049, -32648, -52622, 27762, 66010, 76178, 24590, 3154, 70769, 45834, 89262, 72184,
-        -88726, 43461, 83598, 51542, 50424, 17706, 73144, 1828, 65280, 89012, 6613, 49307, -65584, 21333,
-        -60722, 23865, 58549, 23315, 69734, 86770, 63364, 50730, 97252, 54754, 89406, 65326, 80324, 30914,
-        36696, 11213, -12862, -3565, -5674, 83245, -6700, 50390, 5955, 23844, 87953, -34088, 64643, 94904,
-        35792, 87113, 24532, 71775, 46428, 10751, -46894, 14067, -55580, 14762, 7403, 45805,
-        -29657, 75611, 65716, 55573, 30585, -99115, 64430, -2745, 26527, 39848, 13241, 496, 29997, 88295,
-        42861, 55603, -11476, -5653, 35918, 3297, 89960, 64167, 48239, -119, 61847, 42619, 95000, 9364,
-        30270, 68290, 68997, -48165, -65453, -7641, 32683, 82618, 13385, 4516, 25273, -62248,
-        40229, 23773, -78064, 8011, 50472, 60975, 91985, 27431, -20014, 98932, 19686, 47980,
-        -47046, 6323, 68819, 48200, 30583, 12070, 91698, -36844, -24835, 20556, 41318, 27095,
-        35570, 56797, -20419, 41591, 24952, 41567, 30895, 71282, 56888, 96736, 54248, 26746, 2556, -73328,
-        44901, 69838, 85829, -69232, 94509, -43465, -29558, -18527, 29435, 47919, 63874, 99608,
-        26874, 48507, 46790, 95659, 60266, -15327, 22369, -37826, 96883, -73193, -19004, 22589,
-        5109, 39966, 68497, 15477, 15844, 86415, 90811, 59124, -22423, 76331, 26827, 55579, 40240, -29559,
-        57763, 5761, 3656, 8438, 71013, 66461, 24802, 16483, -87538, 42300, 54751, 67380, 29426, 15314,
-        -8021, 41619, 44310, 70273, 43209, 73117, 54295, 79041, -86182, 19121, 36364, 45656,
-        -14063, 37041, 34912, 45353, -18756, 79772, 89423, 58694, -12381, 43649, -81149, 43239,
-        90213, 35891,
-    ];
-}
+fn random_array(seed: u64) -> [i32; 10000] {
+    let mut rng = DRng::new(seed);
+    let mut array = [0; 10000];
+
+    for value in array.iter_mut() {
+        // Zero is skipped so that the numbers can be used as denominators.
+        while *value == 0 {
+            *value = rng.next_in_range(-100000, 100000);
+        }
+    }
 
-fn random_array_2() -> [i32; 10000] {
-    return [
-        17448, 78363, -28250, 81820, -16365, 53384, -85473, 41082, 90083, 45939, -63826, 17726,
-        -26015, 95822, -55159, -42804, 73709, -18174, 46068, -98185, 25827, 39115, 18620, 90270,
-        -74808, 13047, 52921, 5507, 64924, 22151, 64004, 82256, -66928, 89030, -42724, 20915,
-        16645, 64689, 86651, 88657, 81378, 12294, -69734, 55241, -89950, 33145, 96829, 24670, 2861, -53671,
-        21822, 38700, 97948, 52477, -82141, 42469, -22581, -52821, -56552, 7255, -56081, -40086,
-        95057, -77623, 20213, -73874, -41590, -75568, 26920, 18364, -38780, 61293, 84701, -47850,
-        -3068, 62569, 5839, 18501, 5813, -24988, 41130, 85875, -21916, 23479, -38034, 86032,
-        -24533, -60043, 41955, -82533, 62561, 74076, 21691, -60579, 63243, -94961, 34179, -68575,
-        37682, 485, 3383, -4206, 58299, -11662, 78165, 52216, 39192, -22474, 2818, 32503, -13101, 67001,
-        -69098, 52448, 53540, 15298, -49080, -16763, 76436, 50124, 96632, 45897, 46602, -30364,
-        29903, 45474, 56675, -73368, 55462, 23522, 63646, -42556, 15987, 81073, 35902, 44793,
-        70291, 1036, 3982, 34015, 75784, 32226, 11012, 66419, -9085, 45875, -83040, 78278, 68236, 92840,
-        62249, -20819, -23784, 80297, 79115, 45888, 19398, 65465, 69071, -4034, 35599, -23860,
-        24740, 36132, -58958, 95606, 27590, 99385, 67502, 38683, -62042, -22480, 81982, 37863,
-        28767, 43900, -92636, 86669, -75462, -22473, 49760, 19890, 66753, -8993, 90185, 87015,
-        23128, 21238, 80093, -2124, 7342, 29883, -24485, 62344, -11737, 89354, 23958, 45167, 71583, -41738,
-        82141, 46010, 79346, 74934, 31660, 60045, 77080, -21189, -4385, -82937, -44205, 33454,
-        12937, 63298, 28429, 35596, 38082, 69153, 50276, 35586, 42247, 1104, -11583, -56791,
-        -49288, -53505, 22595, 98568, 59398, 62045, 54499, 2942, 98771, 33503, -78505, 94706,
-        96664, 30505, -22849, 99790, 41515, 429, 33097, 88913, 12016, -11602, 31898, -71866, 14512, 20292,
-        75198, 32519, 32738, 50152, 85016, -66667, -68509, 62759, 28441, 7941, 35609, 74452, 51517, 57281,
-        34554, 90836, 29672, 61316, 14601, -16839, -51629, -20012, 24222, 70300, 40737, 51584,
-        8912, 25524, 21110, 12956, 38677, 82122, 72847, 46520, -24014, 46697, 59205, 60722, -57623, 38891,
-        53739, 34697, 87996, 69965, 13668, 58843, 42346, -1342, 34479, 41009, 85951, 32808, 26132, 4763,
-        -5007, -28913, 20545, 4553, -17433, 38218, 95352, -99390, -17003, 6347, 40855, 71798,
-        76369, -19798, 26185, 94958, 44674, 20520, 22296, 94036, -77288, -26227, 64872, 38337,
-        92915, 93826, -19834, 68696, 67807, 47513, 83826, 80929, -17993, 69270, 2837, 78269, 45093, 37992,
-        76262, 99954, 4953, 96210, 70806, -51644, -38729, 22312, 35481, 84535, 58064, -70572,
-        -74205, -57922, 37114, -7202, 32718, 73940, 64493, 78688, 57259, 60931, 2449, -41367,
-        75026, -63896, -85360, 32948, 55490, -96763, 56481, -14180, 97315, 77932, -79193, 33697,
-        69469, 18886, 1186, 7782, 27247, 53194, -2806, 50966, 39677, 53529, -56084, 61338, 37501, 68808,
-        1470, 43050, 69868, 69792, 58994, 81058, 37980, 99204, -54795, -84307, 65596, -84402,
-        24723, 63436, 70289, -42934, 45733, 26690, -23458, 94305, -57280, 4616, 77519, 88392,
-        -6738, -41136, 22053, 70710, -39341, 29508, 62139, 96318, 67856, -26335, -7237, -90298,
-        81742, -53986, 1249, 91889, 75469, -40193, 46775, 67244, 3622, 91407, -81624, -115, 66456, 40749,
-        -2975, -51745, 34915, -34912, -72792, 73081, 91200, 50031, -30313, 71716, -96156, -11534,
-        74253, 64986, 8624, 60274, 59138, 74114, 64842, -48783, 87933, 7607, 22411, -75737, 90803, 95050,
-        55705, 79193, 39147, -28027, 54489, -39972, 20394, 17933, 56797, 45210, -46006, 50167,
-        78746, 29343, -53460, 6429, 40733, 99104, 99579, -64359, -31427, 57532, 12262, 18819,
-        -37243, 53720, -8583, 64495, -65749, 54819, 53702, 18773, 7937, -45218, 88471, -27968,
-        -3653, 48578, 91980, 70719, 62344, -92763, 83931, 16559, -208, 51229, 97206, 73902, 44862, 42166,
-        79127, 71502, -99100, 62922, 59013, 9498, 83932, 59485, -92908, 95575, 17003, 26774,
-        -80701, 22465, -2993, 61706, 72865, 64708, -96426, 77379, -83579, 63776, -16836, 4797,
-        -48309, 76532, 29526, 11771, 87257, 7926, 13132, 67208, 89487, 22342, 29799, 48486, 96239, 34158,
-        -69381, 65101, 98284, 26122, -6737, 21554, -4991, 98076, -1642, 29536, -34391, 49133,
-        93878, 90655, 97483, 63363, 62146, 94233, 50165, 46407, 65047, -44055, -61093, -74990,
-        -51146, 25950, -27698, 68264, 45010, 49401, 81470, 18001, 62807, -23366, -68130, 82301,
-        5982, 3849, 7927, 79555, -14303, 35605, 50849, 2476, 14780, 19126, -25734, 8722, 95345, 46805,
-        85605, 13557, 88549, 23871, 69158, 40770, 63380, 15773, -64921, -53961, 98252, 80291,
-        25129, 41527, 78658, 43867, 75503, 30920, 47955, 96341, 45747, -34685, 99087, 68044, 96958, -28137,
-        2267, -77544, 1404, 54414, -61736, 24344, -24956, 23736, 76751, 93016, 65302, 30886,
-        -33446, -2919, 1221, 14689, 21713, 17462, -54941, 19524, 65527, 15792, 51915, 60845, 25508, 51927,
-        11586, 159, 21455, 86129, 90582, 19887, 72601, 64109, 31863, 31178, 61868, 16304, 44311, 10169,
-        -72072, 45653, 75896, 86251, 55948, 52246, -59387, -76531, 49090, -42196, -15423, 67786,
-        6133, 37989, 17104, -35909, -91004, 92295, 10180, -78462, 40106, -10452, 73943, 25393,
-        82453, 25316, 61390, 34291, 96871, 42043, 59646, -9734, 98247, -59270, 94164, -80386,
-        88975, -6513, 64340, 31503, 77531, -2654, -52512, 75643, 40549, 90577, 59601, -13231,
-        -2537, 66367, 34568, 18602, -23236, 23782, 27073, -78335, 63912, 87400, 31027, 1083, 66388, 25394,
-        31401, 32698, -7529, -28594, 56591, 96531, 62644, 29108, 862, 70826, 16861, 10959, 71651, 783,
-        79893, -44171, 87704, 89919, 73918, 52301, 89518, -34598, -20464, 57711, 95493, 60170,
-        74621, 21801, -79984, 50630, 22309, -74644, 42630, -3742, -95101, 50508, 70909, 36273,
-        91802, 38440, 23473, 5949, 31540, 40600, 78800, 3929, 31104, 40952, 15399, 88241, 81155, -94760,
-        45839, -6120, 9696, 27396, -66626, 46629, -56770, 42030, 74345, 10176, -48612, 18497,
-        70810, -94603, 67758, 54659, -48451, -31692, 35406, -99850, -80001, -30488, 53931, -86659,
-        37310, 14324, 8129, 69859, 62346, 32435, -57347, -62061, 31000, 53893, 95182, 66672, 90321, 13373,
-        -4645, 46900, 69156, 18258, 97655, 34127, 4829, -41538, 89425, -50406, 38683, 6401, -72466, -63152,
-        48575, -12177, 28693, 66133, 21831, 18168, -57051, 42620, 96719, 90491, 29150, -50036,
-        85872, 69045, 13044, 8346, 15303, 36493, 40653, 62671, 27049, 87918, 35629, 6461, -69269, 52174,
-        22577, 95932, 60422, 28570, 50894, -19951, 87636, 54675, 16813, 72465, 30031, 92515,
-        -72092, 51116, 42434, -8674, 21005, -86198, -71238, 63269, -50597, 31759, -55163, 61190,
-        22507, 12514, 57416, -96915, -10681, 13829, 66468, -41057, 18969, -87341, -76180, 15738,
-        91307, 7252, -50337, -69899, -11942, 51059, 89450, 62546, 58934, 30987, 28892, 21143,
-        48349, 62290, 22704, 25900, 5522, -95003, 43973, -53476, -90220, 73805, 73736, 5600, 48095, 88531,
-        -95879, -5029, 30195, -58646, 27358, 97679, 40447, 96763, 44901, -28927, -98383, -12763,
-        80068, 75111, 15079, 81467, -98912, 33798, 15417, -95886, -77756, 3203, 61291, 41663,
-        -77064, 67921, 82181, 75275, -50901, -56841, 51251, 70612, -82647, 40219, 41573, 20831,
-        60662, 25857, 77655, 48002, 4446, -99602, 38839, 98465, 9415, 28495, -88067, 7090, 5119, 26974,
-        -78381, -15278, 37947, 79895, 6499, 50342, 29306, 26866, 17248, -73773, 42387, 82984,
-        75472, -96808, 14313, 83478, 83531, -5809, 61168, 79162, 4047, 24181, 88651, 30884, 11436, 32316,
-        8116, 30025, 47234, 88191, 48081, 86448, 23664, -70263, -29628, 5491, 49382, 72287, 97006, -8752,
-        -94768, 40561, 29375, 85560, 82774, 17558, 13242, 64649, -70254, 88320, 9843, -77844,
-        -13599, 52174, -37411, 72584, 73719, -11000, 49289, 64818, 86001, 4833, 95417, 207, -38637, 528,
-        64355, 93684, 52796, 68640, 74389, 93163, 36132, 55883, 28702, 1716, 260, -33112, 959, -77905,
-        96380, 75491, -99767, 94583, -64924, 13703, -22592, 92322, 85615, 19709, 15855, 10828,
-        -47223, 67679, 4231, 56106, -52259, 69609, 80850, 15334, -91970, -25021, 92867, 68437,
-        -11314, -72050, 20803, 51625, -63947, 27686, 79613, 65409, -12348, 90411, 71572, 16875,
-        -81731, -63532, 94102, -78195, 77022, 36634, 72873, 31722, -53685, 36614, 74431, -70114,
-        40902, 54003, -18929, -77904, -76540, 99346, 90034, 92736, -32769, 86690, -39848, 74155,
-        -59071, 76659, 37455, 78190, 44394, 14130, 69466, -49373, 30556, -16421, 60178, 65067,
-        -92866, 32474, 27797, 18606, 13080, 85352, 7827, -64772, -27237, 82232, 14215, 17833,
-        22129, 79119, 90560, -92284, -72292, 13418, 14136, 44668, 27000, -54025, -50834, -58933,
-        -59334, 38628, 42807, 32622, 66748, 87403, 67766, 64920, -83675, 85499, 92007, -26863,
-        4615, -89884, 88009, 5254, -72760, 80496, 19721, -26987, 41114, 88135, 51100, -15023,
-        -60783, 67045, 44410, -89498, -91377, 605, 18495, -49379, -55600, 34638, 29438, 23141,
-        18047, 58439, -82959, 31873, 37169, -78043, -19403, -30302, 65725, -82501, 93949, -6952,
-        95515, -23332, 75527, 85370, 57830, -7620, 60874, -98331, 38325, 42001, 96677, 34303,
-        -92389, 25070, -97631, 572, 6951, 18521, 88672, 62667, 422, -28740, 27295, 82138, 9212, -12812,
-        59074, 50119, 79363, 14368, 15456, -72220, 17292, 82205, 27206, -63513, 12226, -17354,
-        -43624, 41062, 45820, -95485, 1960, 22905, 68828, 16342, -5218, -93681, 41164, 81697,
-        93632, 46884, 26688, 25276, 2754, 7851, 97401, 37723, 42558, -82535, 46283, 38288, 74616, 64297,
-        68384, 40939, 22476, 71417, -5846, -60956, 72014, -46435, -27631, 48271, 30608, -73379,
-        13299, 66125, 4336, 37102, 61713, 86226, 40365, 6415, 47483, -14556, 38906, -76653, 59371, 9138,
-        -9890, 35526, -34572, 78674, 96824, -78318, -27193, 76764, -56417, 57592, 21929, 96897,
-        73147, 18500, -63911, 21740, 63339, -13370, 4427, 18430, 56378, 17281, 78031, 81856, 38059, 95477,
-        12785, 182, 62253, 58572, 32495, 90013, 44394, 54764, 97422, -27607, 65508, 15681, -77617, 70608,
-        24393, -28123, 50193, 83304, 34323, 4910, 45290, 55979, 4375, 35842, -10378, 75761, 55105, 93692,
-        23301, 59739, 73323, 51848, 67874, -45688, 57798, 8226, 14007, -13240, 62709, -7282, 13126, -63676,
-        17522, 3036, 6817, -87798, -12397, 87678, 440, 50667, 79714, 98094, 70065, 30623, 35514, 14395,
-        51031, 14435, 4875, -56103, -63147, 34478, 11683, 78433, 74512, 93856, 1290, -47230, 3261, 85215,
-        60438, -56517, 51497, 871, 43887, -74744, 98890, 89649, 67602, 28697, -6965, 14674, 65060, 96809,
-        15268, 63646, 59653, 46902, 59841, -78210, 28348, 31459, -65417, 85085, 99758, -58692,
-        49390, 32646, -23712, 31960, 44379, 88401, 33318, 77122, 84956, 22265, 148, -17193, -33655, 11641,
-        -96048, -90167, -85648, 23246, 23877, 51677, 78024, 89423, -1889, -76981, 65034, 6198,
-        78481, -41407, 28710, -71231, -73599, 63080, 32297, 63483, 75239, 72122, 70913, 56757,
-        40570, 40755, 38588, -5741, -63050, -11536, 92385, 73563, 38697, -70931, 45235, 4644,
-        -62349, 35483, 91921, 78153, 67815, 15727, -45479, 17211, 96732, 86641, 55435, 47231,
-        75302, -1803, 7982, -50067, 88740, -90185, -93997, -2864, -167, 41986, 96660, 43287, 12501, 40485,
-        -99659, 70840, 15939, 67133, 88983, 73909, 67761, 39006, 78216, 27002, 43733, -29872,
-        49176, 42615, 85560, 14729, 77024, 82865, 39243, 9520, -92820, 42735, 46867, 12927, 62442, 8472,
-        32265, 18910, 40500, 82828, -85296, 91184, -57764, 43785, 23492, 97744, -83396, -66125,
-        63144, 96266, 18102, 49998, 36845, 32395, 17556, 75113, 31886, -45776, -28451, 51087,
-        76994, -24974, 89498, 88887, 56280, -3486, 76519, 54768, 1013, 72400, 63146, 31869, 65099, 52668,
-        2845, 88555, -5132, 24823, 38559, -71058, 41862, 68541, -29637, 43931, 98232, 74345, 58717, 24723,
-        52164, -21865, 28336, 73201, 58862, -93738, 89835, 91701, 23418, 26634, 39579, 8989, 71567, 74045,
-        84647, 53565, -51482, 97379, -74210, 69372, 52608, 46239, 45976, 85660, -7860, 85578,
-        33754, -22183, 59020, 52141, 57764, -91815, -2159, 46851, 27608, 34157, -84836, 59781,
-        44560, 79637, -22617, 34847, 16562, 63131, 43959, -92428, 30321, -83542, 56770, 87975,
-        19491, 80640, 3648, 92122, -46369, 89337, 79116, 54916, 10108, 11659, 51078, 78784, 86617, -2718,
-        54872, 40791, 7617, 84573, 62311, 15975, 14901, 20362, -14209, 15792, 23628, 69975, 42079, -76320,
-        89404, -92786, 66594, 46721, 76303, 21128, 24585, 91867, 63735, 35053, 2349, -91049, 31184, 25121,
-        32147, 84919, 37391, -74424, -71986, 5608, -78947, 84787, 83394, 65254, 95883, 39040,
-        15453, 66838, 32278, 29934, -25823, 27808, 16912, 57084, 97859, 58592, -78594, 7926,
-        -97141, -83485, 11644, 16999, 56909, 5558, -96533, 93732, -8509, 96674, -60654, 81493,
-        74324, -94118, 65982, 45410, 52916, 19563, -93526, -21805, 59463, 48372, 19029, 45137,
-        19583, 42349, 96958, 25795, 20268, 86016, 40046, 90905, -48710, -98172, 44732, 10716,
-        38559, 73050, 54281, 64103, 43881, 44508, 28467, -84884, 6873, 43232, -62541, 7040, 25984, 44564,
-        2984, 64259, 52883, 1984, -21098, -83028, 53322, 83200, 45999, 31436, -95273, -3126, 87311, -58085,
-        24721, 73457, 2583, 25762, 58363, 31486, 685, 40543, 1903, 99537, 90256, -63750, 19397, 68337,
-        91565, 27385, 1540, -49104, -6446, 99588, 96754, 77336, 25331, 51952, -11937, 33908, 29074, 47006,
-        83992, 71253, 90135, -908, 69619, 34073, 60100, 6267, 35912, 85691, 69533, -50718, -15477, -1686,
-        27763, -70094, -66177, 79421, 77282, 233, 67431, -64788, 42006, 87010, 56380, -84816, 8154, 29766,
-        29189, -57197, 87366, 90218, 5793, -32202, 91687, 90121, -79106, -61877, 40132, -26491,
-        89904, 33344, 40053, 42770, 47899, -37302, 85854, 90528, -20410, -54838, 80513, 64729,
-        3218, 1855, 24652, 89311, 65949, 24211, -63160, 37948, -38525, 21566, 5127, -90186, 68118, 79738,
-        33326, 97514, 25919, 58716, 10650, 34545, 17608, 91286, -2926, 45793, -35371, 71929, 48457, 61562,
-        -80927, 20969, 15146, -19680, -95792, 99203, -14298, 23097, 17218, 71716, 85057, -21223,
-        60018, 75027, 79222, 27384, 18756, 29723, 79225, 28910, 40911, -55596, -4899, -35252,
-        31689, 64542, 43032, 25365, 25454, 28099, 28868, 51, 30596, 98859, -14956, 66513, 82931, 83626,
-        35362, 44616, 79077, 31444, 26982, 68534, 77634, 36231, 23404, 98256, 20792, -45193, -3844, -32792,
-        19844, 24699, 15345, 81062, -20740, -72150, 32386, -3559, 9207, -23222, 56476, 73658,
-        35050, 37133, 24187, 86850, 18911, 70206, -9905, 56312, 85001, 29967, -39484, 55078,
-        -14504, 72603, 39844, 74284, 25052, 17338, -48774, -91936, -91861, 98060, -63529, -78388,
-        91687, -54463, 81665, 99072, -27680, 37439, -68162, 76938, 27903, -58857, 78136, 13873,
-        72024, -18506, 23889, 55251, 40613, -38541, -73642, -56790, 65889, 14173, 61776, -45567,
-        73023, 7057, 18286, 53709, 10501, -2728, -56696, 1362, -90084, 34157, 96244, 700, 23396, 24524,
-        -60414, 40538, 77643, -75806, 50312, 55957, -48889, 77800, 20087, 78053, 7224, 85981,
-        96388, 73572, 71198, -29815, 34558, 43657, 52705, -36041, -82859, 63065, 74138, 86970,
-        24544, 30074, 65805, 47053, 14451, 99151, -84559, -59299, -98929, 8629, 89355, 37939,
-        93647, 92628, 75945, -21513, 6976, 34216, 76874, 99225, 78441, 95758, 92918, -58989, 23299, 7094,
-        31008, -5927, 94354, 2504, -94547, 45903, 44738, 7738, -13860, 75672, 18510, 22547, 99067, 9559,
-        -45801, 56404, 5945, 72145, 54044, 85793, -27609, -24498, 58716, 19275, 9561, 2881, 78219, 39262,
-        97886, 49899, -23012, 95555, 37252, 90906, 38824, -27446, 34919, 44353, -50371, 92027,
-        37061, -55505, 27524, -8537, 18532, 82022, 61611, 17324, 68436, 13445, 47692, 57361, 70109, -97931,
-        2285, 50267, 91473, -81933, 11761, 27794, 20848, 62114, 95494, 11900, 84709, 96575, 5513, 80073,
-        33937, -37136, 11559, -28122, 81398, 83860, 65756, 91305, 23983, -88046, 1874, 69974,
-        88321, 98269, -21849, 93087, 56117, -34423, 93277, 78537, 2034, 15955, 64325, 24832, 773, 39572,
-        7180, 13464, -6120, 5057, 728, 95439, 60824, -8001, 48203, 53130, 98767, 28687, 34359, 26011,
-        71079, -50389, 27733, 4541, -78164, 80440, -67406, 27654, 19717, 56028, -95197, 55053,
-        69241, 75903, 67343, 56362, 55600, 27751, 67818, 86283, -32618, -30176, 23156, 84586,
-        34699, 55630, 28287, 85840, 84738, 13806, 33184, 28819, 15821, 13570, 83775, 52936, 90740, -6825,
-        -26849, 10221, 13364, 50483, 23520, 19033, 19709, 81417, 58835, 86157, -36754, -99818,
-        -47704, 50467, 73209, 9842, -55045, 3835, 435, 99210, 20573, -47752, 69497, -92980, 33611, 21855,
-        -53394, 45941, -59731, 61912, -54411, 65374, -58898, 33967, 19473, 8920, 64832, -79913,
-        25977, -8492, 31693, 37428, -32314, 21544, 73525, 57333, 93426, -33365, 24187, 5275, 93971, -65252,
-        35186, 50606, 3889, 81635, 28709, 55076, -10928, -25942, -90150, -34950, 76655, 21659,
-        42861, 57676, 48343, -7440, -28842, -67764, 85449, 83987, -27948, 67342, 82945, 46471,
-        22916, 65693, -33997, -46768, -73963, 87175, 82113, 47311, 82264, 89466, 70164, 99435,
-        96792, 29960, -51397, 54690, -11198, -39887, 72854, 89342, 24219, -86586, 44780, 56701,
-        81974, 64181, 30858, 46096, 73980, 32955, 77753, -42597, 63147, 31109, 46195, 57512, 39284, 39141,
-        11270, 97506, -89214, 4727, -44167, 1757, -90791, -90043, 78592, 74945, -8044, 77038,
-        85916, 2783, 85600, -9039, 11712, 81636, 43016, 20733, 4218, 84282, -68804, 35003, -85880, 6579,
-        -24635, 42798, -55249, 9701, 68129, 73627, 41835, 26436, 97334, 55586, 72075, 35414, 16612, 98432,
-        98082, 82932, 37, -44884, 47535, 17913, -32109, 94181, 56376, -55339, 42542, 33991, 90291, 10121,
-        13886, -23795, -93129, -72803, 9472, -59141, 54536, 56970, 18765, 70121, 72029, 18206,
-        6315, 92994, 38030, 15714, 47369, -96187, 44186, 2765, -92893, 49116, 7260, 37322, -80534, 2716,
-        -47605, 57802, 81478, 13209, 48094, 83136, 60383, 76340, 40907, 3341, 88797, 11793, 69, -76093,
-        27578, 1147, 82296, -95826, -53842, 5847, 75293, 3948, 12085, -80317, 3674, 3634, 74103, 40148,
-        93720, -22470, 22958, 76002, 31799, -33684, -22947, 89482, -97580, -17100, 4226, -93290,
-        82259, 23736, 29508, 54765, 9274, 21405, 92528, 90404, 2074, 45734, 58195, 14580, 45255, 78601,
-        93972, -91698, 29079, 27659, 21154, 72000, -27752, -21884, 24682, -89650, 62191, -38066,
-        67797, 23796, 5556, 48726, 34379, 37184, 79817, 34816, 56758, 72957, 7787, 60611, 88574, 74541,
-        55388, 58148, 97632, -35761, 10173, 1988, 96877, -72668, 91762, -52242, 35498, 13909,
-        -21563, 74512, -72369, 94448, 62793, 23876, -21629, -79098, 82477, 47650, 36847, -28285,
-        53206, -27933, -13341, 98578, 87662, -44692, 41760, 39592, 39594, 38821, 68942, 20064,
-        -18064, 44979, 71374, -71121, -19190, 15741, 15322, 86514, 55577, 370, -41102, 99036,
-        21301, 82006, 58623, 54005, -90190, 82128, -95245, 63264, 9423, 63492, 3154, 45589, 66396, -6210,
-        11091, 86724, -45501, 6648, 77947, -21325, 95702, -51262, 56533, 85586, 13433, 80653,
-        16341, 72906, -31401, 77482, 94903, 60753, 80595, 31062, 12447, 50931, 92007, 52963, 65840, 97491,
-        76987, 28087, 17160, 45310, 39413, 221, -31977, -41108, 68501, 37791, 65379, 89177, -56823, 87505,
-        82389, 6001, 99307, -85087, 9565, 15423, 62213, 69944, 69785, 2887, 23480, -27491, 29596, 35752,
-        -24691, 78427, -56958, -4814, 36901, 12258, 19958, -16179, 47143, 84354, -6372, 14679,
-        -89217, 75472, 62209, 68272, 19884, 86563, 8373, 62310, -38601, 71756, -71026, 47233,
-        -84338, 95789, 24839, 72104, -72986, 7120, -1602, 59758, 33646, 19796, 13547, 46055, 40086, -21670,
-        -17423, 78183, 11247, 83651, 81457, 21949, 76460, -26035, 93574, 57032, -40662, -1700,
-        9834, 22992, -15082, 65795, -76487, 94264, -49351, 32281, 94227, 82591, 6010, -80352,
-        33341, 70678, 42559, 78676, -77650, 39161, 27967, 13183, 26018, 14104, -47267, 99800,
-        50418, -85018, 42479, 33047, 40495, 42307, 34190, -85738, -74049, 81548, 91652, -41616,
-        72941, -59798, -50126, 58246, -32310, 63271, -24674, 56293, 19237, 72842, 34855, -35494,
-        60892, 30654, -27013, 10365, 89029, -87982, 59355, 34939, 16967, 10947, 90352, 81172,
-        43987, 73504, 1550, 94973, 63167, 4684, 73017, 1722, 51815, 59736, 36752, 40211, 51570, 3620,
-        77928, 33540, -10065, -97593, 56091, 60580, 2404, 7851, 68969, 5025, 39486, 45232, 73303, -80393,
-        41610, -68011, 75808, -21307, -9795, 65040, -55120, 89497, 76066, 25973, 64242, 31846,
-        28324, 81901, -79739, 92289, 81224, 79870, -68552, -90985, 85306, -98326, 96893, 6916,
-        43241, 47354, 53447, -28516, -71738, 67787, -18947, -51631, 66871, -66955, 14140, 28960,
-        -4061, 45659, 78838, 37787, 11234, 7777, 57500, 16514, -27337, 99077, -86582, -14082, 7857, 4306,
-        60787, 94010, 50111, 94534, 94386, -39444, 47037, 55470, 18898, 53720, 75263, 91213,
-        -50533, -3651, 24022, 6020, 57513, 6373, 97254, -41839, 91077, 9221, -99688, 64511, 66665, 58372,
-        -78219, 79131, -98535, 62527, 74761, 7022, 66755, -34616, 81064, -21526, 68638, 79379,
-        21148, -22391, 65627, 80716, -83786, 84859, -20702, -61320, 18610, 62510, 33858, 42732,
-        33807, 43256, 83212, 2403, 24169, 28612, 94525, 8580, 60459, -62803, 53084, 29791, -90233, -14630,
-        15520, 43165, -93130, 40536, 93569, 65167, 28057, 5851, -83887, 68724, 4799, -94513, 60913, -49146,
-        14371, -93826, -68855, -33597, 11354, 88651, -13297, -39249, 84850, 68281, 88085, 78414,
-        82614, 52198, 67818, 75525, -50977, 61449, 54461, 62711, 79994, 27985, -36663, 91437,
-        -70798, 6020, 25944, 91306, -98678, 59456, 85637, 4768, -77305, -78304, 14129, 50016, 913, -93364,
-        -23844, 45313, 15621, 5953, 22796, -49658, -52538, -14429, 19671, 38529, 21091, 69901,
-        -68523, 10703, 94863, -59123, 18049, -78997, 20291, 41938, 4769, 12299, 58696, -93121,
-        -69028, 51991, -36716, 27322, 97207, -25814, 89130, 4956, -20900, 32900, -67497, 99710,
-        62250, 49296, -86987, 59157, 21831, 76918, 86024, 42467, 72115, -92431, 47978, 80237,
-        11281, 5972, 1170, 9324, 95283, -5755, 57629, 18437, 55574, 97589, 7747, 53523, 68578, -89829,
-        60042, 64972, -33929, 9072, 44989, -8526, 13584, 67267, 26544, 74762, -44740, 56944, 2511, 44123,
-        45527, -17020, 3226, 7298, 3588, 47320, -16661, -72590, -84555, -15442, 57388, 62570,
-        90634, 74623, 68652, 91322, -12464, 64431, 68228, 82778, 76394, 40431, -58902, -41050,
-        93604, 36217, 52545, 63381, 30781, -33822, 13884, 70956, -58452, 41023, 78517, 46112,
-        15691, -98877, 27979, 26664, 90439, 39166, 60902, 60264, 14351, -23807, -75316, 98048,
-        77466, -2391, 46185, 74987, 85479, 51510, 78511, 47548, 35185, 37157, 22246, 75512, -39281, -78917,
-        -16319, 12098, 32540, -6433, 38381, -37178, 45543, 61661, 89785, 63437, 83662, 31030,
-        -3485, -66697, 99576, -77603, -85419, 42120, 801, 32194, 50339, -75747, -42379, 90552,
-        -8433, 16478, 71044, -81534, -51241, 6139, -10518, 21466, 89991, -60767, 43975, 25982,
-        -27842, 58296, 12064, 54906, 64986, 20958, 21567, 88457, 55898, 35653, -43220, 70466,
-        91190, 53539, 52986, 56920, 62743, 75766, 4700, 92634, 40516, -12520, -97376, 87513, 88184, 39691,
-        -74285, -30348, -37660, -95200, 34878, -2138, 85694, 76943, 1645, -77990, -76670, -76807,
-        35624, 79478, 31032, -61095, 93724, 15904, 68402, -96787, 52420, 58452, 80929, 12046,
-        47122, 1529, 67842, 79206, 79352, 48838, 24649, 7668, -95271, -70952, 60358, 64490, -72192, 77818,
-        -43235, 31434, 408, 84049, -96529, 28732, 90351, -71399, 29088, 95538, 15291, 89911, 5940, -69082,
-        -5830, 20595, -22844, 42540, 35031, -37467, 48246, 63765, 14478, 73156, -2053, -94766,
-        36419, -88532, 15860, 77589, 84229, 66937, 34524, 25846, -20455, 18232, 42745, 72342,
-        71230, -70904, -93532, -94319, 41225, -42356, 88585, -69375, -26452, -92174, 36955, 17240,
-        90007, -40461, 51097, -27819, 52506, 51871, -68429, -25454, 36104, -62495, 45010, 93907,
-        75772, 15440, -86208, 2249, -70548, 97709, 7688, 67661, 83560, 93735, 13441, 57326, 51160, 57937,
-        9349, 94024, 25230, -81578, 94903, 11962, -84275, 21092, -65206, -81949, -46101, 67234,
-        78232, 43174, -51601, -33234, 93528, 7399, 51225, 52843, -73431, -3170, 33262, 40717, 2114, 19080,
-        -91012, 13221, 18021, -50201, -52483, 839, -5345, 88003, 49517, 92623, -40807, 7623, 23416, 14856,
-        -82507, 11837, 92998, 83603, -53576, 65178, 1968, 48921, 52574, -1214, -21648, -39599,
-        52864, 40636, 11634, -68012, -49927, 37933, 78330, -33633, 21196, -41177, 35502, -98657,
-        -9372, -29439, 91915, 99980, -10560, -59615, 2751, 27981, -34051, -27832, 5706, 1573,
-        53534, 77702, 37660, 14272, -22246, -54529, 30640, 82486, 48264, 67219, 21239, 21029,
-        -57287, 11145, 90168, 52311, -4277, 15334, -272, 8568, 75049, -96498, 40066, 63325, 94419, 39834,
-        75367, -1623, 97226, 5104, 8652, 85049, 71811, 86533, 11079, 75884, 61224, 83779, 77314, 72735,
-        48116, -12991, 41936, 17677, 54054, 10524, 71788, 50498, 61126, -2658, 75244, 1264, 7027, 74010,
-        -24589, 68058, 27719, 34667, -38918, 57804, 40563, -55500, 38920, 3933, 15465, 7834, 46914, 28503,
-        -85165, 98781, 7041, 11244, 86059, 62253, 78482, 72379, 77176, -42703, 31370, -49238,
-        29031, 58916, 34535, 98485, 99811, 21165, 56729, 78561, -3533, -5998, 41516, 16277, -43931, 26030,
-        51895, 72478, -53550, 34499, 46523, 62699, 97658, -95760, 29463, -38177, 72519, -11571,
-        18548, 92430, 10433, 80167, 12178, 84768, 35181, 45567, 93548, 43365, 9431, -19564, 25828, 97909,
-        -65347, 13544, -69878, 90303, 29282, 1598, 47072, 87859, 41325, 77977, 68693, 95350, 22056, 51790,
-        84125, -29073, 97078, 49804, -56833, 60225, 37839, 45380, 83400, -65278, 15781, 63825,
-        -67178, -90493, 76851, -59788, 7511, -94882, 92435, 18399, 78409, 2485, 28021, 61794,
-        -31846, -46486, 69462, 88365, 21053, 23484, 46969, 12614, -60572, 45553, 2943, 21592,
-        51317, 14743, -63190, 57897, 96338, 63117, -95611, 95928, -3804, 80007, 55835, 47415,
-        -99776, 59957, 50395, 46745, -5454, 23247, 60965, 30877, 35965, 65974, 53269, 27806, 564, 57334,
-        33533, 22227, 76231, 25916, 89028, -5227, 85063, 78987, 99038, -7765, 82859, -14172, 33396, 54468,
-        -60280, -29027, 29432, -2859, 46298, 13260, 22076, 77402, -24635, 84034, -87307, 62204,
-        -6142, 13029, 67058, 56973, 24717, 26061, 65733, 15541, 52539, 28408, 94584, 37253, 66208, 30999,
-        -84074, 12525, -34620, -13677, 26411, 53922, 11748, 13367, 65274, 46445, 96396, 76452,
-        -8718, 20701, 97522, 83251, 80187, -29592, 89460, 26573, 78691, 97178, 92963, 47828, 6949, 60159,
-        -87988, 92690, -24978, -90997, 38920, 79837, 83330, -70408, 85204, 9118, 11187, -21200,
-        64738, -1115, 49258, 30167, 71044, 21093, 67439, 31684, -86935, 63962, -51907, 77760,
-        97768, 96309, 90593, 92558, -2029, 35417, -5925, -61072, 38421, -83603, 17982, 5074,
-        -68704, 63221, -72472, 37125, 2117, 15059, 4046, 62063, 99675, 48641, 7653, 2020, 98509, 30133,
-        45668, 52159, 68351, -6093, 54188, 63938, 17000, -71910, 87304, 21590, -29237, 52926,
-        58843, 46686, -49138, 94533, 98619, 6183, 15289, 39305, 98575, 39079, 97267, 30022, 19628, -80371,
-        51553, 10438, 94847, 40349, 97130, -92763, -28865, 82366, 24666, 33403, 79192, -67140,
-        43929, 76380, 95198, -48648, 43368, 19723, 53979, -80870, 5021, 47152, -84638, 53834,
-        81958, -91169, 5565, -68418, 61174, 31314, 97080, 62020, 8275, 79679, -74502, 98385,
-        -52071, 60465, 70317, 68047, 44478, -65616, 14575, 94404, 30451, 19357, 47192, 16101,
-        40909, -66013, 88294, 20577, -98356, 43475, -76830, 72463, 10969, -26655, 32593, 32101,
-        73189, 21652, 46291, 39504, 28356, -88135, 11333, 32975, 1031, -33080, 68700, -27748,
-        72439, 71219, 23262, 60459, 46421, 25168, 57173, 27677, -43269, 89860, 90836, 33012, 18794, 28289,
-        63978, 86229, 15786, 8539, 39972, 33502, 55183, 72763, 44995, 70156, -76871, 75418, 6119, 36810,
-        -1671, 76027, 41286, -16041, 439, 3771, 84872, 15437, 40038, 13946, -99095, -1015, 25666, 20545,
-        99707, 97585, 49931, 63610, 57903, 79024, 78294, 61383, -54924, 96041, 23172, -18772,
-        60567, 94407, -61599, 15943, 82574, 5474, 33489, -34608, 50711, 72872, 23249, -39222,
-        79488, -15361, 85937, 56548, 29263, -25698, 59447, -62797, 18964, -17944, 58846, 54071,
-        5508, 98269, -99543, 16221, -70487, 53661, 5807, -18118, 13092, -61458, 8354, -3414,
-        -80770, 17900, 60958, 81908, 76589, 5575, 33885, -25653, 33202, 97794, 10962, 54524, 52507, 25925,
-        69128, 70899, 99930, 23203, 13342, -71263, -6973, -19796, 55917, -4346, -76690, 54722,
-        -56558, -82316, 10345, 68506, 64958, -61700, 18789, 37118, 93106, -55381, 17533, 26863,
-        23726, 19303, 9045, -99088, -55066, 70733, 40875, 67259, 60308, -58319, 63590, 73051,
-        83390, 88773, 10686, 75897, 79938, 26535, -22317, 31365, -6045, 63718, 92631, 72407, 61660, -11836,
-        -51675, -5726, 91221, -29271, 62117, -61347, 77068, -9500, -49305, 49556, 93923, -2780,
-        44474, 43710, 67816, 71333, 32190, -30999, 34048, -77944, 46570, 88866, 73193, 86306,
-        15217, -26808, -53842, 55860, 29462, 2744, -2596, -63479, 62975, -64906, 93223, 75916,
-        83432, 35699, -8690, 88257, 14872, 8037, -94826, 78736, 59998, -76134, 94229, 28870, 81415, 76560,
-        17189, -53317, 62830, 36692, 98811, 32537, 33089, 29128, 54114, 38072, -4632, 76863, 17167, 98406,
-        12203, 78280, 50664, 8310, -81495, 62840, 8161, 87390, 87269, 93151, 22379, -66162, 99217, 90119,
-        37293, -64505, 11784, -61796, 76004, -87807, 17752, 667, 18715, -15936, 9776, 70512, 89159, -54543,
-        -20888, 65474, 83416, 9738, -97426, 73587, 5918, -99965, 70829, -42778, -92345, 26358,
-        35875, 31881, 39427, 94263, -84442, 81052, 58615, 19237, 44922, 87043, 69003, 73550,
-        -67909, 56391, 16764, 14634, 98142, 91622, 32332, 37798, 47348, -32956, -63776, -92603,
-        -54711, 18373, 48473, 75414, 13001, -511, 36853, 73169, 6168, -21301, -72556, -84931,
-        90854, 42934, 16975, 2819, -40424, 11998, 98950, 35356, 58094, 52733, 59861, 60020, 27205, -60255,
-        11549, 70788, 72273, 24375, 34812, 72897, 16205, 72530, 34088, 23471, 27117, 44141, 73453, 66452,
-        87450, 93292, -25130, -16111, -10087, 23232, 35915, -25965, -98016, -43386, 45404, 31057,
-        56593, 52939, 6346, 99724, 28846, 7312, 70626, 71485, 38984, 14246, 9693, -11, 39069, 32183,
-        3392, -13455, 73259, 22922, 43292, -40434, 59576, 9458, 41117, 21388, 3627, 82842, 58117, -71954,
-        -76498, 5269, 48956, 12258, 57991, 87643, 24045, -45945, 99090, 47884, 4415, -99234,
-        -23192, 80830, 38108, 69049, 33257, 75873, -25191, -88856, -77373, 51647, 31398, 19273,
-        -85021, -48071, 74882, 4333, -86129, -54040, 44542, 24048, 60491, 52901, -74756, 65719,
-        4683, 82894, 37235, 19658, 14028, 17926, 59684, 36784, 48874, 44220, 66553, 62498, 48022, 38311,
-        57515, 46232, 89304, -3168, 28391, 5885, 25303, 79586, -73880, 91487, 95910, 60732, 48365, 86716,
-        15423, 50847, 76021, 10917, 75591, -53697, 59776, 47682, 45346, -1936, 30987, -61661,
-        17134, 54898, 64835, 61823, -84871, 61197, 51650, 38993, -30516, 97333, -36692, 99105,
-        81027, 2941, -40429, -88808, 56246, 24080, -49615, 99763, 36493, 72579, -39988, 32424,
-        7020, 33154, -90326, -6021, 8460, 74521, 67726, 51723, 17955, -32095, 5652, 73623, 54447, 88106,
-        3833, -21884, 67221, 81462, 12785, -67486, 69566, 14949, 30641, -98099, 65966, 76852,
-        65626, -83718, 87679, 24334, -31503, 26794, 63361, 76676, 51210, 37536, -69049, 11770,
-        75009, 12852, 84908, 3246, 8290, 4302, 70189, 14735, 56398, 54473, 42669, 7173, -82534, -61881,
-        2179, 62811, 10699, 93086, -4407, 18500, 29702, 60749, 76351, 3689, -49079, -13758, 24641, 19759,
-        30749, 96524, 3834, 70663, 82623, -78453, 11135, 34707, 32786, 91905, 64579, -35875,
-        -30668, 99924, -72586, -18807, 24014, 19329, 36489, 40309, 71196, 99854, 58974, 66155,
-        72735, 4286, 50980, 78623, -76877, 7667, -60476, 29429, 74623, 75744, -42572, 32937, 459, 87734,
-        -75445, 13229, 47484, 33055, -58107, 81583, 82359, 57413, 64214, 26634, 38741, 42867,
-        -65198, 28290, 89750, 16028, 70720, 74565, -36339, -60832, 66357, 48279, -66773, -97512,
-        44577, 71960, -48637, 13741, 24312, -43842, 60575, -42632, 71043, 61837, 57582, 52335,
-        14574, -94150, -41556, 87293, 1553, 36690, -55847, 81970, 87906, 34223, 29669, 30356,
-        -98498, 28036, 387, 31578, 66919, -65365, 92071, -57160, 14673, -59845, 27253, 14372,
-        55088, 59680, -3895, 90431, 34944, 1161, 40446, 2869, -53112, -21182, 20208, 47282, 82202, 48538,
-        87165, 21118, 81708, 11478, 60111, -62131, 6894, 37450, -9315, -85839, 16283, 91296, 78335, 82163,
-        -17735, 85571, -56913, 8419, 39933, 83655, 59536, -97720, 88698, 97200, 18755, 23095,
-        -48184, 13308, 75182, -75340, -69336, 65945, 50238, 35834, 51526, -2924, 28163, -83713,
-        96531, 1461, -74946, 31650, 84709, -26949, 56695, 31599, 7170, -32422, -61597, 43932,
-        -81003, 7486, -43850, 20078, 18137, 37698, 65579, 57439, -12155, 43416, -17716, 61807,
-        20523, 39049, -19536, 46009, 82738, 98775, 55718, 37560, 18728, 51239, 11096, -49508,
-        -94480, 9724, 37002, 60908, 12414, 39366, 83869, 3427, -16105, -59219, 23561, 10800, 50986, 34008,
-        21988, -80387, 93479, 98473, 2001, 65935, -16142, 27761, -56649, 63393, -19755, 54700,
-        71300, 75312, 47988, 63009, 82328, -87941, -89236, -41893, 94218, 16840, 61841, 75036,
-        17062, 12956, -41682, 94277, 71855, 93225, 71323, 3264, 82077, -58716, 78929, 40744, 59021, -765,
-        39612, 33608, 92127, 18190, 48544, 44323, 37841, 46863, -5937, 7026, 95216, -36899, -36943, -87679,
-        86595, -27234, 48527, -86750, 31672, 73544, 681, 69627, 7873, 7663, 23375, 59350, 96076, -80982,
-        72904, 74203, 81079, -99630, -39694, 11725, -82871, 29017, -74525, 73894, 24478, 86528,
-        57475, 28324, 12238, -75334, -88243, 28929, 59397, 60732, 66751, 34640, 27773, 74029,
-        -46679, 61781, 26191, 19669, 34867, -26835, 58676, 71937, 47290, 85939, -45136, 9432,
-        84630, 36639, 88438, 90624, -14038, -3537, 24334, 38341, 40818, -97593, 82159, 71530,
-        89237, -359, -73511, -4586, 67797, 27425, 64172, 417, -83832, -81069, 1020, 55134, -98704, 86775,
-        20709, 6628, 10669, 22138, 61470, -62681, 48960, 56226, -34133, 63460, 54127, -20153,
-        73687, 97450, 23659, 55889, -7685, 62319, 6043, 91332, 6869, -89474, 29981, 77115, 12044, 24977,
-        14449, 42247, -70949, -69719, 21010, 65025, 4609, 65025, 98653, -86717, -8718, 96201,
-        -32951, -94266, 67738, 7476, 76134, 85353, 57722, 80389, 12462, 88384, -25506, 22766,
-        52735, 31089, 84993, 86651, 57134, 18073, 17930, 9077, 49410, 56790, 77096, -25402, 26983, -91571,
-        63975, 86234, -42091, 2795, 14985, 63819, -41216, 78605, -93076, 67568, 1782, 89263,
-        -71887, 66636, 18705, 11, 87624, -60127, 87882, 14253, 92645, 88129, 7631, 98240, 28294, 74415,
-        91478, 95935, -4137, -38948, -28759, 11863, -58627, 44489, 91315, 49354, 3950, 42075,
-        45706, -707, 93482, -96090, 54777, -22103, 9419, 89973, 90778, -77255, 48918, -64510,
-        77239, -18131, 43553, 41067, -39369, 90967, 5982, -52906, -13097, 67968, 83036, 18825,
-        64648, -77255, 98047, 62686, -19502, -63061, 63664, 92653, 10389, 66788, -47010, 18572,
-        14433, 59499, 14579, 54616, 44142, 31058, 59192, -12517, 31302, -6903, -44818, -14543,
-        -86120, -27588, 50826, -90950, 9545, 55000, -66901, 82671, -7237, 10368, 79207, 15088,
-        53096, 98407, -34992, 15431, 65506, -99770, -24885, 37235, -6166, 396, 18332, 80096, 71943, 6868,
-        -7227, 67662, -3348, 45838, 79288, 38161, 50798, 89749, 89382, 50972, 89363, -59520, 66944, -28766,
-        60458, -36707, -90761, 18865, 78846, 94956, 62470, -98706, 14765, 26760, 39338, 48829,
-        95337, 20453, 70961, 51408, 83461, 72069, 74927, 18163, 16913, 60243, 51521, 37512, 98125, 4989,
-        57450, 27330, 71585, -88105, 47302, 28492, -61621, 3389, 2476, 65056, 88852, 38467, 28116, -1197,
-        -91467, 22234, -10334, 14870, -53050, 4024, 61496, 4886, 10788, 64963, -57693, 63292,
-        43029, 31517, 26984, -81261, -26790, 36287, 87294, -4465, -2260, 88225, 80135, -60424,
-        62605, 24882, 23442, 74079, 60422, -80776, -89401, 18915, -71840, 52511, 8948, -39710,
-        95283, 62625, 76107, 42076, 37101, 42087, 51396, -44481, 79257, 86543, -72143, -51291,
-        56328, 17106, 18294, 72431, 78043, 12642, -74670, 13085, 24151, 79350, 19344, 89646, 23167, 17687,
-        65315, 18978, -88041, 48587, -31945, 79832, -50161, 44876, 39926, 46292, -89338, -72393,
-        -72806, 37915, 59921, -50746, 90537, 93448, 27760, 92342, 2920, 98451, -70104, -54325,
-        14285, 65211, 2910, -22350, 65559, -58053, 33449, 24419, 22113, 85768, 61429, 6766, -86282, -27663,
-        -25940, 69697, 76031, -75270, 19925, 61814, 85266, 63864, 80120, -2890, 55773, 3860, 1969, 23425,
-        72734, -88757, 76659, -83018, 87879, 66444, 8255, -73392, 56086, 5295, -50269, -25633,
-        -64903, 13002, 24253, 57296, 12903, 40011, -58957, 48636, 99037, 75946, 45718, 45715,
-        47282, -72822, 63245, 61550, -26376, -27479, 73939, 40620, 89959, 56495, -99235, 78655,
-        -18352, 54282, 37526, 68046, 11380, 75496, 8769, 6086, 24452, 28411, 22122, 17245, -50140, 84803,
-        71728, 59077, -58391, 18684, 72850, 53345, 22839, -66748, 99858, 49100, 81728, -50675,
-        91105, 14636, 41370, 77681, 47313, 98093, -82853, 56588, 52111, 58234, 11431, 81816, 17210, 50744,
-        86590, -62787, -96686, -34799, 19837, 6271, 41048, -49394, -29565, 16536, -39984, 25493,
-        16443, 67042, 65489, -14768, 71138, 45136, 35694, -36611, 79906, 16148, 47926, -52548,
-        -28803, 27352, 33812, 44134, 95537, 59597, -93458, -15031, -54694, -39516, -7923, 79677,
-        66197, 76144, 91352, 20637, 70956, 68603, 8536, 80549, 98218, 98100, -6486, 25208, -11812, -61968,
-        -92941, 3878, -49927, 28732, -59144, -8605, 44834, 11732, 82536, 82532, -65510, -17624,
-        63458, 49435, 98112, -35366, 20289, -54057, -13711, 62825, 85477, 80003, 98651, -37162,
-        -70375, 16360, -60012, 27774, -23517, 35757, 51543, 35647, 50472, 59733, 3410, 19238,
-        -47065, 94844, 57643, 96962, 50979, 17339, 51123, 89536, -87074, 89537, -24371, 50122,
-        59079, 62078, -27375, 9387, 33671, 4179, -48753, 93087, 87723, 51723, -51047, -12291,
-        -95067, -96323, 21412, 42793, 2040, 6997, 52711, 85437, 61517, -28434, 46091, 74983, 97506, 17590,
-        51930, 84250, -51743, 46426, -39536, 65765, 34982, 81949, 47630, 95851, 97304, 53466,
-        78262, -42748, 90877, 92960, 3268, 71831, -37299, 74260, 9572, 36524, -80742, 35275, 44400, 50415,
-        335, 73978, 39921, 87061, 45521, 48033, 2165, -4769, 97157, -54520, -11397, 31267, 16914, 87997,
-        1607, -41210, 16395, 66868, 87830, 78297, 15767, -96875, 11854, -3991, -53823, 63220,
-        -79449, 66031, 91484, 87926, 33946, -29485, 22854, 91570, 53118, 77073, -56665, 1909,
-        23578, -15916, -71522, 34837, 9441, 91811, 58550, 46836, 90845, 97873, 24881, 93993, 62432, 63038,
-        45098, 50631, 64612, 37509, 18665, 53717, -34977, -4342, 50000, 85332, -11648, 82680,
-        21875, 62515, 60400, 35049, -61284, 99662, 39021, 27946, 22833, 64182, 73322, 15757, 39035, -12721,
-        54974, 80668, 59926, -13772, 30032, 20907, 19511, -89797, 32985, -83755, 71481, -5734,
-        11847, 27619, 61866, 28997, 1304, 24248, 22211, 16186, 90423, 16024, 13914, 99863, 86532, 69016,
-        95028, 71841, 3101, 56625, 95916, 8139, 63346, 21933, 65883, 62554, 12593, 56620, 75675, 91335,
-        24634, -19610, 32920, -14829, -53214, -7093, -40202, 57903, 61017, -4093, -20662, -31461,
-        -41652, -40869, -92489, -12424, 51945, 66988, 38026, -53528, 60566, 9089, -81699, -83242,
-        14722, 73507, 81504, 74594, -36029, 97087, 8866, 30547, 18607, -91449, 51900, 61708,
-        -60219, 91516, -71617, 41604, -68493, -51361, 21513, 42773, 84525, 82587, -92695, -30682,
-        59210, -87364, 1723, 72645, 81205, 58296, -90898, 96616, 35730, -76379, 80615, -51745,
-        -61689, 83301, 2897, 59253, 12634, -77598, -39749, 83604, 11480, 62193, -36828, -25959,
-        92245, 38314, -48511, 62715, -15334, 41888, 72540, -7251, 47568, 16987, 93009, 7233, 90385, 1466,
-        28552, -12395, 84539, 90719, -6620, 5542, 22174, 1896, 70018, 20260, 28716, 28768, 57881, 85914,
-        13355, 18584, 12236, 31449, 65632, 20200, 11728, -52105, 68587, -48821, 4739, 51597, 56255, 2724,
-        36213, -3885, 34565, 44485, -63158, -97315, 46366, 48754, -22964, 40424, 4734, 46942,
-        -87396, 43148, 56457, 45090, 13404, -24023, 35392, -79190, -55617, 12963, 99597, -59891,
-        67045, 97850, -47045, 62007, 61937, 43192, -65209, 6805, 86193, 77693, -76545, 44412,
-        59217, 45867, -77534, 19702, 19814, 64108, 16621, 79472, -50083, -57496, 47205, 86208,
-        39119, 91861, 29134, -54769, 46405, 96291, 65334, 48715, 70719, 78844, -54571, 63101,
-        49980, 78086, 76624, -79494, -13929, -72020, -48736, 62578, 20597, -5011, 51379, 48916,
-        38068, 44831, 36719, -43607, 792, 43100, 40290, 28981, 93837, 57796, 63393, 90264, 63780, 51771,
-        57477, 12949, 41585, -90827, 58824, 61898, 33981, -37083, 95015, 17693, 94025, -7724,
-        60114, 79818, 36880, 27214, 45161, 61095, 24926, 24648, 83915, 35544, 7627, 4943, -11419, 36989,
-        74833, -59223, 5351, 67298, -69202, 50657, 66190, 7293, 58792, -82674, 35859, 6301, 11831, 44070,
-        -43864, 28926, 66298, 39506, 54952, 61294, 59058, -2435, 5104, 39091, 18398, 11591, -61354, 35353,
-        52903, 91467, -54402, 67868, -91236, 86769, -90408, 92869, 64067, -33002, 57792, 68632,
-        -62908, 54326, -58654, -19577, 13633, -41851, -85610, -83968, 10774, 10546, -7536, 31169,
-        93661, 17048, 96821, 90661, -59697, 77134, 28833, 18957, -60445, 99901, 73414, 70127,
-        64164, 73504, 60027, 4401, -95332, 57894, 18587, -24236, 36803, 501, -22784, 44838, 35383, 23199,
-        88121, -10654, -27633, 12383, -65057, 96063, 98369, 36122, 75523, -9418, -81501, 90119,
-        -59829, -52389, 1486, 5467, -72088, -75668, -71771, 83865, 70074, 6535, -35539, -12529,
-        92327, -36791, 27913, -34025, 88409, 45005, 38251, 65823, -85193, -85720, 89785, -8133,
-        60137, 2827, -27288, 85660, -92118, 38806, 82995, -37088, 28946, 33318, 55086, 73849,
-        -11775, 28090, 29854, 5519, 5610, 58859, 70344, -12552, 30599, 26301, 98191, 97325, -83630, 4997,
-        53837, 68153, 68353, 15136, 28172, 66030, -28809, 98677, 71253, 94786, -33019, 62972,
-        21812, -82381, -95551, 24221, 50899, 62302, 83914, 48025, -62644, 25986, 54086, 73778,
-        59516, 77828, 22976, -57721, 36589, 63544, 39748, 40779, 48230, 40082, 36352, 12285, 4860, 87592,
-        -51791, 85180, 72458, 79907, 16426, 44142, 37177, 21998, 56926, 10372, 11460, 75093, 86168, -622,
-        82634, 41007, 66587, 2059, 32484, -28816, 95759, 21510, -78775, -7290, 2438, 71977, 49707, 57035,
-        39939, 26084, 42163, 40092, -40182, -3145, 52315, -23690, 78827, 82067, -77379, 76810,
-        75802, 18743, -32082, 10994, -5318, 46003, 79492, -96215, 29315, -76214, 18460, 56742,
-        -93488, 83506, 25221, 51942, 35573, 37960, 39382, 70169, 18666, 19926, -70545, 9935, 37763, -17735,
-        77202, 10181, -63311, 33696, 80172, 80234, 45057, -88595, 27594, 75513, -53036, 87531,
-        36055, 84167, 93020, -37003, 5003, 30213, 41863, 80525, 59556, 68085, 44026, 72893, 40776, -56338,
-        -22796, 2492, 37707, 96796, 42082, 44361, 90073, -64677, -44295, 91863, -15853, 47320,
-        19709, 38936, 37101, 44435, -63369, -6752, 44808, 9209, 62733, 94840, 37307, 60071, 4030, 14282,
-        57808, 53442, 99098, 71155, 78985, 57903, -81372, -30011, 3683, 45102, 34768, 92197, 10358, 68320,
-        35223, 92845, 8634, 76236, 56751, 45202, 87245, 36702, 25883, 90375, -57001, 60986, 26532, 2100,
-        -91085, 67128, 99035, -47151, 56739, 96423, 85751, 13379, -8304, 39059, 72407, -94700,
-        88346, 5729, 82093, 61496, 53216, 30196, 65968, 8713, 2501, 7651, -83806, 95066, 63128, 61349,
-        60704, 17018, 99420, 20881, 13663, 67004, 36967, 29105, -38974, -77956, 3902, 87339, 78484, 29549,
-        45858, 90724, 81018, 34434, 95607, 58034, 81738, 18263, -55148, 77059, -48273, 67063,
-        43540, 57272, 22116, 23465, 50275, 43373, -40987, -55057, 92756, 15288, 31198, 98424,
-        52236, -48692, -88653, 31940, 82430, 3218, 93804, -12625, 81925, -73600, 80229, 23421,
-        86419, 65518, 41909, 34969, 53410, 41827, 12206, 16681, 44012, 38773, -26665, 27803, 92613, 89203,
-        13590, -86093, -32954, 95422, 51182, 44069, 27819, -91413, 76587, 26399, -83813, 21121,
-        57734, -77552, 1184, 7261, 45520, 71427, 33797, 95378, 28285, 47600, -5120, 38331, 85701, -507,
-        11337, 86644, -62706, -11317, 85611, 94055, 3460, 83977, 47822, 84200, 25033, 98666, 65021, 48078,
-        56311, 46918, 50696, 3566, 71306, 67060, 61293, 34791, 72358, 37075, 15794, 36746, 47941, 96762,
-        -58678, 23322, 79402, 58983, 48222, 6473, -34687, -22470, 34536, 6499, 7588, 75946, -75159, 33941,
-        54196, 17819, -98471, -73979, 78339, 23991, 3157, 90467, 40126, 52623, -33572, 52501,
-        69034, 79781, 92385, 43656, 23498, 19636, 1305, -3064, 25525, -4029, 78499, 93021, 28586, 85434,
-        -98093, 38308, 50238, 61784, 64935, 85565, -74153, 60971, 86139, 27376, -76460, 98748,
-        75951, 70390, 17610, 9139, -39564, 50435, -53088, 96898, 45571, 62947, 70222, 28824,
-        -32250, 4205, 31403, 98539, 6642, 76755, 88223, -85551, -48903, 82777, 35473, 80317, 95039, -63087,
-        -63134, -52084, 84708, 28488, 80671, -5799, 1346, 23781, 42644, 96092, 38931, 66567, 81876, 95367,
-        81041, 6086, 69717, 66035, 7759, 44422, 86224, 53575, 31881, 20934, -25296, 91207, 20451, 43686,
-        43022, -68671, -55931, 761, 55647, -69396, 91188, 77026, -76723, 58773, -48471, 54272,
-        -96449, 6366, -46972, 21608, 68337, 33329, 55134, 79657, 77705, 99596, 29159, 7538, 78842, -95962,
-        49187, -49810, 85520, -41147, 47929, 80684, -53915, 95057, 58363, -88553, -86978, 40851,
-        75029, -17137, 283, 76636, 9310, 38857, 8855, 60255, 67208, 29733, 62331, 72560, 2120, 60126,
-        96254, 64346, -62704, 60366, 33887, -52241, 31468, 45078, -38684, 33987, 82100, 28091,
-        34843, 61401, 83014, -33784, 11646, 12131, -39701, 72149, 89896, -93127, 71434, -74343,
-        -77203, 1470, 25980, -44411, -12276, 83787, 32076, 55479, 84830, 28123, 23521, -50971,
-        -13110, 87839, -40615, -4414, 9505, 70223, 65272, 81774, 30590, 47714, 87704, 48604, 77702, -76406,
-        -3388, 68113, 14260, -67403, -95641, 19410, 64556, 50493, 25551, 16977, -51230, -49501,
-        -93793, 76475, -38330, -86153, -36063, 42800, 80602, 32215, 9991, 27186, 61660, -9794,
-        -48086, 16517, 39533, 28352, 58816, 1579, 10594, -57929, 7686, -80805, 86812, 29971, 72044, 25457,
-        99079, 94030, -10607, 97403, -43944, 30663, -52654, 40894, 5546, 57774, 67033, 28916, 9561, 61315,
-        2706, 47845, 41787, 76683, 52325, 13516, 64549, 39511, -87833, 56389, 42780, 62981, 9704, 85933,
-        97658, -16244, -744, 22307, -37239, 95514, 33751, 18560, 22388, -41481, 42470, 80550,
-        59002, 3789, 38347, 68027, 14090, 92491, -13995, -86131, 21119, 5675, 83910, 71550, 97148, -20068,
-        35455, -97141, 74410, 73660, 70696, -27851, -91508, 33656, 72256, 12111, 24542, 96927,
-        -94503, 68883, 34912, 75525, 87928, 68997, 73875, 83337, 61242, 49824, 17284, 5784, 14957, 4330,
-        68624, 57294, -18478, -80224, 43090, 533, 4709, 3489, -26728, -71780, -54630, 98274, 5163, 90977,
-        -3908, -44008, -9463, -32700, 76250, 3452, 68786, 92716, -95045, 71144, 93640, -98991,
-        93476, 60916, 73856, 99394, 18154, -80729, 76064, 77942, -20372, 34934, 21974, 43036,
-        -7731, 62971, 78690, 91968, 31409, 79714, 60958, 69876, 20120, 43908, 74337, -63859, 37566, 36362,
-        48093, -80806, 83129, 9784, 23178, 71125, -10344, 75430, 13085, -17005, 32105, 33686,
-        72904, 28426, -42803, -19227, 67636, 23299, 9405, 70441, 4361, -76553, 92337, -32704,
-        33899, 1081, 17859, 35359, -29133, 57660, 20880, 58254, 21359, -86677, 19876, 22835, 53920, 88713,
-        30223, -86246, 27902, -74490, 36358, 16521, 29351, 53610, 53325, 22302, -19938, -18843,
-        6546, -54838, 30640, 74333, 58106, 97987, -70940, 94651, 59637, 29869, 44016, -87453,
-        14970, 17671, -23842, -75154, 12545, 17531, 48758, 41561, 37829, -31884, 57773, 74341,
-        99944, 69029, 39579, -21976, 83893, 75617, 79702, 13435, 78510, 62723, 56385, 84843, 9612, 82334,
-        -6588, -25083, 24864, 84762, -78531, 36573, 15312, -86517, -32279, 89936, 63033, 97618,
-        25105, -10820, 65573, 21024, 40690, -41862, -50161, -57703, -58954, 98800, 52153, 53107,
-        62052, 81522, -69772, 89782, 63135, 87409, 23071, 27484, 53764, -85304, -47652, 46807,
-        10718, -87283, 24983, 59200, -85792, 98024, 66404, 5272, 11141, 14794, 64137, 99702, 18601, 39916,
-        -67596, -87213, 7710, 31852, 94657, -26283, 21581, -38373, -82740, -71592, 84486, -8090,
-        39392, 18570, 36905, 69142, -88941, -78434, 91924, 78722, -76435, 2945, 12922, 34410,
-        50570, 64666, -69076, 25808, 44958, 7100, -94360, -23250, 1032, 82126, 96059, 80513, 26362, -42032,
-        -38263, 91282, 36537, 82140, -16801, 88593, 65145, -82338, 8895, 36573, -94667, -7002,
-        22128, -66471, 39761, 88286, 82734, 79021, -47315, 3110, 5702, 12144, 16425, 45308, -3801, 55717,
-        23089, 42115, 42579, 95778, 36079, 16611, -11951, 22363, 82176, 6366, 71572, 2815, 72487, 56456,
-        78164, -8226, 13493, 78881, 7068, 58495, 35036, 89201, 82857, 36015, 38951, 70931, 29813, 30784,
-        91388, 55256, 94854, 37986, 19334, 42548, 30890, -30094, -26782, 69650, 98677, 19388,
-        81208, 37840, 81476, 82394, 6889, 22805, -88362, 1620, 48193, 42543, 74056, 87264, 36072, 33132,
-        -27370, -1774, 30859, 67522, -28322, 73485, 20210, 16630, -45944, 987, -5162, -84347,
-        58390, 25990, 35202, -9944, 10888, 91308, -38654, 4710, 89296, -26482, 54235, 9457, 10188, 81687,
-        79738, 52348, 38768, -70664, 43442, 67452, 40650, 80260, 45614, 87305, 23101, 82151, -8529, 99111,
-        30002, -98767, 18933, 77750, 90569, 24452, -19637, -39373, -88625, 75264, 54455, 10935,
-        5059, 70239, 33155, -49691, 84843, 10957, 72467, 90617, 44983, 74819, 49486, 62733, 76264, 44602,
-        67135, -1912, 58452, 94226, 96772, 46505, 8277, 25650, -95468, -65280, 95143, 46065, 97840, 68748,
-        2899, -52370, 50256, 24572, 44693, -86326, 90096, 32497, 18700, 18145, 28160, 6541, 95103, 55710,
-        -95914, 48554, 40910, 61293, -82386, 76667, 64666, 14000, 53118, 22071, 501, -72286, 90354, -61862,
-        42970, -43826, 43993, 78093, 51292, 33382, 51685, 55307, -48870, -80974, 58428, 79438,
-        79305, 10169, 71041, 67342, 54198, 55566, 28072, -22792, -27102, 55637, 4650, -97737,
-        27028, 83417, -33448, 46457, 32345, 81025, 56268, 92224, 74904, 92250, 86369, 12323, 25605, 11840,
-        -5639, 47114, 3007, 86578, 61444, 65094, 7572, 17508, 99473, 40383, 12391, 70175, 75428, 12138,
-        43413, -98794, -82388, 6771, -62369, 54779, -62906, 26778, 25250, 24499, 98229, -64233,
-        11646, 79259, -35939, 32772, 5694, 22222, 85272, -38163, 65672, 49666, 35742, 24669, 1467, -53129,
-        74450, 83933, 97365, -99307, 3221, 14615, -81423, 26753, -24706, -70397, -59579, -83129,
-        40258, 16675, -64928, 67458, 24460, -9732, 89058, 15126, 6260, 23032, -56653, 67044,
-        -30231, 73245, 45404, 80107, 45063, -46489, -88607, 65223, 11561, 89697, 69449, 27986,
-        63688, 37743, 74127, 89532, -81249, 84667, 91312, 5111, 85973, 88689, 67255, 11676, 27809, 11361,
-        6956, 76117, -81509, 98815, 19704, 32972, 58719, -96375, 38665, -36360, 85615, 15471,
-        -19440, -74759, -89711, -28258, 74696, -96766, -50155, 54641, 67405, 70244, -91944, 2893,
-        72920, -74179, 69821, 85679, 35269, 52505, 84020, 27652, 59633, 79378, 34509, 46647, 72170, 60307,
-        62603, 94233, -89325, 29691, -59258, 58308, 50924, -29769, 32345, 41373, -4599, -29425,
-        50335, 88303, 9120, 25766, 58654, 11597, -1845, 23144, -2340, -42179, 91625, 78628, 90763, 68035,
-        23716, 9563, 35318, 12416, -41126, 1083, 8113, 99565, 97008, 23097, 30302, -36964, 26596, 27621,
-        26724, -4484, 33666, 28401, 94825, 23780, -14600, 41099, 25683, 39857, 40967, 73731, 64687, 39826,
-        82853, -94800, 24593, 8228, 97913, -82450, 70656, 90162, 93914, -12862, 80276, 6653, 66250, 87680,
-        16095, -60413, 1812, 70916, 52607, 98716, 83115, -21126, 18176, 1739, 13070, 25159, 92565, 96017,
-        76186, 10299, 80594, 52267, 33906, 76389, 38760, 63203, 99976, -94439, 15418, 26102, 53747, 82913,
-        56380, 9817, 65174, 60332, 56695, 76078, 85873, 85112, -74999, -324, 99667, 22599, -33137, -24287,
-        68578, 62028, 78798, 43612, 63546, 79251, -54677, 90827, 23376, 97142, -11775, 55693,
-        37927, 27350, 67880, -56703, -1134, 98513, -24490, 65871, 81201, -3914, 95609, 85819,
-        -7433, 17486, 76791, 40617, 3423, 44045, 78002, -6451, 54909, -1951, -12092, -207, 40428, -8816,
-        -6028, 96270, -10073, 11788, 2005, 7195, -88802, 29994, -55221, 45297, 85811, -16388,
-        -17210, 14693, -92664, 58277, 68759, 45433, 41148, 45150, -91913, 25785, -25134, 92607,
-        39798, 51519, 16031, 94226, 95569, -83764, -74434, 47510, 54071, -15692, 55078, 67363,
-        46300, 38004, 78333, -1876, 16371, 73165, 34950, 1183, 86456, -74561, -76016, -62604,
-        72700, -84176, 89445, 78266, 73657, 70176, 29186, 46945, 30899, 83407, 25804, 26604,
-        -65351, 18628, 44729, 82917, 37511, 46295, 31557, 74173, 14213, 53719, -54821, 87702,
-        73394, -65999, 46819, -75332, 35423, -66305, 54906, 95705, -29013, -55181, 60645, 8737,
-        6748, 18496, 49061, 39464, -44988, 66412, 78050, 49111, 51370, 27677, 80720, 22194, 45836, 5242,
-        61217, 31428, 21156, -76991, 48498, 11865, 68461, 41638, 3586, 41396, -76998, 87973,
-        -67758, 40, 39907, 13536, 3001, 48854, 95129, 12644, 21991, 39985, 48843, -41485, 14777, -28686,
-        66838, -37049, 88189, 97099, 95467, 17873, 12592, 7121, 18821, -40823, 29659, 51532, 54034, 71798,
-        1163, 14750, 61809, -51744, -51138, 87662, 43486, 77939, 48073, -31031, -29790, -81580,
-        -71926, 84452, 2131, 13654, 69263, 17302, -20432, 3930, 8974, -91562, 37729, 74854, 64783, 13692,
-        -10335, 69889, 53172, -24517, 77278, 63791, 45967, 3481, 597, 43739, 28023, 87251, 43027, 78428,
-        62477, 98361, 59388, 78929, -54268, -75885, -60947, 84896, 11845, 46834, 52742, 91343,
-        68003, 60725, 93646, 46626, 95604, 50920, 1043, 30951, 35304, 15231, -64817, 48340, 99077, 97596,
-        64886, 83231, 3745, 68300, 15715, 86706, 91968, 3859, 32967, 48885, 48271, 90449, 55899, 9182,
-        -67932, -57289, -40543, 94256, 92182, 6601, 72120, 78104, 40750, 4146, 5679, 32015, 20566, 98282,
-        -44324, 29688, -23257, 5881, -35834, 76957, 30656, -11148, 14694, 94315, 33318, 66809,
-        12801, 75530, 6337, 92259, -55805, 85128, 97928, 26138, 9782, -68221, 62675, 72684, 14400, 52713,
-        -77884, 50158, 95536, -8127, 24885, 51162, 24651, -51400, 80927, 95670, 9381, -44794,
-        10008, -53328, -86282, 72412, -80336, -43989, -10466, -32191, -3016, 59286, -68063, 65861,
-        20811, 62235, 28092, 86331, 344, -94247, -33012, 53925, -96173, 34334, 60809, 95533, 64319, 8849,
-        46808, 36871, -80141, -60141, 46935, 19000, -3990, 13821, 22819, 68675, 6495, -38628,
-        72864, 20627, 35223, 79540, 4019, -46955, 92254, 15150, 81722, 87909, 31999, -80340, 14775, 54170,
-        22981, 56120, -41124, 86440, -6703, 54336, 60823, -44476, 87303, 12704, 12676, -87507,
-        -78742, 56019, 59510, -50197, 17167, -14438, 48066, 78627, -85720, 75588, 69732, 19785,
-        -85735, 67307, 60909, 79696, 94901, -730, 65909, 183, 45667, 3893, -6075, 39872, 53321, 30031,
-        94423, 51574, -20654, 89310, 16718, -85562, 51291, 74920, 34538, 3344, 51684, 88291, 18807, -55228,
-        99891, -59124, -32993, 45853, 82544, 71458, 12578, 70158, 96531, 80507, 71635, 53251,
-        44612, -44999, 46198, 41796, 7802, 52611, 10238, 78434, 40425, 58040, 42491, 355, 10287, 92406,
-        32212, 35382, 2650, -31560, 82089, -183, 25561, 72992, -12430, 23178, 85458, 7261, 92607, -66861,
-        -46431, 25824, 66683, 70553, -76575, 10848, -11787, 96381, 86986, -96588, 21153, 41326,
-        -3026, 54206, 85629, 22213, 99120, 78633, 97218, -1125, 90086, 96369, 39043, 86973, -70931, 79433,
-        -64811, 22581, 84373, 87981, 58003, -75373, 34140, 18881, 37414, -56894, -7083, 27689,
-        58054, -58931, 57008, 4055, -42495, 81725, 85998, -65243, 11709, 53692, 31205, 39890,
-        83216, 88464, 31433, 39257, 34619, -19550, 16273, -39857, 72422, 92964, -87886, 62762,
-        81987, 17534, 98413, 4355, 74307, 6613, 49823, 26347, -30632, -47876, -82761, -35144, 482, 18112,
-        -14353, 27597, -89453, -99160, 18973, 32232, 18856, 56681, -46834, 52989, 7917, 88695,
-        3372, 23554, 77894, 7852, 86472, 42546, -54390, 80516, -7998, 42761, 26388, 11285, 51320, 89323,
-        1768, 6857, -72177, 50926, -27438, -89405, 76837, -74179, 58340, 94854, -72952, 89747,
-        2797, 6779, 49739, 46058, -85491, 18976, 77087, 58352, 1780, -60176, 46944, 25311, 36477, 51162,
-        85360, 49045, 30460, 83776, 46315, 70511, 18483, 23130, -91082, 51028, 54960, -47813,
-        99736, 60231, 35993, -84247, -68715, 59419, 95210, 11931, 43705, 78311, -22095, 57215,
-        91705, 93133, 57111, 98162, -72419, 38205, 27321, -6145, 7202, 58101, 84228, 26495, 31395, 41229,
-        65649, 7928, 40955, 91952, 39732, 49297, 67586, 44659, 9859, 21539, 39894, 92057, 64695, 91162,
-        38878, -16992, -92469, 76738, 17096, 29026, 18608, 85456, 5579, 15945, 95111, 37244, 92592, 94352,
-        11663, 43489, -15813, 2877, 75191, -67386, 22132, 1649, 57029, 59857, -88278, 19559, 44639, 81229,
-        77633, 94237, 41895, 25491, -29299, -8694, 47599, 75398, 70770, 24831, 69756, -23144,
-        37106, 15187, 11258, 62711, 37452, 58617, -2660, 32944, 30611, 69459, -71004, 38815,
-        -41150, 66161, -51163, 28080, 32291, -17443, 14284, 31946, 32340, 28752, -62184, 36403,
-        28343, 22326, 33349, 77247, 61182, 15090, -55822, 12996, -64194, 57843, 80653, 23782,
-        10124, 45490, 12327, -52414, 85403, 49868, 18075, 99707, 99617, -93056, 61989, 2355, 88064, 2920,
-        45588, -77243, 79004, -78105, -41705, 32177, -66481, 90752, 85146, 71765, -28071, -31032,
-        61399, -58702, 32153, 9949, -58469, -53116, 49950, 23819, 87759, -8422, 8202, 25032, 99450, 60320,
-        69991, 45525, -87184, 86095, 26265, -73825, 90811, 83133, 81091, 54077, 54976, 74393,
-        65102, -70523, 52543, 86669, -64773, 53420, 46278, 20258, 65736, 13314, -15861, 99165,
-        -7691, 35441, 20997, 11510, 39433, -25388, 77602, -89761, -78168, 44411, 82208, 66385,
-        70551, -28856, 70900, 67164, -42315, -66689, 95892, -63036, 35539, 15385, 76842, 32852,
-        70698, 65593, -69487, -34900, -95140, 49237, 64528, 69265, 23231, 2002, 60971, 85760,
-        37444, -87515, 62063, -51333, -82474, 41221, 3629, 38765, -52434, -28101, 59373, 15913,
-        83261, 77417, 66777, 33377, 3888, 93770, -22643, -45532, -14521, 16450, -93117, 24769,
-        39514, 48222, 36753, 44809, 95519, 29898, 6662, 39351, 60228, -9475, -71403, 68972, 65385, 92043,
-        52484, 55877, 46160, 12356, 84997, 60945, 44067, 24396, 80310, 4197, 37727, 27521, -55323, 42648,
-        15230, 7984, 19208, 24076, 68995, 89796, 8295, 94587, -46564, -33463, 40083, 37048, 70474, 95251,
-        99366, 78681, 55082, -56693, -3275, 41213, 20491, 47859, 91963, 66283, -28215, -80043,
-        51642, 92490, -32711, 95825, 85322, 34917, -35501, 25597, -57798, 57279, -88214, 97164,
-        42254, -4725, 11424, 67221, -61430, 62531, 59092, 1986, 30245, -6940, 61389, 77967, 52927, 27005,
-        -8524, -82455, 72612, 55824, 51698, 32525, 11531, 31414, -26385, 11978, 50999, 590, 96387, 31421,
-        48228, 69271, 89352, -26935, 50899, 11757, 95635, 83714, 62991, 65101, 16451, 43412, 71128, 97983,
-        11635, 67087, 36679, 34664, 68431, 82937, 36683, 57491, 16619, 1812, -37437, 5099, 86253, 59774,
-        83054, -58650, 78610, 29934, 26929, 31727, 35847, -8307, 74891, 97653, -72916, -46340,
-        -89062, 87816, 81035, -53931, 80003, 16771, 29259, 70166, 79240, 19448, 90821, 96607,
-        96530, 12674, 60075, -97617, 31395, -2680, 63148, 5461, 79564, -26563, 57460, -17076,
-        11907, -26409, 1793, -15911, -97676, 78222, -39291, 36225, 45083, 34431, 46562, 72443,
-        38574, 13781, 93770, 64071, -85248, 88935, 14004, 94169, 28076, -80953, -96686, -46054,
-        28291, 25780, -13443, 94079, 27180, 10600, -45011, 26269, 97781, 51291, 8095, 88354, 50041, -13260,
-        -83547, 66616, 6016, -65126, 12531, 47821, 79334, 21189, -7472, 2330, -19013, 82617, 32416, 41793,
-        86745, -20031, 56624, 21549, 78617, 65795, -83115, 64112, -35246, 82456, 72462, 11284,
-        48912, 53006, 59501, -51441, 97342, -40756, -93857, 51289, 78478, -76808, 30439, -12226,
-        90469, 29008, 12914, 25895, 38688, 23556, 64339, 90952, -34223, 27634, 59045, 69622, 90916, -4831,
-        37491, 51759, 39775, 23381, 15754, 1768, 43676, 8262, 50929, 97472, 66035, 44093, 96034, 51633,
-        -84592, 13751, 62095, 7056, 43124, 52058, -88666, 51298, 60173, -91369, 31462, -84346,
-        2373, 15885, -57762, -11069, 10234, 29667, 72667, 34075, -15544, 74619, 31724, 97050,
-        -89087, 85104, 42818, -95671, 10748, 59928, 65910, 82293, 35982, -68999, 21914, 32341,
-        69958, 35338, 72274, 36935, 35042, 18547, 59878, 67143, 89274, 60175, 47613, 35644, 26162, 27951,
-        45280, 29694, -61812, 20208, 60614, -17133, 30598, -37694, 65188, -15353, -98190, 26380,
-        11388, 67542, 7827, 14884, 80711, 33260, 21236, -40623, -4499, 31550, 19356, -6942, 45507, -31171,
-        -10331, -76686, 82599, 25815, -66316, 73698, -49485, -97418, -43886, 54359, 85066, -71735,
-        91124, 42944, 49829, 30258, -35337, -55737, 75488, 78878, 88506, 53896, 46795, 25430,
-        87072, 2347, 30578, 97490, 35109, 61991, 56248, 13296, -40580, 66433, 33793, 47360, 93718, 90622,
-        83937, -55897, 16201, 30622, 67792, 79667, 17906, 57185, 80749, 83026, -21960, 13086,
-        21367, -68636, 71096, 64543, -52156, 99736, 37202, -50098, -64018, 86339, -10267, -80064,
-        65677, 52575, 83533, -42873, -19124, 16184, 15203, 2522, 24188, 11940, 53557, 6142, 29572, 59050,
-        40916, 63412, -41700, -28001, 47296, 35199, 22936, 46991, 25769, -32190, -4808, 40341,
-        37041, 2588, 41274, 3193, 91135, 50238, 32497, -83124, 96906, -93351, 32400, -90866, 33146, 91276,
-        96500, 27711, -90567, 50610, 61702, 91763, 74259, 51906, 25328, 72015, -64977, -98011,
-        -56435, 76989, 41384, 47403, 41468, -34618, 3359, -75833, -59869, 19133, 42605, 33699,
-        92135, 30556, 7478, 73407, 92583, -70972, 65806, 4762, 99932, 87800, 37499, 65792, 5223, 97452,
-        35860, 60278, 37731, -23554, 71237, 82161, -24878, 12385, -65496, 57278, 90584, 21590,
-        -2145, 88110, 86969, -74997, 57365, 68721, 27615, 80048, -47202, 94407, 88370, 24539,
-        11937, 98143, 33252, -51011, 88735, -35645, 41520, 70051, -36806, 35420, 10628, 85310,
-        62772, 54391, -42572, 51732, -60357, 11951, 61176, 7078, 26080, 73711, 97199, 29001, 94794, 88555,
-        38886, 84813, 77, 78497, 73293, 60441, -3820, 94172, -36128, 8564, 22764, 34626, 87744, 99583,
-        40619, 79012, 80949, 66210, 37832, 98813, 39451, -70480, 1262, 33594, 45391, 62238, 88236, -81804,
-        34848, -42249, 70417, 29127, 66436, 38322, 26272, 89468, 18213, 77650, -3122, 81679, 22747, -50263,
-        37746, 90091, -27365, -1664, 33381, 69643, -3522, 43790, -83133, -11425, 27212, 61251,
-        -38333, 9534, 56975, 79567, -31813, 33239, -86469, 24436, 70105, -57148, 59629, 23402,
-        23630, 14239, 34411, -16056, 39825, 19195, 7419, -17025, -86428, 36199, 25998, 18337,
-        41266, -5562, 74060, 99525, -96079, 55870, 78909, 84460, -53182, 13280, 64863, 50032,
-        71789, 65874, 90315, -45251, 16713, 95687, 22660, 56179, 72942, 10968, 63326, 71668, 16444, -72120,
-        -21362, 89344, 5725, 97792, 17469, 16999, 35783, 65589, 16166, 34666, -56219, 99262, 36588, 75477,
-        67192, 43712, -87730, -22579, -26635, -92115, 28852, 44894, 44815, -8919, 77788, 77620,
-        23482, -75691, 67060, 76601, 27084, 20390, -60748, -43047, 11223, -6647, -15709, 60191,
-        90966, -45680, 47878, 27906, 44618, 47485, 92047, -39899, 75089, 86577, 63474, 31058,
-        36918, -48063, 96568, -44755, 64996, -52631, 18016, 14576, -95284, 304, 72930, 84924,
-        61697, -96058, -39445, 94162, -13824, 95179, 68127, 3301, 38320, -2912, 88607, -42086,
-        15762, 70224, 28584, 89153, 52133, 3781, -52323, -63548, 70410, -29577, 19963, 91319,
-        93426, 557, 47513, 11332, 16179, -18243, -63652, 39457, -84123, 13603, 28792, 53156, 37447, 30573,
-        90702, 91597, 81629, 20393, 34707, 16178, 13520, 51738, 37241, 56435, 15119, 73721, -7977, -3076,
-        94527, -16745, -89444, 38355, 35192, 65188, 43168, 68421, 38975, 4124, 63187, 69261, 76960, 28352,
-        90173, 99177, -31626, 28897, 40388, 31602, 12303, 63858, 65037, 79075, 8197, 68194, 47416, 99276,
-        97001, -57439, 93933, 22046, -86784, -64675, -41186, 68600, -6238, 12791, 29476, 78987,
-        33658, 49420, 38172, 2692, -73973, 4876, 36556, 21037, 1245, -78750, 4727, 66750, 91484, 65013,
-        79168, 56651, 14055, 44733, 63015, 47843, 19833, 1818, 64318, 41041, 45706, -22440, 83504, 36755,
-        65378, 57635, 91958, 68508, -60429, 71037, -74597, -87259, 1365, 14307, 52242, 29926,
-        23779, 7276, -68377, 34484, 28070, 4321, 66251, 36645, 46479, 18099, 7889, 55879, 92550, 38662,
-        -81465, 21435, 41526, -65057, 31216, 55319, 59, 37517, 72154, 16894, 5919, 5587, -20974, -11274,
-        -35151, 42157, -76950, 24982, 77369, 89211, 19666, 84823, 79578, 18024, 29383, 75182, 5071, 4070,
-        44120, 33065, 76098, 71824, 77041, 85054, 30243, 53835, 8166, 43324, -53770, 29096, 63069, 85905,
-        95442, 28581, 43609, 74416, -4926, 93315, 880, -89976, 96856, -39933, 34092, 73535, 87384, 72460,
-        -96576, 10758, 95648, 31060, -57051, 96430, -64402, 88954, 33549, 82321, 82512, 35221,
-        13288, 68650, 75517, 88985, 33634, 28941, 39095, 95759, 22504, 17806, -57765, 23467, 9646, 6122,
-        84119, 67011, 78914, 30574, -24506, 30762, 98945, 81065, 25578, 75779, -72888, 95459,
-        -46731, -58117, 89491, 65379, -70236, 85010, 41989, 29389, -66296, -87075, 71850, 52782,
-        -10354, 1100, 86575, 80291, 53898, 98694, -57120, 62645, -1945, 42238, -32686, 33758,
-        -47859, -20051, 38325, 57009, 28347, 91728, 94324, 55675, 97640, 30848, 39672, 29607,
-        50108, 76478, -38285, 74959, 60261, 64022, 2295, -70865, -59159, 30649, 94690, 33784,
-        42929, 99730, 93342, 23369, 53612, 23030, 15249, 15196, 1442, 31945, 69917, 54240, 62364, -37646,
-        80176, -43828, -36043, 31827, -979, 60880, 51002, 37118, 91532, 98538, 75460, 72473,
-        -64259, 6423, 4235, 5536, 99851, 40454, 61009, -18430, -12186, -8509, 28625, 41238, 52989, 3605,
-        95347, 1514, -10724, 31946, -45455, -99524, 78927, 82892, 62753, 15870, 19902, 2287, 4029, 61889,
-        71993, 70038, -65921, 45783, 48257, 76953, 24604, -55905, 48858, 37496, 37154, 1852, 11442, 73457,
-        34002, 92864, 34183, 90625, -88944, 44557, 60927, -70577, 85616, 28076, 90384, 33043,
-        21321, 96439, 5133, 13975, 31142, 92131, 4331, 76822, -86187, -71427, 32963, -49747,
-        -66466, 34401, -74923, 32573, 56793, 13774, 72057, 28502, 56500, 22015, 57270, 61382,
-        57787, 90343, 37211, 61008, 3473, 58292, -38365, -11596, 93646, 68413, 65099, 56662, 21320, -55580,
-        5883, -76713, 47339, 10759, 69093, -80950, 41642, 66289, 86294, 59634, 55207, 60742, 22873, -76368,
-        26466, 65141, 94607, 27131, 35385, -51854, -89356, 84702, 25076, 87659, 49055, 63686,
-        81107, 22519, 86303, 90547, 66023, 23077, 70006, 18112, -58138, -65823, 21156, 97898,
-        38294, 75927, -47403, 29137, 2041, 84614, 8754, 52627, 75796, -51483, 45267, 45876, 36452, 24400,
-        -67271, 5945, -64289, 26236, 65032, 19474, 89702, 16218, -47709, -60280, -37842, 40475,
-        74800, 19907, -42987, 97281, 89958, 15083, 92130, 5643, 4586, 68933, 80888, 88792, 84545, -24802,
-        -27006, 82843, -66282, 26703, -32297, -22966, 27979, -25784, 30932, 72865, -24532, -69541,
-        83285, 53889, 46632, -60220, 73637, 94928, 97531, 51517, 71917, 11261, -98973, 52438,
-        33301, -58506, 86100, -53460, 61375, 8759, -46515, 23982, -65224, -66446, -59117, 43235,
-        94360, -68078, 38169, 77975, 86120, 49997, 77929, -63208, -96791, -65876, 24705, 12387,
-        39369, 19652, 36761, 1483, 62294, -7222, 63708, 74692, 66021, -35501, 80487, -57378, 12163, 83719,
-        26777, 51332, 75479, -39709, 21791, -46616, 15514, 46185, 66332, -57747, 14843, 20485,
-        72848, 39622, 1299, 80442, 2752, 82110, 3449, 55034, -67539, -1092, -62713, 61226, 22566, 81904,
-        19436, 8352, 15739, 14973, -62808, 95462, 17303, -23898, 45686, 47800, 8082, -67681, 54409, 61987,
-        94439, 90386, 9889, -64790, 7022, 62296, 71247, -32301, 69700, 8037, 26796, 78639, 97270, 34003,
-        88653, 53401, 32359, 95089, 8962, 40614, 1739, -36672, 82086, 9579, 80663, 65983, 25065, 69268,
-        52851, 32457, 90175, -47786, 19061, 44174, 30588, 696, 42658, 14565, 25443, 90633, 90349, 39373,
-        47116, -62485, -83326, -92106, 29637, 28823, 65395, 22995, 13838, -54491, 6524, 8502,
-        90427, -88416, 75887, 65895, -50126, -63205, 76923, -96344, 82583, 85068, 23685, 5229,
-        -30118, 49022, 214, -71332, -66955, 6002, 4234, -26756, -57935, 18915, 27957, 65202, 88874, 85015,
-        97124, 34951, 85898, -47747, -56144, 86489, -50022, 35655, 99264, 93065, 13990, -38811,
-        56517, 56310, -39727, 40028, 26029, 11363, -39422, 8958, 68856, -80056, 38507, 17468,
-        -60841, 60520, 8731, 21916, 20879, 73590, 59311, 59722, 14806, 82919, 13929, 65440, 63580, 80071,
-        71416, 51622, 59113, 39344, 99083, -13100, 69640, 36523, 69766, -63057, 57250, 45122,
-        20200, -75144, 44215, 25580, 51408, 15386, -32946, 73882, 62576, 68282, 96691, -95291,
-        41436, 90598, 83415, -5909, 50266, 91579, -13306, 46709, 40794, -42255, 52388, 74845,
-        16763, 16455, 10392, 9756, 89275, 44238, 40819, 90675, 81333, 4589, -35709, -63580, 12303, -41223,
-        76158, -69641, 17840, 5500, 72086, -13898, 15759, -93911, 42933, -76945, 54438, 54845,
-        26250, -88426, 45735, 83213, 52441, 31719, 75503, 1731, 98817, 16698, 36217, 54605, 38191, 99844,
-        92336, 87612, 56777, 82040, 167, 17908, 39054, -23292, 81656, 20014, -89617, 1049, 52601, 59848,
-        5167, 17481, -4429, 18709, 80717, 32618, 75095, 67613, -38507, 23089, 39342, 85077, 31183, 23381,
-        92744, 60134, 66018, -30093, -50264, 84690, 88897, 78910, 79252, -66215, -11022, 48868,
-        21250, 41196, 48858, 7003, 35286, 98875, -54253, -28726, 1664, 41225, 14975, 79776, 39685, 26007,
-        12474, 80510, 18002, 51479, 81457, -49937, 82316, 41782, -14715, -97347, 27205, 29843,
-        13887, 88142, -6309, -83098, 95314, 874, 99956, 5118, 74701, 6853, -40784, -28401, -61513, 71679,
-        7496, 86320, 43294, -27024, 11197, -51052, 13143, 78623, 31061, 8113, 8303, 36847, 41404, 64460,
-        16627, 84858, 23450, 44928, -33723, 99958, 42603, -21144, 82949, 61885, -42855, 7514,
-        -69480, 90099, 84859, 29318, 99146, 59097, 74069, -53058, 12475, 66780, 7153, -64600,
-        57209, -36143, -82269, -81019, 73326, 28813, 826, -20451, 73320, 16780, 85286, 33995,
-        41185, 69481, 48754, -91951, 2229, 89115, 10591, 59828, 62348, 78986, 19549, 83178, 5235, 41445,
-        7613, -98120, 71280, -40881, 41554, 96379, 89054, -22712, 43430, 51926, 23994, 694, -23749, 68460,
-        37498, -82242, 16702, 21308, 16488, 51986, 81476, -9895, 52679, 41481, 13148, -61512,
-        80427, -82440, -86137, 67966, 99607, 97032, 70214, -7972, 89635, 23542, 686, 16996, 52172, 18178,
-        59527, 53486, -29164, 56327, 56928, 44855, 52465, -82713, 99495, 26766, 41905, 7736, 88986, 70343,
-        38168, 78212, 95227, 28061, 53847, 69685, 51952, 4471, -74157, -78680, 49494, 82131, 52869, 60501,
-        14005, 10330, -94314, 16072, 25010, 15506, 8027, 58513, 23233, -54117, 7618, -61718, 34568, 58829,
-        27121, 86876, -61777, 83735, 96568, 89416, 26953, 98563, -42373, 55692, 61611, -91848,
-        63977, 8722, -32978, -17331, 24834, 42403, 72384, 55199, 45978, 93197, 71248, 17979,
-        -15036, 57826, 59795, -84804, 53393, 66022, 92831, -747, -91084, 6938, 2580, -44941,
-        -24812, -5470, 18529, 40464, -81698, 99342, 59670, 79508, 72359, 24877, -80337, 24475,
-        84209, 77580, -20451, 24577, 8675, -71115, -87892, 50205, 72812, 82341, 16208, -28764,
-        -51456, 91055, 4561, 91884, -72578, 17649, 49714, 80096, -86573, 14947, 21359, -27540,
-        -51032, 6925, 75477, 53348, 16755, 23361, -78488, 17962, 33445, 76023, 79259, 41519,
-        -81300, 42168, 57496, 89566, 59980, 16294, -40129, 98874, -77870, 33644, 21561, 23297,
-        -60332, 52775, 72681, 47716, 69379, 27782, 70340, -96508, -85868, -61745, -85829, 75570,
-        23187, 43037, 23637, 36901, 80815, 29916, 88493, -25058, -31209, 20242, 90556, -30172,
-        76157, 40048, 76966, 83528, 52879, 62822, 28394, 86266, 48185, -33948, 24765, 89976, 25106, -61090,
-        62016, 82959, 47006, 41658, -3965, -89698, -20564, -33744, -19029, 58073, 71935, -64623,
-        35715, -49777, 52124, 57585, 57291, 67095, 66042, -35058, -45291, 90550, -52848, -24791,
-        28444, 60679, 9511, -11895, 62958, 60366, -34430, -41692, -97915, -83070, 70863, -66979,
-        95970, 20946, -97200, -61687, 52467, 98303, -54742, 90974, -76163, -87793, 20972, -23188,
-        2652, 80151, 25588, 40499, -73742, 42266, 83455, -51943, -82094, 64410, -92703, 83372,
-        86604, -85555, 48425, 82548, 86886, 97559, 40529, 36968, -60912, -89928, -7266, 7089,
-        -31002, 93181, 20941, -5335, -56127, 34482, 48054, 594, 5355, 19396, -74339, 51986, -60620, 92982,
-        37766, 21136, -10750, -90436, 74441, 76127, 15421, 9512, 46790, -31738, 1601, -59817,
-        91682, -9640, -50464, 55931, -12181, -80297, 36429, 56229, -69236, 17887, 18385, -25240,
-        -25635, 29707, 41487, 89021, 76513, 64476, -65913, 53329, 38683, 59048, -54354, -27541,
-        30305, -47023, -2007, 76796, -96840, 93849, 57935, -13409, 87697, 88049, -73216, 10885,
-        27001, -88534, 57410, 12691, 74257, 39929, 7117, -37339, 98505, 56234, 75042, 85504, 12397, 69221,
-        16047, -52468, 55442, 32041, 20366, 47044, 49353, 9268, 58685, 3732, 10567, -63311, -60118, 60156,
-        19845, 74127, 39946, -47056, 38781, 66278, 12395, 44826, 63119, 96782, 35763, -12211, 5022, 73134,
-        67381, -11245, 91317, 21254, -56137, 94338, 46762, 71539, -83994, 94028, 54877, -90038,
-        -27302, 17722, 98654, -43647, 75184, 15697, 5595, -33937, -41474, -79765, -12178, -49910,
-        16993, 74923, 11519, -89413, 64388, 78605, 1190, 41213, 72190, 10140, 19246, 80195, -15620, 56116,
-        20999, 34072, 49472, -5644, -88862, -85936, 41306, 17256, -14946, 86526, -68082, 99232,
-        73554, 64804, 44697, 9981, 33016, 11964, -78402, -96834, 50177, 21728, 19007, 12269, 44245, 8793,
-        -20095, 91290, -82127, 53439, -12146, 24611, 33511, 9288, 43530, 41186, 5024, 34180, 63100, 38370,
-        47814, 57532, -97764, 59787, -96000, 40828, -85253, 69728, 21487, 92411, 46824, 94193,
-        95916, 4741, 30878, 14299, 53891, -72496, -61356, -23636, 35144, 60058, 15193, 24816,
-        48044, 68881, 8426, 52045, 21009, 98338, 43862, -15136, 74052, -54032, 9603, 81406, -10828, 63672,
-        -90338, 88371, 29706, 32246, 59809, 50088, 64657, 99815, 34243, -16323, 26948, -51607,
-        86129, 11175, 8240, 41116, 21980, 19632, -79671, -22917, 61292, 88697, -21913, 80237, 8359, 81307,
-        58241, 8096, 55421, -30725, 23473, -42423, 592, 27200, 36935, 7060, -84264, 96231, 72576, 28353,
-        -62291, -35655, 30257, 63080, 7684, 25917, -82187, 93419, 28813, 90113, 84873, -93056,
-        -27146, 31068, 59415, 7084, 31284, 15153, 17290, 38484, 74679, 59638, 27344, -96031, 2711, 81029,
-        -34387, 72379, 80937, 95208, 35261, -49300, 45695, 93254, -89748, 37594, 4363, 61419,
-        77014, 99332, 3097, 47643, 28747, 62148, 92603, 56407, 53652, 1508, 75534, 75369, 55271, 82477,
-        38428, 52617, -5479, 10739, 64267, 90088, 84954, 28311, 64251, 48684, 15946, -63409,
-        -68123, -41206, 37724, 94385, 96413, 10317, 82521, 41862, -46014, 94574, 85364, 52086,
-    ];
+    array
 }
@@ -0,0 +1,124 @@
+/* These tests check trig identities and symmetries over arbitrary (i32, i32) fractions. Proptest shrinks any failure
+to a minimal counterexample, which it prints along with the failure.
+
+Every input is rounded to thousandths before it is looked up, and negative or large angles are reduced to one turn
+and rounded to thousandths again. So x and -x, or x and x + 2 PI, can land on neighboring thousandths. The symmetries
+and the periodicity are therefore checked to within 1/1000, which is what the library guarantees, and not exactly. */
+
+use deterministic_trigonometry::{ DTrig, Fraction };
+use proptest::prelude::*;
+
+// Any fraction with a non-zero denominator.
+fn fraction() -> impl Strategy<Value = (i32, i32)> {
+    (any::<i32>(), any::<i32>().prop_filter("The denominator must not be zero.", |denominator| *denominator != 0))
+}
+
+// Any fraction whose numerator can be negated without overflowing.
+fn negatable_fraction() -> impl Strategy<Value = (i32, i32)> {
+    fraction().prop_filter("The numerator must not be i32::MIN.", |(numerator, _)| *numerator != i32::MIN)
+}
+
+// Any fraction from -1 to 1, which the arcsine and arccosine accept.
+fn unit_fraction() -> impl Strategy<Value = (i32, i32)> {
+    any::<i32>()
+        .prop_filter("The denominator must not be zero.", |denominator| *denominator != 0)
+        .prop_flat_map(|denominator| {
+            let limit = i64::from(denominator).abs().min(i64::from(i32::MAX)) as i32;
+            (-limit..=limit, Just(denominator))
+        })
+}
+
+// The distance between two fractions in thousandths, rounded up.
+fn distance_in_thousandths(first: (i64, i64), second: (i64, i64)) -> i128 {
+    let numerator = (i128::from(first.0) * i128::from(second.1) - i128::from(second.0) * i128::from(first.1)).abs();
+    let denominator = (i128::from(first.1) * i128::from(second.1)).abs();
+    (numerator * 1000 + denominator - 1) / denominator
+}
+
+proptest! {
+    #[test]
+    fn sine_squared_plus_cosine_squared_is_one(input in fraction()) {
+        let d_trig = DTrig::initialize();
+        let sine = i64::from(d_trig.sine(input).0);
+        let cosine = i64::from(d_trig.cosine(input).0);
+
+        // Each output is within 1/2000 of the true value at the rounded angle, so the sum of squares in millionths is
+        // within 2 * 1000 * (1/2) * sqrt(2) + 1/2, which is less than 1415.
+        prop_assert!((sine * sine + cosine * cosine - 1000000).abs() <= 1415);
+    }
+
+    #[test]
+    fn sine_is_odd(input in negatable_fraction()) {
+        let d_trig = DTrig::initialize();
+        let sine = d_trig.sine(input).0;
+        let sine_of_negative = d_trig.sine((-input.0, input.1)).0;
+
+        prop_assert!((sine + sine_of_negative).abs() <= 1);
+    }
+
+    #[test]
+    fn cosine_is_even(input in negatable_fraction()) {
+        let d_trig = DTrig::initialize();
+        let cosine = d_trig.cosine(input).0;
+        let cosine_of_negative = d_trig.cosine((-input.0, input.1)).0;
+
+        prop_assert!((cosine - cosine_of_negative).abs() <= 1);
+    }
+
+    #[test]
+    fn sine_has_a_period_of_two_pi(
+        numerator in any::<i32>(),
+        denominator in (1..=1000000i64).prop_union(-1000000..=-1i64),
+        turns in -100..=100i64
+    ) {
+        let d_trig = DTrig::initialize();
+
+        // 2 PI is 6283185307/1000000000 to within a billionth, so a hundred turns move the angle by less than
+        // 1/10000000 more than they should.
+        let shifted = (
+            i64::from(numerator) * 1000000000 + 6283185307 * turns * denominator,
+            denominator * 1000000000,
+        );
+
+        let sine = d_trig.sine((i64::from(numerator), denominator)).0;
+        let shifted_sine = d_trig.sine(shifted).0;
+
+        prop_assert!((sine - shifted_sine).abs() <= 1);
+    }
+
+    #[test]
+    fn arcsine_and_arccosine_are_monotonic(first in unit_fraction(), second in unit_fraction()) {
+        let d_trig = DTrig::initialize();
+        let (low, high) = if Fraction(first.0, first.1) <= Fraction(second.0, second.1) {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+        prop_assert!(d_trig.arcsine(low).0 <= d_trig.arcsine(high).0);
+        prop_assert!(d_trig.arccosine(low).0 >= d_trig.arccosine(high).0);
+    }
+
+    #[test]
+    fn arctangent_is_monotonic(first in fraction(), second in fraction()) {
+        let d_trig = DTrig::initialize();
+        let (low, high) = if Fraction(first.0, first.1) <= Fraction(second.0, second.1) {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+        prop_assert!(d_trig.arctangent(low).0 <= d_trig.arctangent(high).0);
+    }
+
+    #[test]
+    fn arcsine_inverts_sine(input in unit_fraction()) {
+        let d_trig = DTrig::initialize();
+        let result = d_trig.arcsine(d_trig.sine(input)).0;
+
+        // From -1 to 1 radian the slope of the arcsine is at most 1.86, so the errors of the rounded input, the sine
+        // and the arcsine add up to less than 2/1000.
+        let distance = distance_in_thousandths((i64::from(result), 1000), (i64::from(input.0), i64::from(input.1)));
+        prop_assert!(distance <= 2, "arcsine(sine({}/{})) is {}/1000", input.0, input.1, result);
+    }
+}
@@ -0,0 +1,95 @@
+/* These tests check the reduction of large angles to one turn against the exact reference in examples/reference,
+which computes PI with 192 bits after the point. An angle of x + 2 PI k in thousandths, with 2 PI k rounded to the
+nearest thousandth, is less than half a thousandth from x plus whole turns, so it has to give exactly the same results
+as x for any number of turns k. The largest k here moves the angle close to the end of the range of an i64. */
+
+#[path = "../examples/reference/mod.rs"]
+mod reference;
+
+use deterministic_trigonometry::{ Compatibility, DRng, DTrig };
+use reference::Real;
+
+// 2 PI k rounded to the nearest thousandth.
+fn turns_in_thousandths(turns: i64) -> i64 {
+    reference::pi().mul_small(2000).mul(&Real::from_integer(i128::from(turns))).round() as i64
+}
+
+// Reduces an angle in thousandths to the nearest thousandth from 0 to 6282 with the reference.
+fn reference_index(thousandths: i128) -> i64 {
+    let turn = reference::pi().mul_small(2000);
+    let angle = Real::from_integer(thousandths);
+
+    // Takes off the nearest whole number of turns and adds one back if the angle is left below zero.
+    let turns = angle.div(&turn).round();
+    let mut reduced = angle.sub(&turn.mul(&Real::from_integer(turns)));
+    if reduced < Real::zero() {
+        reduced = reduced.add(&turn);
+    }
+
+    (reduced.round() % 6283) as i64
+}
+
+// Numbers of turns from a thousand up to the most that keep every angle of the first turn within an i64.
+fn large_turns() -> Vec<i64> {
+    let mut rng = DRng::new(50);
+    let most = (i64::MAX - 6283) / 6284;
+
+    let mut turns = vec![1000, 1000000, 1000000000, 1000000000000, 1000000000000000, most];
+    turns.extend((0..20).map(|_| ((rng.next_u64() >> 1) as i64) % most));
+    turns.extend(turns.clone().into_iter().map(|turns| -turns));
+    turns
+}
+
+#[test]
+fn test_sine_repeats_every_turn_for_large_k() {
+    let d_trig = DTrig::initialize();
+
+    for turns in large_turns() {
+        let shift = turns_in_thousandths(turns);
+
+        for thousandths in 0..6283 {
+            let shifted = (thousandths + shift, 1000);
+            let angle = (thousandths, 1000);
+
+            assert_eq!(d_trig.sine(shifted), d_trig.sine(angle), "{}/1000 plus {} turns", thousandths, turns);
+            assert_eq!(d_trig.cosine(shifted), d_trig.cosine(angle), "{}/1000 plus {} turns", thousandths, turns);
+            assert_eq!(d_trig.tangent(shifted), d_trig.tangent(angle), "{}/1000 plus {} turns", thousandths, turns);
+        }
+    }
+}
+
+#[test]
+fn test_version_0_1_drifts_over_many_turns() {
+    let legacy_d_trig = DTrig::initialize().with_compatibility(Compatibility::Version0_1);
+    let shift = turns_in_thousandths(2000000000);
+
+    // The 12 digit value of 2 PI is off by 4 * 10^-10 thousandths per turn, which adds up to 0.8 thousandths over two
+    // billion turns, so the legacy normalization lands on the neighboring thousandth.
+    let moved = (0..6283)
+        .filter(|thousandths| {
+            legacy_d_trig.cosine((thousandths + shift, 1000)) != legacy_d_trig.cosine((*thousandths, 1000))
+        })
+        .count();
+    assert!(moved > 1000, "{}", moved);
+}
+
+#[test]
+fn test_reduction_matches_reference_for_any_i64() {
+    let d_trig = DTrig::initialize();
+    let mut rng = DRng::new(51);
+
+    let mut numerators = vec![i64::MIN, i64::MIN + 1, i64::MAX, i64::MAX - 1, i64::from(i32::MIN), i64::from(i32::MAX)];
+    numerators.extend((0..2000).map(|_| rng.next_u64() as i64));
+
+    for numerator in numerators {
+        for denominator in [1, -1, 1000] {
+            let thousandths = i128::from(numerator) * 1000 / i128::from(denominator);
+            let index = (reference_index(thousandths), 1000);
+            let input = (numerator, denominator);
+
+            assert_eq!(d_trig.sine(input), d_trig.sine(index), "{:?}", input);
+            assert_eq!(d_trig.cosine(input), d_trig.cosine(index), "{:?}", input);
+            assert_eq!(d_trig.tangent(input), d_trig.tangent(index), "{:?}", input);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+// These tests only run with the serde feature enabled: cargo test --features serde
+#![cfg(feature = "serde")]
+
+use deterministic_trigonometry::geometry::{ Circle, Intersection, Ray, Segment };
+use deterministic_trigonometry::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+// Checks that a value encodes to the documented JSON and decodes back to the same value.
+fn check_encoding<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T, json: &str) {
+    assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    assert_eq!(serde_json::from_str::<T>(json).unwrap(), value);
+}
+
+#[test]
+fn test_value_type_encodings() {
+    check_encoding(Fraction(707, 1000), "[707,1000]");
+    check_encoding(Fraction(-3, -7), "[-3,-7]");
+    check_encoding(Angle::from_thousandths(-785), "-785");
+    check_encoding(DVec2::new(3, -4), r#"{"x":3,"y":-4}"#);
+    check_encoding(
+        DMat2::from_cols(DVec2::new(1000, 0), DVec2::new(0, 1000)),
+        r#"{"x_axis":{"x":1000,"y":0},"y_axis":{"x":0,"y":1000}}"#
+    );
+    check_encoding(
+        DAffine2::from_translation(DVec2::new(5, 6)),
+        r#"{"matrix":{"x_axis":{"x":1000,"y":0},"y_axis":{"x":0,"y":1000}},"translation":{"x":5,"y":6}}"#
+    );
+    check_encoding(RoundingMode::HalfEven, r#""HalfEven""#);
+    check_encoding(Compatibility::Version0_1, r#""Version0_1""#);
+    check_encoding(RoundingMode::HalfAwayFromZero, r#""HalfAwayFromZero""#);
+}
+
+#[test]
+fn test_geometry_encodings() {
+    check_encoding(
+        Ray::new(DVec2::new(0, 0), DVec2::new(1, 1)),
+        r#"{"origin":{"x":0,"y":0},"direction":{"x":1,"y":1}}"#
+    );
+    check_encoding(
+        Segment::new(DVec2::new(-1, 0), DVec2::new(1, 0)),
+        r#"{"start":{"x":-1,"y":0},"end":{"x":1,"y":0}}"#
+    );
+    check_encoding(Circle::new(DVec2::new(2, 3), 10), r#"{"center":{"x":2,"y":3},"radius":10}"#);
+    check_encoding(
+        Intersection { parameter: 500, point: DVec2::new(1, 2) },
+        r#"{"parameter":500,"point":{"x":1,"y":2}}"#
+    );
+}
+
+#[test]
+fn test_random_state_survives_a_round_trip() {
+    let mut rng = DRng::new(2024);
+    rng.next_u64();
+
+    let json = serde_json::to_string(&rng).unwrap();
+    let mut restored: DRng = serde_json::from_str(&json).unwrap();
+
+    for _ in 0..100 {
+        assert_eq!(restored.next_u64(), rng.next_u64());
+    }
+}
+
+#[test]
+fn test_invalid_fractions_are_rejected() {
+    assert!(serde_json::from_str::<Fraction>("[1,0]").is_err());
+    assert!(serde_json::from_str::<Fraction>("[1]").is_err());
+    assert!(serde_json::from_str::<Fraction>("[1,2,3]").is_err());
+    assert!(serde_json::from_str::<Fraction>("[2147483648,1]").is_err());
+}
+
+#[test]
+fn test_invalid_shapes_are_rejected() {
+    assert!(serde_json::from_str::<Ray>(r#"{"origin":{"x":0,"y":0},"direction":{"x":0,"y":0}}"#).is_err());
+    assert!(serde_json::from_str::<Circle>(r#"{"center":{"x":2,"y":3},"radius":-1}"#).is_err());
+    assert_eq!(
+        serde_json::from_str::<Circle>(r#"{"center":{"x":2,"y":3},"radius":0}"#).unwrap(),
+        Circle::new(DVec2::new(2, 3), 0)
+    );
+}
+
+#[test]
+fn test_decoded_values_give_the_same_results() {
+    let d_trig = DTrig::initialize();
+
+    let angles: Vec<Angle> = (-7000..7000).step_by(13).map(Angle::from_thousandths).collect();
+    let restored: Vec<Angle> = serde_json::from_str(&serde_json::to_string(&angles).unwrap()).unwrap();
+
+    for (angle, restored) in angles.iter().zip(restored.iter()) {
+        assert_eq!(d_trig.sine(*angle), d_trig.sine(*restored));
+    }
+}